@@ -17,7 +17,7 @@ use criterion::{
 use std::sync::Arc;
 use std::thread;
 
-use cc_graph_kernel::{
+use admissibility_kernel::{
     TokenVerifier, VerificationMode, CacheConfig,
     SliceExport, TurnSnapshot, TurnId, Role, Phase, GraphSnapshotHash,
 };
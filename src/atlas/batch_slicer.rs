@@ -3,15 +3,22 @@
 //! Generates deterministic slices for a set of anchor turns,
 //! producing a registry of all slices with their fingerprints.
 
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::{BufRead, Write};
 use std::sync::Arc;
 
 use crate::canonical::canonical_hash_hex;
 use crate::policy::SlicePolicyV1;
 use crate::slicer::{ContextSlicer, SlicerError};
 use crate::store::GraphStore;
-use crate::types::{TurnId, SliceExport};
+use crate::types::{
+    AdmissibleEvidenceBundle, DiversityMetrics, SliceExport, SliceFingerprint, SufficiencyCheck,
+    SufficiencyPolicy, TurnId, VerificationError,
+};
+#[cfg(feature = "tokio")]
+use std::time::Duration;
 
 /// Result of a batch slice operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,15 +35,121 @@ pub struct BatchSliceResult {
     pub slices: Vec<SliceExport>,
     /// Registry of slice metadata.
     pub registry: SliceRegistry,
+    /// Anchors that could not be sliced (e.g. per-slice timeout).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<BatchSliceError>,
+}
+
+impl BatchSliceResult {
+    /// Summarize the batch.
+    pub fn summary(&self) -> BatchSummary {
+        let total_turns: usize = self.slices.iter().map(|s| s.turns.len()).sum();
+        let unique_turns = self
+            .slices
+            .iter()
+            .flat_map(|s| s.turns.iter().map(|t| t.id))
+            .collect::<HashSet<TurnId>>()
+            .len();
+        let mean_slice_size = if self.slices.is_empty() {
+            0.0
+        } else {
+            total_turns as f32 / self.slices.len() as f32
+        };
+
+        BatchSummary {
+            anchor_count: self.slices.len() + self.errors.len(),
+            slice_count: self.slices.len(),
+            error_count: self.errors.len(),
+            total_turns,
+            unique_turns,
+            mean_slice_size,
+            registry_hash: self.registry.registry_hash.clone(),
+        }
+    }
+
+    /// Check every slice against a sufficiency policy.
+    ///
+    /// Each slice's token is verified first, so only admissible slices are
+    /// evaluated. A slice that fails verification gets its error in place
+    /// of a check; the remaining slices are still evaluated. Results are in
+    /// registry order.
+    pub fn evaluate_sufficiency(
+        &self,
+        hmac_secret: &[u8],
+        policy: &SufficiencyPolicy,
+    ) -> Vec<(SliceFingerprint, Result<SufficiencyCheck, VerificationError>)> {
+        self.slices
+            .iter()
+            .map(|slice| {
+                let check = AdmissibleEvidenceBundle::from_verified(slice.clone(), hmac_secret)
+                    .map(|bundle| policy.check(&DiversityMetrics::from_bundle(&bundle)));
+                (slice.slice_id.clone(), check)
+            })
+            .collect()
+    }
+}
+
+/// One-line digest of a batch run, for dashboards.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchSummary {
+    /// Anchors attempted: sliced plus skipped.
+    pub anchor_count: usize,
+    /// Slices produced.
+    pub slice_count: usize,
+    /// Anchors skipped (see `BatchSliceResult::errors`).
+    pub error_count: usize,
+    /// Turns summed over all slices, counting repeats.
+    pub total_turns: usize,
+    /// Distinct turns across all slices.
+    pub unique_turns: usize,
+    /// `total_turns / slice_count` (0.0 for an empty batch).
+    pub mean_slice_size: f32,
+    /// The registry's integrity hash.
+    pub registry_hash: String,
+}
+
+/// An anchor that was skipped during batch slicing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BatchSliceError {
+    /// The anchor turn that failed.
+    pub anchor_turn_id: String,
+    /// Why the anchor was skipped.
+    pub reason: String,
 }
 
 /// Registry of all slices in a batch.
+///
+/// Lookups by `slice_id` use an index built in `new` (and on
+/// deserialization). The index is not serialized and not hashed; rebuild
+/// the registry with `new` after editing `entries`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "SliceRegistryRepr")]
 pub struct SliceRegistry {
     /// Individual slice entries.
     pub entries: Vec<SliceRegistryEntry>,
     /// Hash of the registry for integrity verification.
     pub registry_hash: String,
+    /// Position of each slice_id in `entries` (first occurrence).
+    #[serde(skip)]
+    index: HashMap<String, usize>,
+}
+
+/// Serialized form of `SliceRegistry`.
+#[derive(Deserialize)]
+struct SliceRegistryRepr {
+    entries: Vec<SliceRegistryEntry>,
+    registry_hash: String,
+}
+
+impl From<SliceRegistryRepr> for SliceRegistry {
+    fn from(repr: SliceRegistryRepr) -> Self {
+        let index = SliceRegistry::build_index(&repr.entries);
+        Self {
+            entries: repr.entries,
+            registry_hash: repr.registry_hash,
+            index,
+        }
+    }
 }
 
 /// Metadata for a single slice in the registry.
@@ -58,10 +171,30 @@ impl SliceRegistry {
     /// Create a new registry from entries.
     pub fn new(entries: Vec<SliceRegistryEntry>) -> Self {
         let registry_hash = canonical_hash_hex(&entries);
+        let index = Self::build_index(&entries);
         Self {
             entries,
             registry_hash,
+            index,
+        }
+    }
+
+    fn build_index(entries: &[SliceRegistryEntry]) -> HashMap<String, usize> {
+        let mut index = HashMap::with_capacity(entries.len());
+        for (i, entry) in entries.iter().enumerate() {
+            index.entry(entry.slice_id.clone()).or_insert(i);
         }
+        index
+    }
+
+    /// Get entry by slice ID in O(1).
+    pub fn get(&self, slice_id: &str) -> Option<&SliceRegistryEntry> {
+        self.index.get(slice_id).map(|&i| &self.entries[i])
+    }
+
+    /// Check whether a slice ID is registered.
+    pub fn contains(&self, slice_id: &str) -> bool {
+        self.index.contains_key(slice_id)
     }
 
     /// Get entry by anchor turn ID.
@@ -71,7 +204,7 @@ impl SliceRegistry {
 
     /// Get entry by slice ID.
     pub fn get_by_slice_id(&self, slice_id: &str) -> Option<&SliceRegistryEntry> {
-        self.entries.iter().find(|e| e.slice_id == slice_id)
+        self.get(slice_id)
     }
 }
 
@@ -79,13 +212,45 @@ impl SliceRegistry {
 pub struct BatchSlicer<S: GraphStore + Send + Sync + 'static> {
     slicer: ContextSlicer<S>,
     policy: SlicePolicyV1,
+    concurrency: usize,
+    #[cfg(feature = "tokio")]
+    per_slice_timeout: Option<Duration>,
 }
 
 impl<S: GraphStore + Send + Sync + 'static> BatchSlicer<S> {
     /// Create a new batch slicer with HMAC secret.
     pub fn new(store: Arc<S>, policy: SlicePolicyV1, hmac_secret: Vec<u8>) -> Self {
         let slicer = ContextSlicer::new(store, policy.clone(), hmac_secret);
-        Self { slicer, policy }
+        Self {
+            slicer,
+            policy,
+            concurrency: 1,
+            #[cfg(feature = "tokio")]
+            per_slice_timeout: None,
+        }
+    }
+
+    /// Slice up to `n` anchors concurrently (default 1, clamped to at least 1).
+    ///
+    /// Results are still assembled in anchor order, so the registry is
+    /// identical to a sequential run.
+    pub fn with_concurrency(mut self, n: usize) -> Self {
+        self.concurrency = n.max(1);
+        self
+    }
+
+    /// Bound the time spent slicing any single anchor.
+    ///
+    /// Anchors that exceed the timeout are recorded in
+    /// `BatchSliceResult::errors` instead of failing the batch; other
+    /// anchors are sliced as usual.
+    ///
+    /// Only available with the `tokio` feature (also enabled by `postgres`
+    /// and `service`).
+    #[cfg(feature = "tokio")]
+    pub fn with_per_slice_timeout(mut self, timeout: Duration) -> Self {
+        self.per_slice_timeout = Some(timeout);
+        self
     }
 
     /// Create for testing (uses test secret).
@@ -107,10 +272,26 @@ impl<S: GraphStore + Send + Sync + 'static> BatchSlicer<S> {
 
         let mut slices = Vec::with_capacity(anchors.len());
         let mut entries = Vec::with_capacity(anchors.len());
+        let mut errors = Vec::new();
+
+        // `buffered` yields in input order; dropping the stream on the
+        // first hard error cancels any in-flight slices
+        let mut outcomes = stream::iter(anchors)
+            .map(|anchor| async move { (anchor, self.slice_anchor(*anchor).await) })
+            .buffered(self.concurrency);
 
-        for anchor in anchors {
+        while let Some((anchor, outcome)) = outcomes.next().await {
             // slice() now returns AdmissibleEvidenceBundle, proving verification
-            let bundle = self.slicer.slice(anchor.clone()).await?;
+            let bundle = match outcome? {
+                Ok(bundle) => bundle,
+                Err(reason) => {
+                    errors.push(BatchSliceError {
+                        anchor_turn_id: anchor.as_uuid().to_string(),
+                        reason,
+                    });
+                    continue;
+                }
+            };
             let slice = bundle.slice();
 
             entries.push(SliceRegistryEntry {
@@ -133,9 +314,28 @@ impl<S: GraphStore + Send + Sync + 'static> BatchSlicer<S> {
             policy_params_hash,
             slices,
             registry,
+            errors,
         })
     }
 
+    /// Slice one anchor, applying the per-slice timeout if configured.
+    ///
+    /// `Ok(Err(reason))` marks an anchor to skip; `Err` fails the batch.
+    async fn slice_anchor(
+        &self,
+        anchor: TurnId,
+    ) -> Result<Result<AdmissibleEvidenceBundle, String>, SlicerError> {
+        #[cfg(feature = "tokio")]
+        if let Some(limit) = self.per_slice_timeout {
+            return match tokio::time::timeout(limit, self.slicer.slice(anchor)).await {
+                Ok(result) => result.map(Ok),
+                Err(_) => Ok(Err(format!("slice timed out after {}ms", limit.as_millis()))),
+            };
+        }
+
+        self.slicer.slice(anchor).await.map(Ok)
+    }
+
     /// Get the policy being used.
     pub fn policy(&self) -> &SlicePolicyV1 {
         &self.policy
@@ -145,17 +345,25 @@ impl<S: GraphStore + Send + Sync + 'static> BatchSlicer<S> {
 /// Anchor set with deterministic hash.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnchorSet {
-    /// Anchor turn IDs (sorted for determinism).
+    /// Anchor turn IDs (sorted and deduplicated).
     pub anchors: Vec<TurnId>,
     /// Selection policy used.
     pub selection_policy: String,
     /// Hash of the anchor set.
     pub anchor_set_hash: String,
+    /// Number of anchors passed to `new`, including duplicates.
+    #[serde(default)]
+    pub original_count: usize,
 }
 
 impl AnchorSet {
     /// Create a new anchor set from turn IDs.
+    ///
+    /// Anchors are sorted and deduplicated, so each turn is sliced once and
+    /// `anchor_set_hash` is independent of input order and duplicates.
     pub fn new(mut anchors: Vec<TurnId>, selection_policy: &str) -> Self {
+        let original_count = anchors.len();
+
         // Sort for determinism
         anchors.sort();
         anchors.dedup();
@@ -168,9 +376,15 @@ impl AnchorSet {
             anchors,
             selection_policy: selection_policy.to_string(),
             anchor_set_hash,
+            original_count,
         }
     }
 
+    /// Number of distinct anchors after deduplication.
+    pub fn deduped_count(&self) -> usize {
+        self.anchors.len()
+    }
+
     /// Number of anchors in the set.
     pub fn len(&self) -> usize {
         self.anchors.len()
@@ -180,6 +394,114 @@ impl AnchorSet {
     pub fn is_empty(&self) -> bool {
         self.anchors.is_empty()
     }
+
+    /// Write the set as `anchors.jsonl`.
+    ///
+    /// The first line is a header `{ anchor_set_hash, selection_policy,
+    /// count }`, followed by one `{ turn_id, index }` object per anchor in
+    /// TurnId order, so the bytes are identical for identical sets.
+    pub fn write_anchors_jsonl<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let header = AnchorsJsonlHeader {
+            anchor_set_hash: self.anchor_set_hash.clone(),
+            selection_policy: self.selection_policy.clone(),
+            count: self.anchors.len(),
+        };
+        writeln!(writer, "{}", serde_json::to_string(&header)?)?;
+
+        for (index, anchor) in self.anchors.iter().enumerate() {
+            let line = AnchorsJsonlEntry {
+                turn_id: anchor.as_uuid().to_string(),
+                index,
+            };
+            writeln!(writer, "{}", serde_json::to_string(&line)?)?;
+        }
+        writer.flush()
+    }
+
+    /// Read a set written by `write_anchors_jsonl`.
+    ///
+    /// Anchors must appear in index order, and the recomputed
+    /// `anchor_set_hash` must match the header.
+    pub fn read_anchors_jsonl<R: BufRead>(reader: R) -> Result<AnchorSet, AnchorsJsonlError> {
+        let mut lines = reader.lines();
+
+        let header: AnchorsJsonlHeader = match lines.next().transpose()? {
+            Some(line) => serde_json::from_str(&line).map_err(|e| AnchorsJsonlError::InvalidLine {
+                line: 1,
+                reason: e.to_string(),
+            })?,
+            None => return Err(AnchorsJsonlError::MissingHeader),
+        };
+
+        let mut anchors = Vec::with_capacity(header.count);
+        for (index, line) in lines.enumerate() {
+            let line = line?;
+            let line_number = index + 2;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let invalid = |reason: String| AnchorsJsonlError::InvalidLine { line: line_number, reason };
+            let entry: AnchorsJsonlEntry = serde_json::from_str(&line).map_err(|e| invalid(e.to_string()))?;
+            if entry.index != anchors.len() {
+                return Err(invalid(format!("expected index {}, got {}", anchors.len(), entry.index)));
+            }
+            let turn_id = TurnId::from_str(&entry.turn_id)
+                .map_err(|e| invalid(format!("invalid turn_id '{}': {}", entry.turn_id, e)))?;
+            anchors.push(turn_id);
+        }
+
+        let set = AnchorSet::new(anchors, &header.selection_policy);
+        if set.anchor_set_hash != header.anchor_set_hash || set.len() != header.count {
+            return Err(AnchorsJsonlError::HashMismatch {
+                expected: header.anchor_set_hash,
+                actual: set.anchor_set_hash,
+            });
+        }
+        Ok(set)
+    }
+}
+
+/// Header line of `anchors.jsonl`.
+#[derive(Serialize, Deserialize)]
+struct AnchorsJsonlHeader {
+    anchor_set_hash: String,
+    selection_policy: String,
+    count: usize,
+}
+
+/// Anchor line of `anchors.jsonl`.
+#[derive(Serialize, Deserialize)]
+struct AnchorsJsonlEntry {
+    turn_id: String,
+    index: usize,
+}
+
+/// Error reading `anchors.jsonl`.
+#[derive(Debug, thiserror::Error)]
+pub enum AnchorsJsonlError {
+    /// Underlying I/O failure.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The file is empty.
+    #[error("anchors.jsonl is missing its header line")]
+    MissingHeader,
+    /// A line could not be parsed.
+    #[error("Invalid anchors.jsonl line {line}: {reason}")]
+    InvalidLine {
+        /// 1-based line number.
+        line: usize,
+        /// What was wrong with the line.
+        reason: String,
+    },
+    /// The anchors do not hash to the header's `anchor_set_hash`.
+    #[error("Anchor set hash mismatch: header {expected}, computed {actual}")]
+    HashMismatch {
+        /// Hash recorded in the header.
+        expected: String,
+        /// Hash recomputed from the anchors.
+        actual: String,
+    },
 }
 
 /// Build a turn-to-slices index from a batch result.
@@ -208,8 +530,7 @@ pub fn build_turn_slice_index(result: &BatchSliceResult) -> BTreeMap<String, Vec
 mod tests {
     use super::*;
     use crate::store::memory::InMemoryGraphStore;
-    use crate::types::{TurnSnapshot, Edge, EdgeType, Phase, Role};
-    use crate::policy::PhaseWeights;
+    use crate::types::{AdmissibilityToken, TurnSnapshot, Edge, EdgeType, Phase, Role};
     use uuid::Uuid;
 
     fn make_test_store() -> Arc<InMemoryGraphStore> {
@@ -244,15 +565,15 @@ mod tests {
             3000,
         );
 
-        let id1 = turn1.id.clone();
-        let id2 = turn2.id.clone();
-        let id3 = turn3.id.clone();
+        let id1 = turn1.id;
+        let id2 = turn2.id;
+        let id3 = turn3.id;
 
         store.add_turn(turn1);
         store.add_turn(turn2);
         store.add_turn(turn3);
-        store.add_edge(Edge::new(id1.clone(), id2.clone(), EdgeType::Reply));
-        store.add_edge(Edge::new(id2.clone(), id3.clone(), EdgeType::Reply));
+        store.add_edge(Edge::new(id1, id2, EdgeType::Reply));
+        store.add_edge(Edge::new(id2, id3, EdgeType::Reply));
 
         Arc::new(store)
     }
@@ -260,21 +581,19 @@ mod tests {
     #[tokio::test]
     async fn test_batch_slice() {
         let store = make_test_store();
-        let turns: Vec<_> = store.all_turns().iter().map(|t| t.id.clone()).collect();
+        let turns: Vec<_> = store.all_turns().iter().map(|t| t.id).collect();
 
         let policy = SlicePolicyV1 {
             max_nodes: 10,
             max_radius: 3,
-            phase_weights: PhaseWeights::default(),
             salience_weight: 1.0,
             distance_decay: 0.8,
-            include_siblings: true,
             max_siblings_per_node: 3,
-            version: "slice_policy_v1".to_string(),
+            ..Default::default()
         };
 
         let slicer = BatchSlicer::new_for_test(store, policy);
-        let anchors = vec![turns[0].clone(), turns[2].clone()];
+        let anchors = vec![turns[0], turns[2]];
 
         let result = slicer
             .slice_all(&anchors, "snapshot_test", "anchor_hash_test")
@@ -286,6 +605,34 @@ mod tests {
         assert_eq!(result.snapshot_id, "snapshot_test");
     }
 
+    #[test]
+    fn test_registry_lookup_by_slice_id() {
+        let entry = |n: usize| SliceRegistryEntry {
+            anchor_turn_id: format!("anchor_{}", n),
+            slice_id: format!("slice_{}", n),
+            turn_count: n,
+            edge_count: n.saturating_sub(1),
+            policy_params_hash: "params_hash".to_string(),
+        };
+        let entries: Vec<_> = (1..=4).map(entry).collect();
+        let registry = SliceRegistry::new(entries.clone());
+
+        // The index does not affect the hash
+        assert_eq!(registry.registry_hash, canonical_hash_hex(&entries));
+
+        assert_eq!(registry.get("slice_3").unwrap().anchor_turn_id, "anchor_3");
+        assert!(registry.contains("slice_1"));
+        assert!(!registry.contains("slice_9"));
+        assert!(registry.get("slice_9").is_none());
+
+        // Deserialized registries are indexed too
+        let json = serde_json::to_value(&registry).unwrap();
+        assert!(json.get("index").is_none());
+        let parsed: SliceRegistry = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.get("slice_2").unwrap().turn_count, 2);
+        assert_eq!(parsed.registry_hash, registry.registry_hash);
+    }
+
     #[test]
     fn test_anchor_set_determinism() {
         let id1 = TurnId::new(Uuid::new_v4());
@@ -293,26 +640,73 @@ mod tests {
         let id3 = TurnId::new(Uuid::new_v4());
 
         // Different order, same content
-        let set1 = AnchorSet::new(vec![id1.clone(), id2.clone(), id3.clone()], "policy_v1");
-        let set2 = AnchorSet::new(vec![id3.clone(), id1.clone(), id2.clone()], "policy_v1");
+        let set1 = AnchorSet::new(vec![id1, id2, id3], "policy_v1");
+        let set2 = AnchorSet::new(vec![id3, id1, id2], "policy_v1");
 
         assert_eq!(set1.anchor_set_hash, set2.anchor_set_hash);
     }
 
+    #[test]
+    fn test_anchor_set_dedup() {
+        let a = TurnId::new(Uuid::from_u128(1));
+        let b = TurnId::new(Uuid::from_u128(2));
+
+        let with_dup = AnchorSet::new(vec![a, b, a], "policy_v1");
+        let distinct = AnchorSet::new(vec![a, b], "policy_v1");
+
+        assert_eq!(with_dup.anchor_set_hash, distinct.anchor_set_hash);
+        assert_eq!(with_dup.anchors, vec![a, b]);
+        assert_eq!(with_dup.original_count, 3);
+        assert_eq!(with_dup.deduped_count(), 2);
+        assert_eq!(distinct.original_count, 2);
+    }
+
+    #[test]
+    fn test_anchors_jsonl_round_trip() {
+        let ids: Vec<TurnId> = [3u128, 1, 2].iter().map(|&n| TurnId::new(Uuid::from_u128(n))).collect();
+        let set = AnchorSet::new(ids.clone(), "policy_v1");
+
+        let mut first = Vec::new();
+        set.write_anchors_jsonl(&mut first).unwrap();
+        let mut second = Vec::new();
+        AnchorSet::new(ids.into_iter().rev().collect(), "policy_v1")
+            .write_anchors_jsonl(&mut second)
+            .unwrap();
+        assert_eq!(first, second);
+
+        let text = String::from_utf8(first.clone()).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].contains(&set.anchor_set_hash));
+        assert_eq!(
+            lines[1],
+            r#"{"turn_id":"00000000-0000-0000-0000-000000000001","index":0}"#
+        );
+
+        let parsed = AnchorSet::read_anchors_jsonl(first.as_slice()).unwrap();
+        assert_eq!(parsed.anchors, set.anchors);
+        assert_eq!(parsed.anchor_set_hash, set.anchor_set_hash);
+
+        // Dropping an anchor breaks the hash
+        let truncated = lines[..3].join("\n");
+        assert!(matches!(
+            AnchorSet::read_anchors_jsonl(truncated.as_bytes()),
+            Err(AnchorsJsonlError::HashMismatch { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn test_turn_slice_index() {
         let store = make_test_store();
-        let turns: Vec<_> = store.all_turns().iter().map(|t| t.id.clone()).collect();
+        let turns: Vec<_> = store.all_turns().iter().map(|t| t.id).collect();
 
         let policy = SlicePolicyV1 {
             max_nodes: 10,
             max_radius: 3,
-            phase_weights: PhaseWeights::default(),
             salience_weight: 1.0,
             distance_decay: 0.8,
-            include_siblings: true,
             max_siblings_per_node: 3,
-            version: "slice_policy_v1".to_string(),
+            ..Default::default()
         };
 
         let slicer = BatchSlicer::new_for_test(store, policy);
@@ -329,5 +723,214 @@ mod tests {
             assert!(index.contains_key(&turn_str), "Turn {} not in index", turn_str);
         }
     }
-}
 
+    /// Store that stalls when fetching one specific turn.
+    #[cfg(feature = "tokio")]
+    struct SlowAnchorStore {
+        inner: InMemoryGraphStore,
+        slow: TurnId,
+    }
+
+    #[cfg(feature = "tokio")]
+    #[async_trait::async_trait]
+    impl GraphStore for SlowAnchorStore {
+        type Error = crate::store::memory::InMemoryError;
+
+        async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error> {
+            if *id == self.slow {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+            }
+            self.inner.get_turn(id).await
+        }
+
+        async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error> {
+            self.inner.get_turns(ids).await
+        }
+
+        async fn get_parents(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+            self.inner.get_parents(id).await
+        }
+
+        async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+            self.inner.get_children(id).await
+        }
+
+        async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+            self.inner.get_siblings(id, limit).await
+        }
+
+        async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error> {
+            self.inner.get_edges(turn_ids).await
+        }
+
+        async fn turns_by_phase(&self, phase: Phase, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+            self.inner.turns_by_phase(phase, limit).await
+        }
+
+        async fn recent_turns(&self, session_id: &str, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+            self.inner.recent_turns(session_id, limit).await
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_per_slice_timeout_records_error() {
+        // Three disconnected turns so the slow one is only touched as its own anchor
+        let mut inner = InMemoryGraphStore::new();
+        let ids: Vec<TurnId> = (1..=3u128).map(|n| TurnId::new(Uuid::from_u128(n))).collect();
+        for id in &ids {
+            inner.add_turn(TurnSnapshot::new(
+                *id,
+                "session_1".to_string(),
+                Role::User,
+                Phase::Planning,
+                0.5,
+                0, 0, 0.5, 0.5, 1.0,
+                1000,
+            ));
+        }
+
+        let reference = BatchSlicer::new_for_test(Arc::new(inner.clone()), SlicePolicyV1::default())
+            .slice_all(&[ids[0], ids[2]], "snapshot", "anchors")
+            .await
+            .unwrap();
+
+        let store = Arc::new(SlowAnchorStore { inner, slow: ids[1] });
+        let slicer = BatchSlicer::new_for_test(store, SlicePolicyV1::default())
+            .with_per_slice_timeout(Duration::from_millis(50));
+        let result = slicer.slice_all(&ids, "snapshot", "anchors").await.unwrap();
+
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].anchor_turn_id, ids[1].as_uuid().to_string());
+        assert!(result.errors[0].reason.contains("timed out"));
+
+        // The other anchors match an untimed run exactly
+        assert_eq!(result.slices.len(), 2);
+        assert_eq!(result.registry.registry_hash, reference.registry.registry_hash);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_batch_matches_sequential() {
+        let store = make_test_store();
+        let mut anchors: Vec<TurnId> = store.all_turns().iter().map(|t| t.id).collect();
+        anchors.sort();
+
+        let sequential = BatchSlicer::new_for_test(store.clone(), SlicePolicyV1::default())
+            .slice_all(&anchors, "snapshot", "anchors")
+            .await
+            .unwrap();
+        let concurrent = BatchSlicer::new_for_test(store, SlicePolicyV1::default())
+            .with_concurrency(4)
+            .slice_all(&anchors, "snapshot", "anchors")
+            .await
+            .unwrap();
+
+        assert_eq!(concurrent.registry.registry_hash, sequential.registry.registry_hash);
+        let order: Vec<String> = concurrent
+            .registry
+            .entries
+            .iter()
+            .map(|e| e.anchor_turn_id.clone())
+            .collect();
+        let expected: Vec<String> = anchors.iter().map(|a| a.as_uuid().to_string()).collect();
+        assert_eq!(order, expected);
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_sufficiency_classifies_slices() {
+        let turn = |n: u128, role: Role| {
+            TurnSnapshot::new(
+                TurnId::new(Uuid::from_u128(n)),
+                "session_1".to_string(),
+                role,
+                Phase::Planning,
+                0.5,
+                0, 0, 0.5, 0.5, 1.0,
+                1000,
+            )
+        };
+
+        // An exchange (1 -> 2) and a lone user turn (3)
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(turn(1, Role::User));
+        store.add_turn(turn(2, Role::Assistant));
+        store.add_turn(turn(3, Role::User));
+        let (exchange, lone) = (TurnId::new(Uuid::from_u128(1)), TurnId::new(Uuid::from_u128(3)));
+        store.add_edge(Edge::new(exchange, TurnId::new(Uuid::from_u128(2)), EdgeType::Reply));
+
+        let result = BatchSlicer::new_for_test(Arc::new(store), SlicePolicyV1::default())
+            .slice_all(&[lone, exchange], "snapshot", "anchors")
+            .await
+            .unwrap();
+
+        let policy = SufficiencyPolicy {
+            min_turns: 2,
+            require_exchange: true,
+            ..SufficiencyPolicy::lenient()
+        };
+        let checks = result.evaluate_sufficiency(b"test_secret_for_batch_slicer", &policy);
+
+        let ids: Vec<String> = checks.iter().map(|(id, _)| id.to_string()).collect();
+        let registry_ids: Vec<String> = result.registry.entries.iter().map(|e| e.slice_id.clone()).collect();
+        assert_eq!(ids, registry_ids);
+        assert!(!checks[0].1.as_ref().unwrap().is_sufficient);
+        assert!(checks[1].1.as_ref().unwrap().is_sufficient);
+
+        let checks = result.evaluate_sufficiency(b"some_other_secret", &policy);
+        assert_eq!(checks.len(), 2);
+        assert!(checks.iter().all(|(_, check)| check.is_err()));
+
+        // A single tampered slice is reported without hiding the others
+        let mut tampered = result.clone();
+        tampered.slices[0].admissibility_token = AdmissibilityToken::from_string("0".repeat(64));
+        let checks = tampered.evaluate_sufficiency(b"test_secret_for_batch_slicer", &policy);
+        assert!(checks[0].1.is_err());
+        assert!(checks[1].1.as_ref().unwrap().is_sufficient);
+    }
+
+    #[tokio::test]
+    async fn test_batch_summary() {
+        // Chain 1 -> 2 -> 3 -> 4 -> 5 and an isolated turn 6
+        let mut store = InMemoryGraphStore::new();
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        for n in 1..=6 {
+            store.add_turn(TurnSnapshot::new(
+                id(n),
+                "session_1".to_string(),
+                Role::User,
+                Phase::Planning,
+                0.5,
+                0, 0, 0.5, 0.5, 1.0,
+                1000,
+            ));
+        }
+        for n in 1..5 {
+            store.add_edge(Edge::new(id(n), id(n + 1), EdgeType::Reply));
+        }
+
+        let policy = SlicePolicyV1 {
+            max_radius: 1,
+            include_siblings: false,
+            ..SlicePolicyV1::default()
+        };
+        let mut result = BatchSlicer::new_for_test(Arc::new(store), policy)
+            .slice_all(&[id(1), id(3), id(6)], "snapshot", "anchors")
+            .await
+            .unwrap();
+        result.errors.push(BatchSliceError {
+            anchor_turn_id: id(9).as_uuid().to_string(),
+            reason: "slice timed out after 50ms".to_string(),
+        });
+
+        // Slices {1, 2}, {2, 3, 4} and {6}
+        let summary = result.summary();
+        assert_eq!(summary.anchor_count, 4);
+        assert_eq!(summary.slice_count, 3);
+        assert_eq!(summary.error_count, 1);
+        assert_eq!(summary.total_turns, 6);
+        assert_eq!(summary.unique_turns, 5);
+        assert_eq!(summary.mean_slice_size, 2.0);
+        assert_eq!(summary.registry_hash, result.registry.registry_hash);
+        assert_eq!(summary, result.summary());
+    }
+}
@@ -6,7 +6,7 @@
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-use crate::canonical::canonical_hash_hex;
+use crate::canonical::{canonical_hash_hex, to_canonical_bytes};
 use super::{
     GraphSnapshot,
     BatchSliceResult,
@@ -105,6 +105,135 @@ pub struct AtlasManifest {
     pub artifact_paths: AtlasArtifactPaths,
     /// Summary statistics.
     pub stats: AtlasStats,
+    /// HMAC over the canonical manifest, set by `sign`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl AtlasManifest {
+    /// Signature version marker, prefixed to the signed bytes.
+    const SIGNATURE_VERSION: &'static str = "atlas_manifest_v1_hmac";
+
+    /// Canonical bytes of the manifest, excluding `signature`.
+    ///
+    /// Covers every other field, including `atlas_id`.
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut unsigned = self.clone();
+        unsigned.signature = None;
+        to_canonical_bytes(&unsigned)
+    }
+
+    fn mac(&self, secret: &[u8]) -> hmac::Hmac<sha2::Sha256> {
+        use hmac::{Hmac, Mac};
+
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret)
+            .expect("HMAC accepts any key size");
+        mac.update(Self::SIGNATURE_VERSION.as_bytes());
+        mac.update(b"|");
+        mac.update(&self.to_canonical_bytes());
+        mac
+    }
+
+    /// Sign the manifest with the kernel's HMAC secret.
+    ///
+    /// Like `AdmissibilityToken`, the signature is the first 16 bytes of
+    /// HMAC-SHA256, hex-encoded. Any later change to the manifest
+    /// invalidates it.
+    pub fn sign(mut self, secret: &[u8]) -> Self {
+        use hmac::Mac;
+
+        let result = self.mac(secret).finalize().into_bytes();
+        self.signature = Some(hex::encode(&result[..16]));
+        self
+    }
+
+    /// Check that the manifest was signed with `secret` and is unmodified.
+    ///
+    /// Returns false for unsigned manifests. Uses constant-time comparison.
+    pub fn verify(&self, secret: &[u8]) -> bool {
+        use hmac::Mac;
+
+        match self.signature.as_deref().map(hex::decode) {
+            Some(Ok(signature)) if signature.len() == 16 => {
+                self.mac(secret).verify_truncated_left(&signature).is_ok()
+            }
+            _ => false,
+        }
+    }
+
+    /// Report which component hashes differ from `other`.
+    ///
+    /// Localizes a changed `atlas_id` to the pipeline stage(s) that
+    /// produced different output. Timestamps, artifact paths, and
+    /// signatures are not compared.
+    pub fn diff(&self, other: &AtlasManifest) -> AtlasDiff {
+        let pairs = [
+            (AtlasComponent::Version, &self.version, &other.version),
+            (AtlasComponent::Snapshot, &self.snapshot_id, &other.snapshot_id),
+            (AtlasComponent::AnchorSet, &self.anchor_set_hash, &other.anchor_set_hash),
+            (AtlasComponent::SliceRegistry, &self.slice_registry_hash, &other.slice_registry_hash),
+            (AtlasComponent::OverlapGraph, &self.overlap_graph_hash, &other.overlap_graph_hash),
+            (AtlasComponent::TurnInfluence, &self.turn_influence_hash, &other.turn_influence_hash),
+            (AtlasComponent::PhaseTopology, &self.phase_topology_hash, &other.phase_topology_hash),
+        ];
+
+        let mut changed: Vec<AtlasComponent> = pairs
+            .into_iter()
+            .filter(|(_, a, b)| a != b)
+            .map(|(component, _, _)| component)
+            .collect();
+        if canonical_hash_hex(&self.stats) != canonical_hash_hex(&other.stats) {
+            changed.push(AtlasComponent::Stats);
+        }
+
+        AtlasDiff {
+            atlas_id_changed: self.atlas_id != other.atlas_id,
+            changed,
+        }
+    }
+}
+
+/// A hashed component of an Atlas manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AtlasComponent {
+    /// Schema version.
+    Version,
+    /// Source graph snapshot (`snapshot_id`).
+    Snapshot,
+    /// Anchor set (`anchor_set_hash`).
+    AnchorSet,
+    /// Slice registry (`slice_registry_hash`).
+    SliceRegistry,
+    /// Overlap graph (`overlap_graph_hash`).
+    OverlapGraph,
+    /// Turn influence scores (`turn_influence_hash`).
+    TurnInfluence,
+    /// Phase topology (`phase_topology_hash`).
+    PhaseTopology,
+    /// Summary statistics (canonical hash of `stats`).
+    Stats,
+}
+
+/// Components that differ between two Atlas manifests.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AtlasDiff {
+    /// Whether the `atlas_id`s differ.
+    pub atlas_id_changed: bool,
+    /// Differing components, in pipeline order.
+    pub changed: Vec<AtlasComponent>,
+}
+
+impl AtlasDiff {
+    /// Check whether no component differs.
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty()
+    }
+
+    /// Check whether `component` differs.
+    pub fn contains(&self, component: AtlasComponent) -> bool {
+        self.changed.contains(&component)
+    }
 }
 
 /// Summary statistics for an Atlas run.
@@ -230,6 +359,7 @@ impl AtlasBundler {
             computed_at: now,
             artifact_paths: self.artifact_paths,
             stats,
+            signature: None,
         }
     }
 
@@ -268,7 +398,7 @@ struct AtlasIdInput {
 mod tests {
     use super::*;
     use crate::atlas::{SnapshotInput, BatchSliceResult, SliceRegistry, SliceRegistryEntry};
-    use crate::types::{TurnId, TurnSnapshot, Edge, EdgeType, Phase, Role, SliceExport};
+    use crate::types::{TurnId, Edge, EdgeType};
     use uuid::Uuid;
 
     fn make_test_snapshot() -> GraphSnapshot {
@@ -276,7 +406,7 @@ mod tests {
         let turn2 = TurnId::new(Uuid::new_v4());
 
         let input = SnapshotInput {
-            turn_ids: vec![turn1.clone(), turn2.clone()],
+            turn_ids: vec![turn1, turn2],
             edges: vec![Edge::new(turn1, turn2, EdgeType::Reply)],
             timestamps: vec![1000, 2000],
         };
@@ -300,6 +430,7 @@ mod tests {
                     policy_params_hash: "params_hash".to_string(),
                 },
             ]),
+            errors: vec![],
         }
     }
 
@@ -339,6 +470,82 @@ mod tests {
         assert!(result.is_none());
     }
 
+    fn make_test_manifest() -> AtlasManifest {
+        AtlasBundler::new()
+            .snapshot(make_test_snapshot())
+            .batch_result(make_test_batch_result())
+            .overlap_graph(make_test_overlap_graph())
+            .influence_scores(make_test_influence_scores())
+            .phase_topology(make_test_phase_topology())
+            .build()
+    }
+
+    #[test]
+    fn test_manifest_sign_and_verify() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let manifest = make_test_manifest();
+        assert!(!manifest.verify(secret));
+
+        let unsigned_bytes = manifest.to_canonical_bytes();
+        let signed = manifest.sign(secret);
+        assert_eq!(signed.signature.as_ref().unwrap().len(), 32);
+        assert!(signed.verify(secret));
+        assert!(!signed.verify(b"another_secret"));
+
+        // The signature is not part of the signed content
+        assert_eq!(signed.to_canonical_bytes(), unsigned_bytes);
+
+        // Survives a JSON round trip
+        let json = serde_json::to_string(&signed).unwrap();
+        let parsed: AtlasManifest = serde_json::from_str(&json).unwrap();
+        assert!(parsed.verify(secret));
+    }
+
+    #[test]
+    fn test_manifest_tamper_detection() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let signed = make_test_manifest().sign(secret);
+
+        let mut tampered = signed.clone();
+        tampered.atlas_id = "forged".to_string();
+        assert!(!tampered.verify(secret));
+
+        let mut tampered = signed.clone();
+        tampered.stats.slice_count += 1;
+        assert!(!tampered.verify(secret));
+
+        let mut tampered = signed;
+        tampered.signature = Some("not hex".to_string());
+        assert!(!tampered.verify(secret));
+    }
+
+    #[test]
+    fn test_manifest_diff_localizes_anchor_set() {
+        let snapshot = make_test_snapshot();
+        let build = |batch_result: BatchSliceResult| {
+            AtlasBundler::new()
+                .snapshot(snapshot.clone())
+                .batch_result(batch_result)
+                .overlap_graph(make_test_overlap_graph())
+                .influence_scores(make_test_influence_scores())
+                .phase_topology(make_test_phase_topology())
+                .build()
+        };
+
+        let original = build(make_test_batch_result());
+        let mut rerun_input = make_test_batch_result();
+        rerun_input.anchor_set_hash = "other_anchor_hash".to_string();
+        let rerun = build(rerun_input);
+
+        let diff = original.diff(&rerun);
+        assert!(diff.atlas_id_changed);
+        assert_eq!(diff.changed, vec![AtlasComponent::AnchorSet]);
+
+        let same = original.diff(&original.clone().sign(b"secret"));
+        assert!(same.is_empty());
+        assert!(!same.atlas_id_changed);
+    }
+
     #[test]
     fn test_manifest_determinism() {
         let snapshot = make_test_snapshot();
@@ -0,0 +1,162 @@
+//! Weakly-connected component partitioning for Atlas.
+//!
+//! Splits a graph into components that share no edges (ignoring edge
+//! direction), so atlas runs can slice each component independently.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::canonical::canonical_hash_hex;
+use crate::types::TurnId;
+
+use super::SnapshotInput;
+
+/// A weakly-connected component of the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Component {
+    /// Hash of the sorted member turn IDs.
+    pub component_id: String,
+    /// Member turns, sorted by TurnId.
+    pub turn_ids: Vec<TurnId>,
+}
+
+impl Component {
+    /// Create a component from its members (sorted here).
+    pub fn new(mut turn_ids: Vec<TurnId>) -> Self {
+        turn_ids.sort();
+        let component_id = canonical_hash_hex(&turn_ids);
+        Self {
+            component_id,
+            turn_ids,
+        }
+    }
+
+    /// Number of turns in the component.
+    pub fn len(&self) -> usize {
+        self.turn_ids.len()
+    }
+
+    /// Check if the component has no turns.
+    pub fn is_empty(&self) -> bool {
+        self.turn_ids.is_empty()
+    }
+}
+
+/// Partition a graph into weakly-connected components.
+///
+/// Union-find over the edges, treating them as undirected. Turns that only
+/// appear as edge endpoints are included. Components are sorted by their
+/// smallest TurnId, so the result is identical for the same graph
+/// regardless of input order.
+pub fn connected_components(input: &SnapshotInput) -> Vec<Component> {
+    group_connected(
+        input.turn_ids.iter().copied(),
+        input.edges.iter().map(|e| (e.parent, e.child)),
+    )
+    .into_iter()
+    .map(Component::new)
+    .collect()
+}
+
+/// Group nodes into connected components over undirected links.
+///
+/// Nodes that only appear in `links` are included. Each group is sorted,
+/// and groups are ordered by their smallest member.
+pub(crate) fn group_connected<K: Ord + Clone>(
+    nodes: impl IntoIterator<Item = K>,
+    links: impl IntoIterator<Item = (K, K)>,
+) -> Vec<Vec<K>> {
+    let links: Vec<(K, K)> = links.into_iter().collect();
+
+    // One union-find slot per distinct node
+    let mut index: BTreeMap<K, usize> = BTreeMap::new();
+    for id in nodes
+        .into_iter()
+        .chain(links.iter().flat_map(|(a, b)| [a.clone(), b.clone()]))
+    {
+        let next = index.len();
+        index.entry(id).or_insert(next);
+    }
+
+    let mut parent: Vec<usize> = (0..index.len()).collect();
+    fn find(parent: &mut [usize], mut node: usize) -> usize {
+        while parent[node] != node {
+            parent[node] = parent[parent[node]];
+            node = parent[node];
+        }
+        node
+    }
+
+    for (a, b) in &links {
+        let a = find(&mut parent, index[a]);
+        let b = find(&mut parent, index[b]);
+        if a != b {
+            parent[a.max(b)] = a.min(b);
+        }
+    }
+
+    // Iterating in key order makes each group's first member its
+    // smallest; keying groups by that member orders the groups
+    let mut groups: BTreeMap<K, Vec<K>> = BTreeMap::new();
+    let mut root_owner: BTreeMap<usize, K> = BTreeMap::new();
+    for (id, &node) in &index {
+        let root = find(&mut parent, node);
+        let owner = root_owner.entry(root).or_insert_with(|| id.clone()).clone();
+        groups.entry(owner).or_default().push(id.clone());
+    }
+
+    groups.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Edge, EdgeType};
+    use uuid::Uuid;
+
+    fn id(n: u128) -> TurnId {
+        TurnId::new(Uuid::from_u128(n))
+    }
+
+    #[test]
+    fn test_two_clusters() {
+        // Cluster {1, 3, 5} and cluster {2, 4}, edges listed out of order
+        let input = SnapshotInput {
+            turn_ids: vec![id(5), id(4), id(3), id(2), id(1)],
+            edges: vec![
+                Edge::new(id(4), id(2), EdgeType::Reply),
+                Edge::new(id(5), id(3), EdgeType::Reply),
+                Edge::new(id(1), id(3), EdgeType::Branch),
+            ],
+            timestamps: vec![0; 5],
+        };
+
+        let components = connected_components(&input);
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].turn_ids, vec![id(1), id(3), id(5)]);
+        assert_eq!(components[1].turn_ids, vec![id(2), id(4)]);
+        assert_ne!(components[0].component_id, components[1].component_id);
+
+        // Same graph, different input order
+        let reordered = SnapshotInput {
+            turn_ids: vec![id(1), id(2), id(3), id(4), id(5)],
+            edges: input.edges.iter().rev().cloned().collect(),
+            timestamps: vec![0; 5],
+        };
+        assert_eq!(connected_components(&reordered), components);
+    }
+
+    #[test]
+    fn test_isolated_turns_are_singletons() {
+        let input = SnapshotInput {
+            turn_ids: vec![id(2), id(1)],
+            edges: vec![],
+            timestamps: vec![0; 2],
+        };
+
+        let components = connected_components(&input);
+        assert_eq!(components.len(), 2);
+        assert_eq!(components[0].turn_ids, vec![id(1)]);
+        assert_eq!(components[1].turn_ids, vec![id(2)]);
+    }
+}
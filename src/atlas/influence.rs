@@ -36,6 +36,15 @@ impl PhaseCounts {
         }
     }
 
+    /// Add another distribution's counts into this one.
+    pub fn add(&mut self, other: &PhaseCounts) {
+        self.exploration += other.exploration;
+        self.debugging += other.debugging;
+        self.planning += other.planning;
+        self.consolidation += other.consolidation;
+        self.synthesis += other.synthesis;
+    }
+
     /// Total count across all phases.
     pub fn total(&self) -> u32 {
         self.exploration + self.debugging + self.planning + self.consolidation + self.synthesis
@@ -112,6 +121,38 @@ impl InfluenceScores {
         }
     }
 
+    /// Merge scores computed over disjoint groups of slices.
+    ///
+    /// Slice counts and phase distributions are summed per turn, fractions
+    /// are recomputed against the combined slice total, and `is_bridge` is
+    /// re-derived. The result (including `scores_hash`) equals
+    /// `compute_influence` over the union of all slices.
+    pub fn merge(parts: &[InfluenceScores]) -> InfluenceScores {
+        let mut turn_data: BTreeMap<&str, (u32, PhaseCounts)> = BTreeMap::new();
+        let total_slices: usize = parts.iter().map(|p| p.total_slices).sum();
+
+        for part in parts {
+            for score in &part.scores {
+                let entry = turn_data.entry(score.turn_id.as_str()).or_default();
+                entry.0 += score.slice_count;
+                entry.1.add(&score.phase_distribution);
+            }
+        }
+
+        let scores: Vec<TurnInfluence> = turn_data
+            .into_iter()
+            .map(|(turn_id, (slice_count, phase_distribution))| TurnInfluence {
+                turn_id: turn_id.to_string(),
+                slice_count,
+                slice_fraction: slice_count as f32 / total_slices as f32,
+                is_bridge: phase_distribution.is_cross_phase(),
+                phase_distribution,
+            })
+            .collect();
+
+        InfluenceScores::new(scores, total_slices)
+    }
+
     /// Get influence for a specific turn.
     pub fn get(&self, turn_id: &str) -> Option<&TurnInfluence> {
         self.scores.iter().find(|s| s.turn_id == turn_id)
@@ -120,7 +161,7 @@ impl InfluenceScores {
     /// Get the most influential turns (highest slice_count).
     pub fn top_influential(&self, n: usize) -> Vec<&TurnInfluence> {
         let mut sorted: Vec<_> = self.scores.iter().collect();
-        sorted.sort_by(|a, b| b.slice_count.cmp(&a.slice_count));
+        sorted.sort_by_key(|s| std::cmp::Reverse(s.slice_count));
         sorted.into_iter().take(n).collect()
     }
 
@@ -135,25 +176,81 @@ impl InfluenceScores {
     }
 }
 
+/// Error attributing a slice to a phase.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum InfluenceError {
+    /// The slice does not contain its anchor turn and has no override.
+    #[error("Slice {slice_id} does not contain its anchor turn {anchor_turn_id} and has no phase override")]
+    MissingAnchor {
+        /// The slice being attributed.
+        slice_id: String,
+        /// The anchor turn that was not found.
+        anchor_turn_id: String,
+    },
+}
+
+/// Phase of the slice's anchor turn, if the slice contains it.
+fn anchor_phase(slice: &SliceExport) -> Option<Phase> {
+    slice.anchor_turn().map(|t| t.phase)
+}
+
+/// Anchor phase, falling back to `Exploration` with a warning.
+fn anchor_phase_or_default(slice: &SliceExport) -> Phase {
+    anchor_phase(slice).unwrap_or_else(|| {
+        tracing::warn!(
+            slice_id = %slice.slice_id,
+            anchor_turn_id = %slice.anchor_turn_id,
+            "Slice does not contain its anchor turn; attributing to Exploration"
+        );
+        Phase::Exploration
+    })
+}
+
 /// Compute influence scores from slices.
 ///
 /// For each turn, counts how many slices it appears in and
-/// what phases those slices represent.
+/// what phases those slices represent. A slice that does not contain its
+/// own anchor turn is attributed to `Exploration` and logged; use
+/// `compute_influence_with_overrides` to fail on such slices instead.
 pub fn compute_influence(slices: &[SliceExport]) -> InfluenceScores {
+    let phases: Vec<Phase> = slices.iter().map(anchor_phase_or_default).collect();
+    influence_from_phases(slices, &phases)
+}
+
+/// Compute influence scores with explicit per-slice anchor phases.
+///
+/// `anchor_phases` maps `slice_id` to the phase to attribute that slice
+/// to, taking precedence over the anchor turn's own phase. Slices with
+/// neither an override nor their anchor turn are rejected rather than
+/// defaulted.
+pub fn compute_influence_with_overrides(
+    slices: &[SliceExport],
+    anchor_phases: &BTreeMap<String, Phase>,
+) -> Result<InfluenceScores, InfluenceError> {
+    let phases = slices
+        .iter()
+        .map(|slice| {
+            anchor_phases
+                .get(slice.slice_id.as_str())
+                .copied()
+                .or_else(|| anchor_phase(slice))
+                .ok_or_else(|| InfluenceError::MissingAnchor {
+                    slice_id: slice.slice_id.to_string(),
+                    anchor_turn_id: slice.anchor_turn_id.as_uuid().to_string(),
+                })
+        })
+        .collect::<Result<Vec<Phase>, _>>()?;
+    Ok(influence_from_phases(slices, &phases))
+}
+
+/// Tally turn appearances, attributing `slices[i]` to `phases[i]`.
+fn influence_from_phases(slices: &[SliceExport], phases: &[Phase]) -> InfluenceScores {
     // Map: turn_id -> (slice_count, phase_counts)
     let mut turn_data: BTreeMap<String, (u32, PhaseCounts)> = BTreeMap::new();
 
     let total_slices = slices.len();
 
-    for slice in slices {
-        // Determine the anchor's phase for this slice
-        let anchor_phase = slice
-            .turns
-            .iter()
-            .find(|t| t.id == slice.anchor_turn_id)
-            .map(|t| t.phase)
-            .unwrap_or(Phase::Exploration);
-
+    for (slice, &anchor_phase) in slices.iter().zip(phases) {
         for turn in &slice.turns {
             let turn_id = turn.id.as_uuid().to_string();
             let entry = turn_data.entry(turn_id).or_default();
@@ -197,34 +294,42 @@ pub fn extract_bridges(scores: &InfluenceScores) -> Vec<BridgeTurn> {
     scores
         .bridge_turns()
         .iter()
-        .map(|t| {
-            let mut phases = Vec::new();
-            let pd = &t.phase_distribution;
-            if pd.exploration > 0 {
-                phases.push(Phase::Exploration);
-            }
-            if pd.debugging > 0 {
-                phases.push(Phase::Debugging);
-            }
-            if pd.planning > 0 {
-                phases.push(Phase::Planning);
-            }
-            if pd.consolidation > 0 {
-                phases.push(Phase::Consolidation);
-            }
-            if pd.synthesis > 0 {
-                phases.push(Phase::Synthesis);
-            }
-
-            BridgeTurn {
-                turn_id: t.turn_id.clone(),
-                bridged_phases: phases,
-                total_appearances: t.slice_count,
-            }
+        .map(|t| BridgeTurn {
+            turn_id: t.turn_id.clone(),
+            bridged_phases: present_phases(&t.phase_distribution),
+            total_appearances: t.slice_count,
         })
         .collect()
 }
 
+/// Phases with a non-zero count, in declaration order.
+fn present_phases(pd: &PhaseCounts) -> Vec<Phase> {
+    let mut phases = Vec::new();
+    if pd.exploration > 0 {
+        phases.push(Phase::Exploration);
+    }
+    if pd.debugging > 0 {
+        phases.push(Phase::Debugging);
+    }
+    if pd.planning > 0 {
+        phases.push(Phase::Planning);
+    }
+    if pd.consolidation > 0 {
+        phases.push(Phase::Consolidation);
+    }
+    if pd.synthesis > 0 {
+        phases.push(Phase::Synthesis);
+    }
+    phases
+}
+
+/// Centroid limit assumed for stats serialized before the limit was recorded.
+pub const DEFAULT_MAX_CENTROIDS_PER_PHASE: usize = 3;
+
+fn default_max_centroids_per_phase() -> usize {
+    DEFAULT_MAX_CENTROIDS_PER_PHASE
+}
+
 /// Phase topology analysis: how phases relate in the overlap space.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PhaseTopologyStats {
@@ -235,7 +340,19 @@ pub struct PhaseTopologyStats {
     pub phase_centroids: BTreeMap<String, Vec<String>>,
     /// Turns that appear in slices of multiple phases.
     pub cross_phase_bridges: Vec<BridgeTurn>,
+    /// Number of overlap edges averaged into each `phase_pair_overlaps` entry.
+    #[serde(default)]
+    pub phase_pair_counts: BTreeMap<String, u32>,
+    /// Overlap edge count (connectivity) of each centroid slice.
+    #[serde(default)]
+    pub centroid_degrees: BTreeMap<String, u32>,
+    /// Centroid limit the stats were computed with.
+    #[serde(default = "default_max_centroids_per_phase")]
+    pub max_centroids_per_phase: usize,
     /// Content hash.
+    ///
+    /// Covers the overlaps, centroids, and bridges; the counts above are
+    /// merge bookkeeping and are not hashed.
     pub stats_hash: String,
 }
 
@@ -254,9 +371,91 @@ impl PhaseTopologyStats {
             phase_pair_overlaps,
             phase_centroids,
             cross_phase_bridges,
+            phase_pair_counts: BTreeMap::new(),
+            centroid_degrees: BTreeMap::new(),
+            max_centroids_per_phase: 0,
             stats_hash,
         }
     }
+
+    /// Approximately merge stats computed over separate groups of slices.
+    ///
+    /// Phase-pair overlaps are averaged weighted by `phase_pair_counts`,
+    /// centroids are re-ranked by summed `centroid_degrees` and cut to the
+    /// largest `max_centroids_per_phase`, and bridges are unioned by turn ID
+    /// (phases unioned, appearances summed).
+    ///
+    /// This is approximate. The parts only hold aggregates, so overlap
+    /// edges between groups, turns that bridge phases only across groups,
+    /// and centroids a part cut off are all lost. The result equals
+    /// `compute_phase_topology` over the union only when no overlap edge
+    /// or turn spans two groups (e.g. one connected component per group)
+    /// and the weighted averages recombine exactly; overlaps are recombined
+    /// in f64 while a whole-set run averages in f32.
+    pub fn merge_approximate(parts: &[PhaseTopologyStats]) -> PhaseTopologyStats {
+        let max_centroids_per_phase = parts
+            .iter()
+            .map(|p| p.max_centroids_per_phase)
+            .max()
+            .unwrap_or(0);
+
+        let mut pair_sums: BTreeMap<&str, (f64, u32)> = BTreeMap::new();
+        for part in parts {
+            for (key, &avg) in &part.phase_pair_overlaps {
+                let count = part.phase_pair_counts.get(key).copied().unwrap_or(1);
+                let entry = pair_sums.entry(key.as_str()).or_default();
+                entry.0 += avg as f64 * count as f64;
+                entry.1 += count;
+            }
+        }
+        let phase_pair_counts: BTreeMap<String, u32> = pair_sums
+            .iter()
+            .map(|(&k, &(_, count))| (k.to_string(), count))
+            .collect();
+        let phase_pair_overlaps: BTreeMap<String, f32> = pair_sums
+            .into_iter()
+            .map(|(k, (sum, count))| (k.to_string(), (sum / count as f64) as f32))
+            .collect();
+
+        let mut connectivity: BTreeMap<String, BTreeMap<&str, u32>> = BTreeMap::new();
+        for part in parts {
+            for (phase, ids) in &part.phase_centroids {
+                let slices = connectivity.entry(phase.clone()).or_default();
+                for id in ids {
+                    let degree = part.centroid_degrees.get(id).copied().unwrap_or(0);
+                    *slices.entry(id.as_str()).or_default() += degree;
+                }
+            }
+        }
+        let (phase_centroids, centroid_degrees) =
+            rank_centroids(connectivity, max_centroids_per_phase);
+
+        let mut bridges: BTreeMap<&str, (PhaseCounts, u32)> = BTreeMap::new();
+        for part in parts {
+            for bridge in &part.cross_phase_bridges {
+                let entry = bridges.entry(bridge.turn_id.as_str()).or_default();
+                for &phase in &bridge.bridged_phases {
+                    entry.0.increment(phase);
+                }
+                entry.1 += bridge.total_appearances;
+            }
+        }
+        let cross_phase_bridges: Vec<BridgeTurn> = bridges
+            .into_iter()
+            .map(|(turn_id, (phases, total_appearances))| BridgeTurn {
+                turn_id: turn_id.to_string(),
+                bridged_phases: present_phases(&phases),
+                total_appearances,
+            })
+            .collect();
+
+        let mut stats =
+            PhaseTopologyStats::new(phase_pair_overlaps, phase_centroids, cross_phase_bridges);
+        stats.phase_pair_counts = phase_pair_counts;
+        stats.centroid_degrees = centroid_degrees;
+        stats.max_centroids_per_phase = max_centroids_per_phase;
+        stats
+    }
 }
 
 /// Compute phase topology from slices and overlap data.
@@ -270,15 +469,7 @@ pub fn compute_phase_topology(
     // Build slice_id -> phase mapping
     let slice_phases: HashMap<String, Phase> = slices
         .iter()
-        .map(|s| {
-            let anchor_phase = s
-                .turns
-                .iter()
-                .find(|t| t.id == s.anchor_turn_id)
-                .map(|t| t.phase)
-                .unwrap_or(Phase::Exploration);
-            (s.slice_id.to_string(), anchor_phase)
-        })
+        .map(|s| (s.slice_id.to_string(), anchor_phase_or_default(s)))
         .collect();
 
     // Compute phase pair overlaps
@@ -297,49 +488,67 @@ pub fn compute_phase_topology(
         }
     }
 
+    let phase_pair_counts: BTreeMap<String, u32> = pair_sums
+        .iter()
+        .map(|(k, &(_, count))| (k.clone(), count as u32))
+        .collect();
     let phase_pair_overlaps: BTreeMap<String, f32> = pair_sums
         .into_iter()
         .map(|(k, (sum, count))| (k, sum / count as f32))
         .collect();
 
     // Compute phase centroids (most connected slices per phase)
-    let mut phase_connectivity: HashMap<String, HashMap<String, usize>> = HashMap::new();
+    let mut phase_connectivity: BTreeMap<String, BTreeMap<&str, u32>> = BTreeMap::new();
     for edge in overlap_edges {
-        if let Some(phase) = slice_phases.get(&edge.slice_a) {
-            *phase_connectivity
-                .entry(phase_to_string(*phase))
-                .or_default()
-                .entry(edge.slice_a.clone())
-                .or_default() += 1;
-        }
-        if let Some(phase) = slice_phases.get(&edge.slice_b) {
-            *phase_connectivity
-                .entry(phase_to_string(*phase))
-                .or_default()
-                .entry(edge.slice_b.clone())
-                .or_default() += 1;
+        for slice_id in [&edge.slice_a, &edge.slice_b] {
+            if let Some(phase) = slice_phases.get(slice_id) {
+                *phase_connectivity
+                    .entry(phase_to_string(*phase))
+                    .or_default()
+                    .entry(slice_id.as_str())
+                    .or_default() += 1;
+            }
         }
     }
 
-    let phase_centroids: BTreeMap<String, Vec<String>> = phase_connectivity
-        .into_iter()
-        .map(|(phase, slices)| {
-            let mut sorted: Vec<_> = slices.into_iter().collect();
-            sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
-            let top: Vec<String> = sorted
-                .into_iter()
-                .take(max_centroids_per_phase)
-                .map(|(id, _)| id)
-                .collect();
-            (phase, top)
-        })
-        .collect();
+    let (phase_centroids, centroid_degrees) =
+        rank_centroids(phase_connectivity, max_centroids_per_phase);
 
     // Extract bridge turns
     let influence = compute_influence(slices);
     let cross_phase_bridges = extract_bridges(&influence);
 
-    PhaseTopologyStats::new(phase_pair_overlaps, phase_centroids, cross_phase_bridges)
+    let mut stats =
+        PhaseTopologyStats::new(phase_pair_overlaps, phase_centroids, cross_phase_bridges);
+    stats.phase_pair_counts = phase_pair_counts;
+    stats.centroid_degrees = centroid_degrees;
+    stats.max_centroids_per_phase = max_centroids_per_phase;
+    stats
+}
+
+/// Keep the `max` most connected slices per phase (ties by slice ID).
+///
+/// Returns the centroids and the degree of each kept slice.
+fn rank_centroids(
+    connectivity: BTreeMap<String, BTreeMap<&str, u32>>,
+    max: usize,
+) -> (BTreeMap<String, Vec<String>>, BTreeMap<String, u32>) {
+    let mut centroids = BTreeMap::new();
+    let mut degrees = BTreeMap::new();
+    for (phase, slices) in connectivity {
+        let mut sorted: Vec<_> = slices.into_iter().collect();
+        sorted.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        let top: Vec<String> = sorted
+            .into_iter()
+            .take(max)
+            .map(|(id, degree)| {
+                degrees.insert(id.to_string(), degree);
+                id.to_string()
+            })
+            .collect();
+        centroids.insert(phase, top);
+    }
+    (centroids, degrees)
 }
 
 fn make_phase_pair_key(a: Phase, b: Phase) -> String {
@@ -381,7 +590,7 @@ mod tests {
     }
 
     fn make_slice(_id: &str, turns: Vec<TurnSnapshot>) -> SliceExport {
-        let anchor = turns[0].id.clone();
+        let anchor = turns[0].id;
         // Use SliceExport::new_for_test for unit tests
         SliceExport::new_for_test(
             anchor,
@@ -476,5 +685,126 @@ mod tests {
 
         assert_eq!(scores1.scores_hash, scores2.scores_hash);
     }
-}
 
+    #[test]
+    fn test_merge_matches_whole_set() {
+        let uuid1 = "00000000-0000-0000-0000-000000000001";
+        let uuid2 = "00000000-0000-0000-0000-000000000002";
+        let uuid3 = "00000000-0000-0000-0000-000000000003";
+
+        let slices = vec![
+            make_slice("a", vec![make_turn(uuid1, Phase::Exploration), make_turn(uuid2, Phase::Exploration)]),
+            make_slice("b", vec![make_turn(uuid1, Phase::Synthesis)]),
+            make_slice("c", vec![make_turn(uuid2, Phase::Planning), make_turn(uuid3, Phase::Planning)]),
+        ];
+
+        let whole = compute_influence(&slices);
+        let merged = InfluenceScores::merge(&[
+            compute_influence(&slices[..1]),
+            compute_influence(&slices[1..]),
+        ]);
+
+        assert_eq!(merged.total_slices, 3);
+        assert_eq!(merged.scores, whole.scores);
+        assert_eq!(merged.scores_hash, whole.scores_hash);
+        // Turn 1 only becomes a bridge once both groups are combined
+        assert!(merged.get(uuid1).unwrap().is_bridge);
+    }
+
+    #[test]
+    fn test_phase_topology_merge_approximate() {
+        use crate::atlas::OverlapEdge;
+
+        let id = |n: u32| format!("00000000-0000-0000-0000-{:012}", n);
+        let slice = |turns: &[(u32, Phase)]| {
+            make_slice("", turns.iter().map(|&(n, p)| make_turn(&id(n), p)).collect())
+        };
+        let edge = |a: &SliceExport, b: &SliceExport, jaccard: f32| {
+            OverlapEdge::new(a.slice_id.to_string(), b.slice_id.to_string(), 1, jaccard)
+        };
+
+        // Two groups with no shared turns; turn 1 bridges within group one
+        let a1 = slice(&[(1, Phase::Exploration), (2, Phase::Exploration)]);
+        let a2 = slice(&[(3, Phase::Synthesis), (1, Phase::Synthesis)]);
+        let a3 = slice(&[(4, Phase::Synthesis), (2, Phase::Synthesis)]);
+        let b1 = slice(&[(5, Phase::Exploration), (6, Phase::Exploration)]);
+        let b2 = slice(&[(7, Phase::Synthesis), (6, Phase::Synthesis)]);
+        let group_a = vec![a1.clone(), a2.clone(), a3.clone()];
+        let group_b = vec![b1.clone(), b2.clone()];
+        let edges_a = vec![edge(&a1, &a2, 0.5), edge(&a1, &a3, 0.25)];
+        let edges_b = vec![edge(&b1, &b2, 0.75)];
+
+        let all_slices: Vec<_> = group_a.iter().chain(&group_b).cloned().collect();
+        let all_edges: Vec<_> = edges_a.iter().chain(&edges_b).cloned().collect();
+        let whole = compute_phase_topology(&all_slices, &all_edges, 1);
+
+        let parts = [
+            compute_phase_topology(&group_a, &edges_a, 1),
+            compute_phase_topology(&group_b, &edges_b, 1),
+        ];
+        let merged = PhaseTopologyStats::merge_approximate(&parts);
+
+        // Unweighted mean of the part averages would be 0.5625
+        assert_eq!(merged.phase_pair_overlaps["exploration_synthesis"], 0.5);
+        assert_eq!(merged.phase_pair_counts["exploration_synthesis"], 3);
+        assert_eq!(merged.phase_centroids["exploration"], vec![a1.slice_id.to_string()]);
+        assert_eq!(merged.cross_phase_bridges.len(), 3);
+        assert_eq!(merged.phase_pair_overlaps, whole.phase_pair_overlaps);
+        assert_eq!(merged.phase_pair_counts, whole.phase_pair_counts);
+        assert_eq!(merged.phase_centroids, whole.phase_centroids);
+        assert_eq!(merged.centroid_degrees, whole.centroid_degrees);
+        assert_eq!(merged.cross_phase_bridges, whole.cross_phase_bridges);
+        assert_eq!(merged.stats_hash, whole.stats_hash);
+
+        // An overlap edge between the groups is invisible to the parts
+        let mut bridged_edges = all_edges.clone();
+        bridged_edges.push(edge(&a2, &b1, 0.1));
+        let bridged = compute_phase_topology(&all_slices, &bridged_edges, 1);
+        assert_eq!(bridged.phase_pair_counts["exploration_synthesis"], 4);
+        assert_eq!(merged.phase_pair_counts["exploration_synthesis"], 3);
+        assert_ne!(merged.stats_hash, bridged.stats_hash);
+    }
+
+    #[test]
+    fn test_phase_topology_defaults_centroid_limit() {
+        let stats = compute_phase_topology(&[], &[], 1);
+        let mut json = serde_json::to_value(&stats).unwrap();
+        json.as_object_mut().unwrap().remove("max_centroids_per_phase");
+
+        let legacy: PhaseTopologyStats = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.max_centroids_per_phase, DEFAULT_MAX_CENTROIDS_PER_PHASE);
+    }
+
+    #[test]
+    fn test_missing_anchor_requires_override() {
+        let uuid1 = "00000000-0000-0000-0000-000000000001";
+        let uuid2 = "00000000-0000-0000-0000-000000000002";
+        let uuid9 = "00000000-0000-0000-0000-000000000009";
+
+        let complete = make_slice("a", vec![make_turn(uuid1, Phase::Planning)]);
+        // Anchored on a turn the slice does not contain
+        let orphan = SliceExport::new_for_test(
+            TurnId::new(Uuid::parse_str(uuid9).unwrap()),
+            vec![make_turn(uuid2, Phase::Synthesis)],
+            vec![],
+            "test".to_string(),
+            "hash".to_string(),
+        );
+        let slices = vec![complete, orphan.clone()];
+
+        let err = compute_influence_with_overrides(&slices, &BTreeMap::new()).unwrap_err();
+        assert_eq!(
+            err,
+            InfluenceError::MissingAnchor {
+                slice_id: orphan.slice_id.to_string(),
+                anchor_turn_id: uuid9.to_string(),
+            }
+        );
+
+        let overrides = BTreeMap::from([(orphan.slice_id.to_string(), Phase::Debugging)]);
+        let scores = compute_influence_with_overrides(&slices, &overrides).unwrap();
+        assert_eq!(scores.get(uuid2).unwrap().phase_distribution.debugging, 1);
+        assert_eq!(scores.get(uuid2).unwrap().phase_distribution.exploration, 0);
+        assert_eq!(scores.get(uuid1).unwrap().phase_distribution.planning, 1);
+    }
+}
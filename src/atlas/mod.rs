@@ -27,13 +27,15 @@ pub mod batch_slicer;
 pub mod overlap;
 pub mod influence;
 pub mod bundler;
+pub mod components;
 
 // Re-exports
 pub use snapshot::{GraphSnapshot, SnapshotInput, SnapshotStore};
-pub use batch_slicer::{BatchSlicer, BatchSliceResult, SliceRegistry, SliceRegistryEntry, AnchorSet};
-pub use overlap::{OverlapAnalyzer, OverlapGraph, OverlapEdge};
-pub use influence::{TurnInfluence, InfluenceScores, PhaseCounts, BridgeTurn, PhaseTopologyStats, compute_influence, extract_bridges, compute_phase_topology};
-pub use bundler::{AtlasBundler, AtlasManifest, AtlasArtifactPaths, PhaseTopology, AtlasStats};
+pub use batch_slicer::{BatchSlicer, BatchSliceResult, BatchSliceError, BatchSummary, SliceRegistry, SliceRegistryEntry, AnchorSet, AnchorsJsonlError};
+pub use overlap::{Cluster, OverlapAnalyzer, OverlapGraph, OverlapEdge, OverlapCsvError, OverlapMergeError, OVERLAP_CSV_HEADER, shard_for_pair};
+pub use influence::{TurnInfluence, InfluenceScores, PhaseCounts, BridgeTurn, PhaseTopologyStats, InfluenceError, compute_influence, compute_influence_with_overrides, extract_bridges, compute_phase_topology, DEFAULT_MAX_CENTROIDS_PER_PHASE};
+pub use bundler::{AtlasBundler, AtlasManifest, AtlasDiff, AtlasComponent, AtlasArtifactPaths, PhaseTopology, AtlasStats};
+pub use components::{Component, connected_components};
 
 /// Atlas schema version. Increment on breaking changes.
 pub const ATLAS_SCHEMA_VERSION: &str = "atlas_v1";
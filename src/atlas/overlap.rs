@@ -4,10 +4,14 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
+use std::io::{BufRead, Write};
 
-use crate::canonical::canonical_hash_hex;
+use crate::canonical::{canonical_hash, canonical_hash_hex};
+use crate::policy::v1::quantize_float;
 use crate::types::SliceExport;
 
+use super::components::group_connected;
+
 /// An edge in the slice overlap graph.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct OverlapEdge {
@@ -40,6 +44,27 @@ impl OverlapEdge {
     }
 }
 
+/// A group of slices connected by sufficiently strong overlap.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cluster {
+    /// Hash of the sorted member slice IDs.
+    pub cluster_hash: String,
+    /// Member slice IDs, sorted.
+    pub slice_ids: Vec<String>,
+}
+
+impl Cluster {
+    /// Create a cluster from its members (sorted here).
+    pub fn new(mut slice_ids: Vec<String>) -> Self {
+        slice_ids.sort();
+        let cluster_hash = canonical_hash_hex(&slice_ids);
+        Self {
+            cluster_hash,
+            slice_ids,
+        }
+    }
+}
+
 /// The complete slice overlap graph.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverlapGraph {
@@ -48,6 +73,9 @@ pub struct OverlapGraph {
     /// Total number of slices in the graph.
     pub slice_count: usize,
     /// Content hash for integrity verification.
+    ///
+    /// Covers the edges and, when non-zero, the quantized `min_jaccard`, so
+    /// pruned graphs never share an identity with unpruned ones.
     pub graph_hash: String,
     /// Minimum Jaccard threshold used (0.0 means all overlaps included).
     pub min_jaccard: f32,
@@ -56,7 +84,7 @@ pub struct OverlapGraph {
 impl OverlapGraph {
     /// Create a new overlap graph from edges.
     pub fn new(edges: Vec<OverlapEdge>, slice_count: usize, min_jaccard: f32) -> Self {
-        let graph_hash = canonical_hash_hex(&edges);
+        let graph_hash = Self::compute_hash(&edges, min_jaccard);
         Self {
             edges,
             slice_count,
@@ -65,6 +93,109 @@ impl OverlapGraph {
         }
     }
 
+    /// Hash edges together with the pruning threshold.
+    ///
+    /// An unpruned graph (`min_jaccard == 0.0`) hashes its edges alone,
+    /// matching graphs produced before thresholds were hashed.
+    fn compute_hash(edges: &[OverlapEdge], min_jaccard: f32) -> String {
+        if min_jaccard == 0.0 {
+            canonical_hash_hex(&edges)
+        } else {
+            canonical_hash_hex(&(edges, quantize_float(min_jaccard)))
+        }
+    }
+
+    /// Merge independently computed shards into a single graph.
+    ///
+    /// Edges are re-sorted canonically, so the resulting `graph_hash` is
+    /// identical to a single-pass `OverlapAnalyzer::compute` over the same
+    /// slices, regardless of shard order. Fails if the shards were computed
+    /// with different `min_jaccard` thresholds or over different slice sets.
+    pub fn merge(shards: Vec<OverlapGraph>) -> Result<Self, OverlapMergeError> {
+        let slice_count = shards.first().map(|s| s.slice_count).unwrap_or(0);
+        let min_jaccard = shards.first().map(|s| s.min_jaccard).unwrap_or(0.0);
+        for shard in &shards {
+            if quantize_float(shard.min_jaccard) != quantize_float(min_jaccard) {
+                return Err(OverlapMergeError::MinJaccardMismatch {
+                    expected: min_jaccard,
+                    found: shard.min_jaccard,
+                });
+            }
+            if shard.slice_count != slice_count {
+                return Err(OverlapMergeError::SliceCountMismatch {
+                    expected: slice_count,
+                    found: shard.slice_count,
+                });
+            }
+        }
+
+        let mut edges: Vec<OverlapEdge> = shards.into_iter().flat_map(|s| s.edges).collect();
+        edges.sort_by(|a, b| {
+            (&a.slice_a, &a.slice_b).cmp(&(&b.slice_a, &b.slice_b))
+        });
+
+        Ok(Self::new(edges, slice_count, min_jaccard))
+    }
+
+    /// Group slices into clusters connected by edges with
+    /// `jaccard >= min_jaccard`.
+    ///
+    /// Every slice that appears on an edge belongs to exactly one cluster;
+    /// slices whose edges all fall below the threshold become singletons.
+    /// Clusters are sorted by their smallest slice ID, so the assignment is
+    /// stable for the same graph and threshold.
+    pub fn clusters(&self, min_jaccard: f32) -> Vec<Cluster> {
+        let threshold = quantize_float(min_jaccard);
+        group_connected(
+            self.edges
+                .iter()
+                .flat_map(|e| [e.slice_a.clone(), e.slice_b.clone()]),
+            self.edges
+                .iter()
+                .filter(|e| quantize_float(e.jaccard) >= threshold)
+                .map(|e| (e.slice_a.clone(), e.slice_b.clone())),
+        )
+        .into_iter()
+        .map(Cluster::new)
+        .collect()
+    }
+
+    /// Pick one representative slice per cluster, keyed by `cluster_hash`.
+    ///
+    /// The representative is the member with the highest total Jaccard over
+    /// its clustering edges (those with `jaccard >= min_jaccard`), ties
+    /// broken by the smallest slice ID. Weights are summed quantized, so
+    /// the choice never depends on float summation order.
+    pub fn cluster_representatives(&self, min_jaccard: f32) -> BTreeMap<String, String> {
+        let threshold = quantize_float(min_jaccard);
+        let mut weights: BTreeMap<&str, i64> = BTreeMap::new();
+        for edge in &self.edges {
+            let jaccard = quantize_float(edge.jaccard);
+            if jaccard >= threshold {
+                *weights.entry(&edge.slice_a).or_default() += jaccard;
+                *weights.entry(&edge.slice_b).or_default() += jaccard;
+            }
+        }
+
+        self.clusters(min_jaccard)
+            .into_iter()
+            .map(|cluster| {
+                // Members are sorted, so the first maximum has the smallest ID
+                let mut best = &cluster.slice_ids[0];
+                let mut best_weight = i64::MIN;
+                for slice_id in &cluster.slice_ids {
+                    let weight = weights.get(slice_id.as_str()).copied().unwrap_or(0);
+                    if weight > best_weight {
+                        best = slice_id;
+                        best_weight = weight;
+                    }
+                }
+                let representative = best.clone();
+                (cluster.cluster_hash, representative)
+            })
+            .collect()
+    }
+
     /// Get all edges for a given slice.
     pub fn edges_for_slice(&self, slice_id: &str) -> Vec<&OverlapEdge> {
         self.edges
@@ -102,9 +233,154 @@ impl OverlapGraph {
             .map(|(id, d)| (id.to_string(), d))
             .collect();
 
-        hubs.sort_by(|a, b| b.1.cmp(&a.1)); // Sort by degree descending
+        hubs.sort_by_key(|h| std::cmp::Reverse(h.1)); // Sort by degree descending
         hubs
     }
+
+    /// Deterministically sample `n` edges spread across the Jaccard range.
+    ///
+    /// Edges are ranked by `(jaccard, slice_a, slice_b)` and split into `n`
+    /// equal strata; one edge is picked from each stratum by hashing
+    /// `(seed, stratum)`. The sample is returned weakest first, so the same
+    /// graph and seed always yield the same pairs. Returns every edge when
+    /// `n` is at least the edge count.
+    pub fn sample_edges(&self, n: usize, seed: u64) -> Vec<OverlapEdge> {
+        let mut ranked: Vec<&OverlapEdge> = self.edges.iter().collect();
+        ranked.sort_by(|a, b| {
+            (quantize_float(a.jaccard), &a.slice_a, &a.slice_b)
+                .cmp(&(quantize_float(b.jaccard), &b.slice_a, &b.slice_b))
+        });
+
+        if n >= ranked.len() {
+            return ranked.into_iter().cloned().collect();
+        }
+
+        (0..n)
+            .map(|stratum| {
+                let start = stratum * ranked.len() / n;
+                let end = (stratum + 1) * ranked.len() / n;
+                let offset = canonical_hash(&(seed, stratum)) as usize % (end - start);
+                ranked[start + offset].clone()
+            })
+            .collect()
+    }
+
+    /// Write the edges as CSV.
+    ///
+    /// Rows are `slice_a,slice_b,jaccard,shared_turns`, sorted by
+    /// `(slice_a, slice_b)`. Jaccard is written in the shortest form that
+    /// parses back to the same `f32`, so the output is byte-identical for
+    /// identical graphs and `from_csv` recovers the exact values.
+    pub fn to_csv<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        let mut edges: Vec<&OverlapEdge> = self.edges.iter().collect();
+        edges.sort_by(|a, b| {
+            (&a.slice_a, &a.slice_b).cmp(&(&b.slice_a, &b.slice_b))
+        });
+
+        writeln!(writer, "{}", OVERLAP_CSV_HEADER)?;
+        for edge in edges {
+            writeln!(
+                writer,
+                "{},{},{},{}",
+                edge.slice_a, edge.slice_b, edge.jaccard, edge.shared_turns
+            )?;
+        }
+        writer.flush()
+    }
+
+    /// Read edges written by `to_csv`.
+    ///
+    /// Jaccard values come back bit-for-bit as they were written.
+    pub fn from_csv<R: BufRead>(reader: R) -> Result<Vec<OverlapEdge>, OverlapCsvError> {
+        let mut lines = reader.lines();
+
+        match lines.next().transpose()? {
+            Some(header) if header.trim_end() == OVERLAP_CSV_HEADER => {}
+            Some(header) => return Err(OverlapCsvError::InvalidHeader(header)),
+            None => return Err(OverlapCsvError::InvalidHeader(String::new())),
+        }
+
+        let mut edges = Vec::new();
+        for (index, line) in lines.enumerate() {
+            let line = line?;
+            let line_number = index + 2;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.trim_end().split(',').collect();
+            if fields.len() != 4 {
+                return Err(OverlapCsvError::InvalidRow {
+                    line: line_number,
+                    reason: format!("expected 4 fields, found {}", fields.len()),
+                });
+            }
+
+            let jaccard = fields[2].parse::<f32>().map_err(|e| OverlapCsvError::InvalidRow {
+                line: line_number,
+                reason: format!("invalid jaccard '{}': {}", fields[2], e),
+            })?;
+            let shared_turns = fields[3].parse::<usize>().map_err(|e| OverlapCsvError::InvalidRow {
+                line: line_number,
+                reason: format!("invalid shared_turns '{}': {}", fields[3], e),
+            })?;
+
+            edges.push(OverlapEdge::new(
+                fields[0].to_string(),
+                fields[1].to_string(),
+                shared_turns,
+                jaccard,
+            ));
+        }
+
+        edges.sort_by(|a, b| {
+            (&a.slice_a, &a.slice_b).cmp(&(&b.slice_a, &b.slice_b))
+        });
+        Ok(edges)
+    }
+}
+
+/// Header row of the overlap graph CSV format.
+pub const OVERLAP_CSV_HEADER: &str = "slice_a,slice_b,jaccard,shared_turns";
+
+/// Error reading an overlap graph CSV.
+#[derive(Debug, thiserror::Error)]
+pub enum OverlapCsvError {
+    /// Underlying I/O failure.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// First line is not the expected header.
+    #[error("Invalid overlap CSV header: '{0}'")]
+    InvalidHeader(String),
+    /// A data row could not be parsed.
+    #[error("Invalid overlap CSV row at line {line}: {reason}")]
+    InvalidRow {
+        /// 1-based line number.
+        line: usize,
+        /// What was wrong with the row.
+        reason: String,
+    },
+}
+
+/// Shards passed to `OverlapGraph::merge` do not belong together.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum OverlapMergeError {
+    /// Shards were pruned with different thresholds.
+    #[error("Shard min_jaccard {found} differs from {expected}")]
+    MinJaccardMismatch {
+        /// Threshold of the first shard.
+        expected: f32,
+        /// Threshold of the mismatching shard.
+        found: f32,
+    },
+    /// Shards were computed over different numbers of slices.
+    #[error("Shard slice_count {found} differs from {expected}")]
+    SliceCountMismatch {
+        /// Slice count of the first shard.
+        expected: usize,
+        /// Slice count of the mismatching shard.
+        found: usize,
+    },
 }
 
 /// Analyzer for computing slice overlaps.
@@ -120,12 +396,43 @@ impl OverlapAnalyzer {
     }
 
     /// Create an analyzer with a minimum Jaccard threshold.
+    ///
+    /// Pairs whose Jaccard similarity falls below `min_jaccard` get no edge.
+    /// The threshold is part of the resulting `graph_hash`.
     pub fn with_min_jaccard(min_jaccard: f32) -> Self {
         Self { min_jaccard }
     }
 
     /// Compute the overlap graph from a set of slices.
     pub fn compute(&self, slices: &[SliceExport]) -> OverlapGraph {
+        self.compute_pairs(slices, |_, _| true)
+    }
+
+    /// Compute every shard of the overlap graph.
+    ///
+    /// Pass the result to `OverlapGraph::merge` to obtain a graph identical
+    /// to `compute`. Each shard can also be produced on its own with
+    /// `compute_shard`, e.g. on separate workers.
+    pub fn compute_sharded(&self, slices: &[SliceExport], num_shards: usize) -> Vec<OverlapGraph> {
+        let num_shards = num_shards.max(1);
+        (0..num_shards)
+            .map(|shard| self.compute_shard(slices, shard, num_shards))
+            .collect()
+    }
+
+    /// Compute the overlap edges for a single shard.
+    ///
+    /// Only slice pairs assigned to `shard` by `shard_for_pair` are compared.
+    pub fn compute_shard(&self, slices: &[SliceExport], shard: usize, num_shards: usize) -> OverlapGraph {
+        let num_shards = num_shards.max(1);
+        self.compute_pairs(slices, |a, b| shard_for_pair(a, b, num_shards) == shard)
+    }
+
+    /// Compare all slice pairs accepted by `include_pair`.
+    fn compute_pairs<F>(&self, slices: &[SliceExport], include_pair: F) -> OverlapGraph
+    where
+        F: Fn(&str, &str) -> bool,
+    {
         // Build turn sets for each slice
         let slice_turns: Vec<(String, BTreeSet<String>)> = slices
             .iter()
@@ -147,6 +454,10 @@ impl OverlapAnalyzer {
                 let (id_a, turns_a) = &slice_turns[i];
                 let (id_b, turns_b) = &slice_turns[j];
 
+                if !include_pair(id_a, id_b) {
+                    continue;
+                }
+
                 let intersection: BTreeSet<_> = turns_a.intersection(turns_b).collect();
                 let shared = intersection.len();
 
@@ -175,6 +486,19 @@ impl OverlapAnalyzer {
     }
 }
 
+/// Deterministically assign a slice pair to a shard.
+///
+/// The pair is hashed in canonical order, so `(a, b)` and `(b, a)` always
+/// land in the same shard.
+pub fn shard_for_pair(slice_a: &str, slice_b: &str, num_shards: usize) -> usize {
+    let pair = if slice_a < slice_b {
+        (slice_a, slice_b)
+    } else {
+        (slice_b, slice_a)
+    };
+    (canonical_hash(&pair) % num_shards.max(1) as u64) as usize
+}
+
 impl Default for OverlapAnalyzer {
     fn default() -> Self {
         Self::new()
@@ -184,7 +508,7 @@ impl Default for OverlapAnalyzer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{TurnId, TurnSnapshot, Edge, EdgeType, Phase, Role};
+    use crate::types::{TurnId, TurnSnapshot, Phase, Role};
     use uuid::Uuid;
 
     fn make_turn(id: &str) -> TurnSnapshot {
@@ -201,7 +525,7 @@ mod tests {
 
     fn make_slice(_id: &str, turn_ids: &[&str]) -> SliceExport {
         let turns: Vec<TurnSnapshot> = turn_ids.iter().map(|t| make_turn(t)).collect();
-        let anchor = turns[0].id.clone();
+        let anchor = turns[0].id;
 
         // Use SliceExport::new_for_test for unit tests
         SliceExport::new_for_test(
@@ -276,6 +600,39 @@ mod tests {
         assert_eq!(graph.edges.len(), 0);
     }
 
+    #[test]
+    fn test_min_jaccard_prunes_monotonically_and_changes_hash() {
+        let uuids: Vec<String> = (1..=10)
+            .map(|i| format!("00000000-0000-0000-0000-{:012}", i))
+            .collect();
+        // Windows of varying width give a spread of Jaccard values
+        let slices: Vec<SliceExport> = (0..6)
+            .map(|i| {
+                let ids: Vec<&str> = uuids[i..i + 2 + i % 3].iter().map(|s| s.as_str()).collect();
+                make_slice("", &ids)
+            })
+            .collect();
+
+        let unpruned = OverlapAnalyzer::new().compute(&slices);
+        let mut previous = unpruned.edges.len();
+        for threshold in [0.1, 0.2, 0.3, 0.5, 0.8, 1.0] {
+            let graph = OverlapAnalyzer::with_min_jaccard(threshold).compute(&slices);
+            assert!(graph.edges.len() <= previous);
+            assert!(graph.edges.iter().all(|e| e.jaccard >= threshold));
+            assert_ne!(graph.graph_hash, unpruned.graph_hash);
+
+            let again = OverlapAnalyzer::with_min_jaccard(threshold).compute(&slices);
+            assert_eq!(graph.graph_hash, again.graph_hash);
+            previous = graph.edges.len();
+        }
+        assert!(previous < unpruned.edges.len());
+
+        // A threshold that prunes nothing still yields a distinct identity
+        let barely = OverlapAnalyzer::with_min_jaccard(1e-6).compute(&slices);
+        assert_eq!(barely.edges, unpruned.edges);
+        assert_ne!(barely.graph_hash, unpruned.graph_hash);
+    }
+
     #[test]
     fn test_hub_detection() {
         let uuid1 = "00000000-0000-0000-0000-000000000001";
@@ -315,5 +672,183 @@ mod tests {
 
         assert_eq!(graph1.graph_hash, graph2.graph_hash);
     }
-}
 
+    #[test]
+    fn test_sharded_merge_matches_single_pass() {
+        let uuids: Vec<String> = (1..=8)
+            .map(|i| format!("00000000-0000-0000-0000-{:012}", i))
+            .collect();
+
+        // Overlapping windows produce many non-zero pairs
+        let slices: Vec<SliceExport> = (0..6)
+            .map(|i| {
+                let ids: Vec<&str> = uuids[i..i + 3].iter().map(|s| s.as_str()).collect();
+                make_slice("", &ids)
+            })
+            .collect();
+
+        let analyzer = OverlapAnalyzer::new();
+        let single = analyzer.compute(&slices);
+
+        let shards = analyzer.compute_sharded(&slices, 4);
+        assert_eq!(shards.len(), 4);
+        let shard_edges: usize = shards.iter().map(|s| s.edges.len()).sum();
+        assert_eq!(shard_edges, single.edges.len());
+
+        let merged = OverlapGraph::merge(shards).unwrap();
+        assert_eq!(merged.graph_hash, single.graph_hash);
+        assert_eq!(merged.edges, single.edges);
+        assert_eq!(merged.slice_count, single.slice_count);
+
+        // Shards pruned at different thresholds cannot be merged
+        let mut shards = analyzer.compute_sharded(&slices, 2);
+        shards.push(OverlapAnalyzer::with_min_jaccard(0.5).compute_sharded(&slices, 2).remove(0));
+        assert!(matches!(
+            OverlapGraph::merge(shards),
+            Err(OverlapMergeError::MinJaccardMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_csv_export_is_byte_identical_and_round_trips() {
+        let uuids: Vec<String> = (1..=6)
+            .map(|i| format!("00000000-0000-0000-0000-{:012}", i))
+            .collect();
+        let slices: Vec<SliceExport> = (0..4)
+            .map(|i| {
+                let ids: Vec<&str> = uuids[i..i + 3].iter().map(|s| s.as_str()).collect();
+                make_slice("", &ids)
+            })
+            .collect();
+
+        let analyzer = OverlapAnalyzer::new();
+        let mut first = Vec::new();
+        analyzer.compute(&slices).to_csv(&mut first).unwrap();
+        let mut reversed = slices.clone();
+        reversed.reverse();
+        let mut second = Vec::new();
+        analyzer.compute(&reversed).to_csv(&mut second).unwrap();
+        assert_eq!(first, second);
+
+        let text = String::from_utf8(first.clone()).unwrap();
+        assert!(text.starts_with("slice_a,slice_b,jaccard,shared_turns\n"));
+        assert!(text.contains(",0.5,2\n"));
+
+        let graph = analyzer.compute(&slices);
+        let edges = OverlapGraph::from_csv(first.as_slice()).unwrap();
+        assert_eq!(edges.len(), graph.edges.len());
+        for (parsed, original) in edges.iter().zip(&graph.edges) {
+            assert_eq!(parsed.slice_a, original.slice_a);
+            assert_eq!(parsed.slice_b, original.slice_b);
+            assert_eq!(parsed.shared_turns, original.shared_turns);
+            assert_eq!(parsed.jaccard.to_bits(), original.jaccard.to_bits());
+        }
+
+        // Re-exporting the parsed edges reproduces the same bytes
+        let mut third = Vec::new();
+        OverlapGraph::new(edges, graph.slice_count, 0.0).to_csv(&mut third).unwrap();
+        assert_eq!(first, third);
+
+        // Values needing more than six decimals survive the round trip
+        let jaccard = 1.0f32 / 7.0;
+        let graph = OverlapGraph::new(vec![OverlapEdge::new("a".into(), "b".into(), 1, jaccard)], 2, 0.0);
+        let mut bytes = Vec::new();
+        graph.to_csv(&mut bytes).unwrap();
+        let edges = OverlapGraph::from_csv(bytes.as_slice()).unwrap();
+        assert_eq!(edges[0].jaccard.to_bits(), jaccard.to_bits());
+    }
+
+    #[test]
+    fn test_csv_rejects_malformed_input() {
+        assert!(matches!(
+            OverlapGraph::from_csv("a,b,c\n".as_bytes()),
+            Err(OverlapCsvError::InvalidHeader(_))
+        ));
+        assert!(matches!(
+            OverlapGraph::from_csv("slice_a,slice_b,jaccard,shared_turns\nx,y,nope,1\n".as_bytes()),
+            Err(OverlapCsvError::InvalidRow { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_sample_edges_is_reproducible_and_spread() {
+        let edges: Vec<OverlapEdge> = (0..40)
+            .map(|i| OverlapEdge::new(format!("a{:02}", i), format!("b{:02}", i), i, i as f32 / 40.0))
+            .collect();
+        let graph = OverlapGraph::new(edges, 80, 0.0);
+
+        let sample = graph.sample_edges(5, 7);
+        assert_eq!(sample.len(), 5);
+        assert_eq!(sample, graph.sample_edges(5, 7));
+        assert!(sample.windows(2).all(|w| w[0].jaccard <= w[1].jaccard));
+
+        // One pick per stratum of eight edges
+        for (stratum, edge) in sample.iter().enumerate() {
+            assert_eq!(edge.shared_turns / 8, stratum);
+        }
+
+        assert_eq!(graph.sample_edges(100, 7).len(), 40);
+        assert!(graph.sample_edges(0, 7).is_empty());
+    }
+
+    #[test]
+    fn test_shard_for_pair_is_order_independent() {
+        for n in 1..8 {
+            assert_eq!(shard_for_pair("a", "b", n), shard_for_pair("b", "a", n));
+            assert!(shard_for_pair("a", "b", n) < n);
+        }
+    }
+
+    #[test]
+    fn test_clusters_are_stable() {
+        let edge = |a: &str, b: &str, j: f32| OverlapEdge::new(a.to_string(), b.to_string(), 1, j);
+        // {a, b, c} and {d, e}, bridged only by a weak c-d edge
+        let edges = vec![
+            edge("a", "b", 0.6),
+            edge("b", "c", 0.4),
+            edge("c", "d", 0.05),
+            edge("d", "e", 0.5),
+        ];
+        let graph = OverlapGraph::new(edges.clone(), 5, 0.0);
+
+        let clusters = graph.clusters(0.1);
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].slice_ids, vec!["a", "b", "c"]);
+        assert_eq!(clusters[1].slice_ids, vec!["d", "e"]);
+        assert_ne!(clusters[0].cluster_hash, clusters[1].cluster_hash);
+
+        // Edge order does not matter
+        let reversed = OverlapGraph::new(edges.into_iter().rev().collect(), 5, 0.0);
+        assert_eq!(reversed.clusters(0.1), clusters);
+
+        // Without a threshold the weak edge joins everything
+        assert_eq!(graph.clusters(0.0).len(), 1);
+    }
+
+    #[test]
+    fn test_cluster_representatives() {
+        let edge = |a: &str, b: &str, j: f32| OverlapEdge::new(a.to_string(), b.to_string(), 1, j);
+        // b touches both a and c; the weak c-d edge is below the threshold
+        let edges = vec![
+            edge("a", "b", 0.5),
+            edge("b", "c", 0.3),
+            edge("a", "c", 0.2),
+            edge("c", "d", 0.05),
+        ];
+        let graph = OverlapGraph::new(edges.clone(), 4, 0.0);
+
+        let clusters = graph.clusters(0.1);
+        let representatives = graph.cluster_representatives(0.1);
+        assert_eq!(representatives.len(), 2);
+        assert_eq!(representatives[&clusters[0].cluster_hash], "b");
+        assert_eq!(representatives[&clusters[1].cluster_hash], "d");
+
+        // Edge order does not matter
+        let reversed = OverlapGraph::new(edges.into_iter().rev().collect(), 4, 0.0);
+        assert_eq!(reversed.cluster_representatives(0.1), representatives);
+
+        // Equal weights fall back to the smallest slice ID
+        let tied = OverlapGraph::new(vec![edge("y", "x", 0.4)], 2, 0.0);
+        assert_eq!(tied.cluster_representatives(0.0).into_values().collect::<Vec<_>>(), vec!["x"]);
+    }
+}
@@ -179,12 +179,12 @@ mod tests {
         let turn3 = make_turn_id();
 
         let edges = vec![
-            Edge::new(turn1.clone(), turn2.clone(), EdgeType::Reply),
-            Edge::new(turn2.clone(), turn3.clone(), EdgeType::Reply),
+            Edge::new(turn1, turn2, EdgeType::Reply),
+            Edge::new(turn2, turn3, EdgeType::Reply),
         ];
 
         let input = SnapshotInput {
-            turn_ids: vec![turn1.clone(), turn2.clone(), turn3.clone()],
+            turn_ids: vec![turn1, turn2, turn3],
             edges: edges.clone(),
             timestamps: vec![1000, 2000, 3000],
         };
@@ -204,15 +204,15 @@ mod tests {
         let turn2 = make_turn_id();
 
         let input1 = SnapshotInput {
-            turn_ids: vec![turn1.clone(), turn2.clone()],
-            edges: vec![Edge::new(turn1.clone(), turn2.clone(), EdgeType::Reply)],
+            turn_ids: vec![turn1, turn2],
+            edges: vec![Edge::new(turn1, turn2, EdgeType::Reply)],
             timestamps: vec![1000, 2000],
         };
 
         let turn3 = make_turn_id();
         let input2 = SnapshotInput {
-            turn_ids: vec![turn1.clone(), turn2.clone(), turn3.clone()],
-            edges: vec![Edge::new(turn1.clone(), turn2.clone(), EdgeType::Reply)],
+            turn_ids: vec![turn1, turn2, turn3],
+            edges: vec![Edge::new(turn1, turn2, EdgeType::Reply)],
             timestamps: vec![1000, 2000, 3000],
         };
 
@@ -230,20 +230,20 @@ mod tests {
 
         // Input with turns in one order
         let input1 = SnapshotInput {
-            turn_ids: vec![turn1.clone(), turn2.clone(), turn3.clone()],
+            turn_ids: vec![turn1, turn2, turn3],
             edges: vec![
-                Edge::new(turn1.clone(), turn2.clone(), EdgeType::Reply),
-                Edge::new(turn2.clone(), turn3.clone(), EdgeType::Reply),
+                Edge::new(turn1, turn2, EdgeType::Reply),
+                Edge::new(turn2, turn3, EdgeType::Reply),
             ],
             timestamps: vec![1000, 2000, 3000],
         };
 
         // Input with turns in different order (but same timestamps aligned)
         let input2 = SnapshotInput {
-            turn_ids: vec![turn3.clone(), turn1.clone(), turn2.clone()],
+            turn_ids: vec![turn3, turn1, turn2],
             edges: vec![
-                Edge::new(turn2.clone(), turn3.clone(), EdgeType::Reply),
-                Edge::new(turn1.clone(), turn2.clone(), EdgeType::Reply),
+                Edge::new(turn2, turn3, EdgeType::Reply),
+                Edge::new(turn1, turn2, EdgeType::Reply),
             ],
             timestamps: vec![3000, 1000, 2000],
         };
@@ -261,8 +261,8 @@ mod tests {
         let turn2 = make_turn_id();
 
         let input = SnapshotInput {
-            turn_ids: vec![turn1.clone(), turn2.clone()],
-            edges: vec![Edge::new(turn1.clone(), turn2.clone(), EdgeType::Reply)],
+            turn_ids: vec![turn1, turn2],
+            edges: vec![Edge::new(turn1, turn2, EdgeType::Reply)],
             timestamps: vec![1000, 2000],
         };
 
@@ -271,7 +271,7 @@ mod tests {
 
         // Modify input - should fail verification
         let modified_input = SnapshotInput {
-            turn_ids: vec![turn1.clone()],
+            turn_ids: vec![turn1],
             edges: vec![],
             timestamps: vec![1000],
         };
@@ -41,8 +41,8 @@ use tracing_subscriber::{
     EnvFilter,
 };
 
-use cc_graph_kernel::service::{create_router, PolicyRegistry, ServiceState};
-use cc_graph_kernel::PostgresGraphStore;
+use admissibility_kernel::service::{create_router, PolicyRegistry, ServiceState};
+use admissibility_kernel::PostgresGraphStore;
 
 /// Initialize the tracing subscriber with JSON or pretty format
 fn init_tracing() {
@@ -243,7 +243,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     info!("Ready to accept connections");
     
-    axum::serve(listener, app)
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(shutdown_signal)
         .await?;
 
@@ -9,10 +9,30 @@
 //! - Stable Vec order: Vectors serialize in index order
 //! - No HashMap allowed: Use BTreeMap for maps in hashed data
 //! - Stable float format: f32/f64 serialize consistently
+//!
+//! ## Algorithms
+//!
+//! `canonical_hash_hex` uses xxh64 and returns bare hex, as it always has.
+//! Where an adversary might craft collisions, `canonical_hash_hex_with`
+//! selects SHA-256; its output is tagged (`xx:` / `sha:`) so a verifier can
+//! tell which algorithm produced a stored hash. Untagged hashes are xxh64.
+//! Stripping or rewriting a tag downgrades the check, so verifiers facing an
+//! adversary use `verify_canonical_hash_hex_at_least` with `HashAlgo::Sha256`.
+//!
+//! ## JCS
+//!
+//! `to_canonical_bytes` follows serde field order, which other languages
+//! cannot easily reproduce. `to_jcs_string` renders a slice's selection
+//! fields as RFC 8785 (JCS) canonical JSON instead, and `jcs_hash` hashes
+//! it, so JSON-only systems can derive a compatible identifier.
+//! `canonical_json` applies the same rules to any serializable value.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use xxhash_rust::xxh64::xxh64;
 
+use crate::types::SliceExport;
+
 /// Serialize a value to canonical JSON bytes for hashing.
 ///
 /// This function produces deterministic output for the same input,
@@ -32,6 +52,203 @@ pub fn canonical_hash_hex<T: Serialize>(value: &T) -> String {
     format!("{:016x}", canonical_hash(value))
 }
 
+/// Hash algorithm for canonical hashing.
+///
+/// Ordered by strength: `Xxh64 < Sha256`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HashAlgo {
+    /// xxh64: fast, not collision-resistant against adversaries.
+    #[default]
+    Xxh64,
+    /// SHA-256: collision-resistant, for adversarial provenance.
+    Sha256,
+}
+
+impl HashAlgo {
+    /// Prefix identifying this algorithm in a tagged hash string.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Self::Xxh64 => "xx",
+            Self::Sha256 => "sha",
+        }
+    }
+
+    /// Split a hash string into its algorithm and hex digest.
+    ///
+    /// Untagged strings are legacy `canonical_hash_hex` output (xxh64).
+    /// Returns `None` for an unknown tag.
+    pub fn parse_tagged(hash: &str) -> Option<(HashAlgo, &str)> {
+        match hash.split_once(':') {
+            None => Some((Self::Xxh64, hash)),
+            Some(("xx", digest)) => Some((Self::Xxh64, digest)),
+            Some(("sha", digest)) => Some((Self::Sha256, digest)),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Compute a tagged canonical hash (`<tag>:<hex>`) with the given algorithm.
+pub fn canonical_hash_hex_with<T: Serialize>(value: &T, algo: HashAlgo) -> String {
+    let digest = match algo {
+        HashAlgo::Xxh64 => canonical_hash_hex(value),
+        HashAlgo::Sha256 => hex::encode(Sha256::digest(to_canonical_bytes(value))),
+    };
+    format!("{}:{}", algo.tag(), digest)
+}
+
+/// Check a stored hash (tagged or legacy bare hex) against a value.
+///
+/// Accepts whichever algorithm the hash names, including untagged xxh64.
+/// Use `verify_canonical_hash_hex_at_least` when the hash may come from an
+/// adversary.
+pub fn verify_canonical_hash_hex<T: Serialize>(value: &T, hash: &str) -> bool {
+    verify_canonical_hash_hex_at_least(value, hash, HashAlgo::Xxh64)
+}
+
+/// Check a stored hash against a value, rejecting algorithms weaker than `min`.
+///
+/// Untagged hashes count as xxh64, so with `min = HashAlgo::Sha256` a
+/// `sha:` hash whose tag was stripped or rewritten fails.
+pub fn verify_canonical_hash_hex_at_least<T: Serialize>(value: &T, hash: &str, min: HashAlgo) -> bool {
+    match HashAlgo::parse_tagged(hash).filter(|(algo, _)| *algo >= min) {
+        Some((HashAlgo::Xxh64, digest)) => canonical_hash_hex(value) == digest,
+        Some((HashAlgo::Sha256, digest)) => {
+            hex::encode(Sha256::digest(to_canonical_bytes(value))) == digest
+        }
+        None => false,
+    }
+}
+
+/// Render a slice's selection fields as RFC 8785 (JCS) canonical JSON.
+///
+/// The object has these keys (emitted in JCS order, i.e. sorted):
+///
+/// | Key                  | Source                                          |
+/// |----------------------|-------------------------------------------------|
+/// | `anchor_turn_id`     | `anchor_turn_id` as a hyphenated UUID string    |
+/// | `canonical_version`  | `canonical_version` (integer)                   |
+/// | `edges`              | `edges` in slice order, each `{child, edge_type, parent}` |
+/// | `policy_id`          | `policy_id`                                     |
+/// | `policy_params_hash` | `policy_params_hash`                            |
+/// | `schema_version`     | `schema_version`                                |
+/// | `turn_ids`           | IDs of `turns`, in slice order                  |
+///
+/// These are the inputs to `slice_id`. Turn contents, the token, and the
+/// snapshot hash are excluded. `edge_type` uses its serde name (`"Reply"`).
+pub fn to_jcs_string(slice: &SliceExport) -> String {
+    let value = serde_json::json!({
+        "anchor_turn_id": slice.anchor_turn_id,
+        "canonical_version": slice.canonical_version,
+        "edges": slice.edges,
+        "policy_id": slice.policy_id,
+        "policy_params_hash": slice.policy_params_hash,
+        "schema_version": slice.schema_version,
+        "turn_ids": slice.turns.iter().map(|t| t.id).collect::<Vec<_>>(),
+    });
+
+    let mut out = String::new();
+    write_jcs(&value, &mut out);
+    out
+}
+
+/// Serialize any value as RFC 8785 (JCS) canonical JSON.
+///
+/// Object keys are sorted (so `HashMap` iteration order does not leak into
+/// the output) and numbers use the shortest round-trippable form. `f32`
+/// fields render at their own shortest precision (`0.1`, not the widened
+/// `0.10000000149011612`).
+pub fn canonical_json<T: Serialize>(value: &T) -> String {
+    // Round-trip through text so f32s are parsed back from serde_json's
+    // shortest f32 rendering rather than widened to f64
+    let text = serde_json::to_string(value).expect("Canonical serialization failed");
+    let value: serde_json::Value = serde_json::from_str(&text).expect("serde_json output parses");
+
+    let mut out = String::new();
+    write_jcs(&value, &mut out);
+    out
+}
+
+/// SHA-256 of `to_jcs_string`, as bare lowercase hex.
+pub fn jcs_hash(slice: &SliceExport) -> String {
+    hex::encode(Sha256::digest(to_jcs_string(slice).as_bytes()))
+}
+
+/// Serialize a JSON value per RFC 8785.
+fn write_jcs(value: &serde_json::Value, out: &mut String) {
+    use serde_json::Value;
+
+    match value {
+        Value::Null | Value::Bool(_) => out.push_str(&value.to_string()),
+        Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => out.push_str(&i.to_string()),
+            (_, Some(u)) => out.push_str(&u.to_string()),
+            _ => out.push_str(&jcs_number(n.as_f64().unwrap_or_default())),
+        },
+        // serde_json's string escaping matches JCS: short escapes for
+        // \b \f \n \r \t, lowercase \u00xx for other controls, no others
+        Value::String(s) => out.push_str(&Value::String(s.clone()).to_string()),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_jcs(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            // JCS sorts keys by UTF-16 code units
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_jcs(item, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+/// Format a finite f64 the way ECMAScript `Number.prototype.toString` does.
+fn jcs_number(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    if value < 0.0 {
+        return format!("-{}", jcs_number(-value));
+    }
+
+    // Shortest round-trip digits and exponent, e.g. "1.2345e3"
+    let sci = format!("{:e}", value);
+    let (mantissa, exponent) = sci.split_once('e').expect("LowerExp has an exponent");
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().expect("LowerExp exponent is an integer") + 1;
+
+    if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat((-n) as usize), digits)
+    } else {
+        let sign = if n - 1 < 0 { '-' } else { '+' };
+        let (first, rest) = digits.split_at(1);
+        if rest.is_empty() {
+            format!("{}e{}{}", first, sign, (n - 1).abs())
+        } else {
+            format!("{}.{}e{}{}", first, rest, sign, (n - 1).abs())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,5 +270,152 @@ mod tests {
         let h2 = canonical_hash(&s);
         assert_eq!(h1, h2);
     }
-}
 
+    #[test]
+    fn test_hash_algorithms() {
+        let s = TestStruct {
+            name: "test".to_string(),
+            value: 42,
+        };
+
+        let bare = canonical_hash_hex(&s);
+        let xx = canonical_hash_hex_with(&s, HashAlgo::default());
+        assert_eq!(xx, format!("xx:{}", bare));
+
+        let sha = canonical_hash_hex_with(&s, HashAlgo::Sha256);
+        assert!(sha.starts_with("sha:"));
+        assert_eq!(sha.len(), "sha:".len() + 64);
+        assert_eq!(sha, canonical_hash_hex_with(&s, HashAlgo::Sha256));
+
+        // SHA-256 of the canonical bytes `{"name":"test","value":42}`
+        assert_eq!(
+            sha,
+            format!("sha:{}", hex::encode(Sha256::digest(br#"{"name":"test","value":42}"#)))
+        );
+    }
+
+    #[test]
+    fn test_verify_tagged_and_legacy_hashes() {
+        let s = TestStruct {
+            name: "test".to_string(),
+            value: 42,
+        };
+        let other = TestStruct {
+            name: "test".to_string(),
+            value: 43,
+        };
+
+        for hash in [
+            canonical_hash_hex(&s),
+            canonical_hash_hex_with(&s, HashAlgo::Xxh64),
+            canonical_hash_hex_with(&s, HashAlgo::Sha256),
+        ] {
+            assert!(verify_canonical_hash_hex(&s, &hash));
+            assert!(!verify_canonical_hash_hex(&other, &hash));
+        }
+
+        assert_eq!(HashAlgo::parse_tagged("sha:ab"), Some((HashAlgo::Sha256, "ab")));
+        assert_eq!(HashAlgo::parse_tagged("md5:ab"), None);
+        assert!(!verify_canonical_hash_hex(&s, "md5:ab"));
+    }
+
+    #[test]
+    fn test_minimum_algorithm_rejects_downgrade() {
+        let s = TestStruct {
+            name: "test".to_string(),
+            value: 42,
+        };
+
+        let sha = canonical_hash_hex_with(&s, HashAlgo::Sha256);
+        assert!(verify_canonical_hash_hex_at_least(&s, &sha, HashAlgo::Sha256));
+
+        // An xxh64 hash, tagged or bare, no longer satisfies a SHA-256 check
+        for weak in [canonical_hash_hex(&s), canonical_hash_hex_with(&s, HashAlgo::Xxh64)] {
+            assert!(verify_canonical_hash_hex(&s, &weak));
+            assert!(!verify_canonical_hash_hex_at_least(&s, &weak, HashAlgo::Sha256));
+        }
+    }
+
+    #[test]
+    fn test_jcs_number_formatting() {
+        // RFC 8785 Appendix B samples
+        assert_eq!(jcs_number(0.0), "0");
+        assert_eq!(jcs_number(-0.0), "0");
+        assert_eq!(jcs_number(1e21), "1e+21");
+        assert_eq!(jcs_number(1e-7), "1e-7");
+        assert_eq!(jcs_number(333333333.3333333), "333333333.3333333");
+        assert_eq!(jcs_number(4.5), "4.5");
+        assert_eq!(jcs_number(2e-3), "0.002");
+        assert_eq!(jcs_number(-1.5e22), "-1.5e+22");
+        assert_eq!(jcs_number(1e20), "100000000000000000000");
+    }
+
+    #[test]
+    fn test_jcs_sorts_keys_and_escapes() {
+        let value = serde_json::json!({ "b": [1, -2, 0.5], "a": "x\n\u{1}\"", "\u{20ac}": null });
+        let mut out = String::new();
+        write_jcs(&value, &mut out);
+        assert_eq!(out, "{\"a\":\"x\\n\\u0001\\\"\",\"b\":[1,-2,0.5],\"\u{20ac}\":null}");
+    }
+
+    #[test]
+    fn test_canonical_json_is_byte_stable() {
+        use std::collections::HashMap;
+
+        #[derive(Serialize)]
+        struct Dto {
+            weights: HashMap<String, f32>,
+            ratio: f32,
+            count: u64,
+        }
+
+        // Fresh maps get fresh hash seeds, so iteration order differs
+        let render = || {
+            let weights = (0..32).map(|i| (format!("k{}", i), i as f32 / 10.0)).collect();
+            canonical_json(&Dto { weights, ratio: 0.1, count: 3 })
+        };
+        let first = render();
+        for _ in 0..10 {
+            assert_eq!(render(), first);
+        }
+
+        assert!(first.starts_with(r#"{"count":3,"ratio":0.1,"weights":{"k0":0,"k1":0.1,"k10":1,"#));
+    }
+
+    #[test]
+    fn test_slice_jcs_golden() {
+        use crate::types::{Edge, Phase, Role, TurnId, TurnSnapshot};
+        use uuid::Uuid;
+
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let turn = |n: u128| {
+            TurnSnapshot::new(
+                id(n), "s".to_string(), Role::User, Phase::Planning, 0.5, 1, 0, 0.5, 0.5, 1.0, 1000,
+            )
+        };
+        let slice = SliceExport::new_for_test(
+            id(1),
+            vec![turn(2), turn(1)],
+            vec![Edge::reply(id(1), id(2))],
+            "slice_policy_v1".to_string(),
+            "02005444cae1117d".to_string(),
+        );
+
+        let jcs = to_jcs_string(&slice);
+        assert_eq!(
+            jcs,
+            concat!(
+                r#"{"anchor_turn_id":"00000000-0000-0000-0000-000000000001","#,
+                r#""canonical_version":2,"#,
+                r#""edges":[{"child":"00000000-0000-0000-0000-000000000002","#,
+                r#""edge_type":"Reply","parent":"00000000-0000-0000-0000-000000000001"}],"#,
+                r#""policy_id":"slice_policy_v1","policy_params_hash":"02005444cae1117d","#,
+                r#""schema_version":"1.0.0","#,
+                r#""turn_ids":["00000000-0000-0000-0000-000000000001","#,
+                r#""00000000-0000-0000-0000-000000000002"]}"#,
+            )
+        );
+
+        assert_eq!(jcs_hash(&slice), hex::encode(Sha256::digest(jcs.as_bytes())));
+    }
+}
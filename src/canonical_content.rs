@@ -37,6 +37,13 @@
 //!
 //! This module enforces **INV-GK-004: Content Immutability**.
 //! If `content_hash` exists, it MUST match `SHA256(canonical_content(content_text))`.
+//!
+//! ## Alternative Algorithms
+//!
+//! `compute_content_hash_with` can hash with BLAKE3 (behind the `blake3`
+//! feature) for upstream systems that already do. Non-default hashes are
+//! prefixed with the algorithm (`blake3:<hex>`) so `verify_content_hash`
+//! can dispatch; SHA-256 hashes stay bare hex.
 
 use sha2::{Sha256, Digest};
 
@@ -64,7 +71,7 @@ pub const CANONICAL_CONTENT_VERSION: &str = "1.0.0";
 /// # Example
 ///
 /// ```rust
-/// use cc_graph_kernel::canonical_content::normalize_text;
+/// use admissibility_kernel::canonical_content::normalize_text;
 ///
 /// let text = "  Hello\r\nWorld  ";
 /// let normalized = normalize_text(text);
@@ -97,6 +104,41 @@ pub fn canonical_content(text: &str) -> Vec<u8> {
     normalize_text(text).into_bytes()
 }
 
+/// Algorithm used for a content hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ContentHashAlgo {
+    /// SHA-256, stored as bare hex.
+    #[default]
+    Sha256,
+    /// BLAKE3, stored as `blake3:<hex>`.
+    #[cfg(feature = "blake3")]
+    Blake3,
+}
+
+impl ContentHashAlgo {
+    /// Split a stored hash into its algorithm and hex digest.
+    ///
+    /// Bare hex is SHA-256. Returns `None` for an unknown (or disabled)
+    /// prefix.
+    pub fn parse_prefixed(hash: &str) -> Option<(ContentHashAlgo, &str)> {
+        match hash.split_once(':') {
+            None => Some((Self::Sha256, hash)),
+            #[cfg(feature = "blake3")]
+            Some(("blake3", digest)) => Some((Self::Blake3, digest)),
+            Some(_) => None,
+        }
+    }
+
+    /// Hex digest of canonical bytes, without prefix.
+    fn digest_hex(&self, canonical: &[u8]) -> String {
+        match self {
+            Self::Sha256 => hex::encode(Sha256::digest(canonical)),
+            #[cfg(feature = "blake3")]
+            Self::Blake3 => blake3::hash(canonical).to_hex().to_string(),
+        }
+    }
+}
+
 /// Compute SHA-256 content hash of canonical content.
 ///
 /// This is the production method for computing content hashes.
@@ -116,31 +158,44 @@ pub fn canonical_content(text: &str) -> Vec<u8> {
 /// # Example
 ///
 /// ```rust
-/// use cc_graph_kernel::canonical_content::compute_content_hash;
+/// use admissibility_kernel::canonical_content::compute_content_hash;
 ///
 /// let hash = compute_content_hash("Hello World");
 /// assert_eq!(hash.len(), 64); // SHA-256 = 32 bytes = 64 hex chars
 /// ```
 pub fn compute_content_hash(text: &str) -> String {
-    let canonical = canonical_content(text);
-    let mut hasher = Sha256::new();
-    hasher.update(&canonical);
-    let result = hasher.finalize();
-    hex::encode(result)
+    compute_content_hash_with(text, ContentHashAlgo::Sha256)
+}
+
+/// Compute a content hash with the given algorithm.
+///
+/// SHA-256 returns bare hex, identical to `compute_content_hash`. Other
+/// algorithms return `<name>:<hex>`.
+pub fn compute_content_hash_with(text: &str, algo: ContentHashAlgo) -> String {
+    let digest = algo.digest_hex(&canonical_content(text));
+    match algo {
+        ContentHashAlgo::Sha256 => digest,
+        #[cfg(feature = "blake3")]
+        ContentHashAlgo::Blake3 => format!("blake3:{}", digest),
+    }
 }
 
 /// Verify that a content hash matches the expected hash for given text.
 ///
+/// The algorithm is taken from the hash prefix (bare hex is SHA-256).
 /// Uses constant-time comparison to prevent timing attacks.
 ///
 /// # Arguments
 /// * `text` - Raw content text
-/// * `expected_hash` - Expected SHA-256 hash (hex string)
+/// * `expected_hash` - Expected hash, as produced by `compute_content_hash_with`
 ///
 /// # Returns
 /// `true` if the computed hash matches the expected hash
 pub fn verify_content_hash(text: &str, expected_hash: &str) -> bool {
-    let computed = compute_content_hash(text);
+    let Some((algo, _)) = ContentHashAlgo::parse_prefixed(expected_hash) else {
+        return false;
+    };
+    let computed = compute_content_hash_with(text, algo);
 
     // Constant-time comparison
     if computed.len() != expected_hash.len() {
@@ -180,7 +235,10 @@ pub fn validate_content_hash(text: &str, stored_hash: Option<&str>) -> HashValid
     match stored_hash {
         None => HashValidation::Missing,
         Some(expected) => {
-            let computed = compute_content_hash(text);
+            let algo = ContentHashAlgo::parse_prefixed(expected)
+                .map(|(algo, _)| algo)
+                .unwrap_or_default();
+            let computed = compute_content_hash_with(text, algo);
             if verify_content_hash(text, expected) {
                 HashValidation::Valid
             } else {
@@ -393,4 +451,34 @@ mod tests {
         assert_eq!(hash2, empty_hash);
         assert_eq!(hash3, empty_hash);
     }
+
+    #[test]
+    fn test_default_algo_reproduces_sha256() {
+        let text = "  Hello\r\nWorld  ";
+        let hash = compute_content_hash_with(text, ContentHashAlgo::default());
+        assert_eq!(hash, compute_content_hash(text));
+        assert_eq!(hash, hex::encode(Sha256::digest(b"Hello\nWorld")));
+        assert!(verify_content_hash(text, &hash));
+
+        // Unknown prefixes never verify
+        assert!(!verify_content_hash(text, &format!("md5:{}", hash)));
+        assert_eq!(ContentHashAlgo::parse_prefixed("md5:ab"), None);
+    }
+
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn test_blake3_content_hash() {
+        let text = "  Hello\r\nWorld  ";
+        let hash = compute_content_hash_with(text, ContentHashAlgo::Blake3);
+        assert_eq!(hash, format!("blake3:{}", blake3::hash(b"Hello\nWorld").to_hex()));
+        assert!(verify_content_hash(text, &hash));
+        assert!(!verify_content_hash("Hello", &hash));
+        assert_eq!(validate_content_hash(text, Some(&hash)), HashValidation::Valid);
+
+        // A digest is only accepted under the algorithm that produced it
+        let sha = compute_content_hash(text);
+        let blake_digest = hash.trim_start_matches("blake3:");
+        assert!(!verify_content_hash(text, blake_digest));
+        assert!(!verify_content_hash(text, &format!("blake3:{}", sha)));
+    }
 }
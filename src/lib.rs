@@ -1,4 +1,4 @@
-//! # cc-graph-kernel
+//! # admissibility-kernel
 //!
 //! Deterministic context slicing for conversation DAGs.
 //!
@@ -37,16 +37,21 @@ pub mod canonical;
 pub mod canonical_content;
 pub mod atlas;
 
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
 #[cfg(feature = "service")]
 pub mod service;
 
 // Re-exports
 pub use types::{TurnId, TurnSnapshot, Edge, EdgeType, Role, Phase};
-pub use types::slice::{SliceExport, SliceFingerprint, GraphSnapshotHash, AdmissibilityToken};
-pub use types::admissible::{AdmissibleEvidenceBundle, VerificationError};
-pub use types::verification::{TokenVerifier, VerificationMode, VerificationResult, CacheConfig, CacheStats};
+pub use types::slice::{SliceExport, SelfDescribingSlice, SliceFingerprint, GraphSnapshotHash, AdmissibilityToken, AdmissionReason, DEDUP_SELECTION_PREFIX, EXTERNAL_SELECTION_PREFIX, PRUNED_SELECTION_PREFIX};
+pub use types::admissible::{AdmissibleEvidenceBundle, VerificationError, ContextSizeEstimate, ChatMessage, FreshnessProof, FreshnessError};
+pub use types::verification::{TokenVerifier, VerificationMode, VerificationResult, FailureKind, CacheConfig, CacheStats, SecretResolver};
+#[cfg(feature = "remote-verify")]
+pub use types::verification::RemoteEndpointError;
 pub use types::sufficiency::{
-    DiversityMetrics, SalienceStats, SufficiencyPolicy, SufficiencyCheck,
+    DiversityAccumulator, DiversityMetrics, SalienceStats, SufficiencyPolicy, SufficiencyCheck,
     SufficiencyViolation, EvidenceBundle, EvidenceBundleError,
 };
 pub use types::boundary::{
@@ -61,37 +66,65 @@ pub use types::incident::{
     IncidentMetrics, NoOpMetrics, TestMetrics,
     QUARANTINE_TABLE_SCHEMA, INCIDENT_TABLE_SCHEMA,
 };
+pub use types::audit::{AuditSink, NoOpAuditSink, InMemoryAuditSink};
+pub use types::chain::{SliceChain, ChainLink, GENESIS_CHAIN_HASH};
 pub use canonical_content::CANONICAL_CONTENT_VERSION;
-pub use policy::{SlicePolicyV1, PhaseWeights};
-pub use store::GraphStore;
+pub use policy::{SlicePolicyV1, SlicePolicyBuilder, PhaseWeights, ExpansionDirection, TieBreak, PolicyVersion, PolicyVersionError};
+pub use store::{GraphStore, NeighborOrder, ShardedGraphStore};
 #[cfg(feature = "postgres")]
 pub use store::PostgresGraphStore;
-pub use slicer::ContextSlicer;
-pub use canonical::{to_canonical_bytes, canonical_hash, canonical_hash_hex};
+#[cfg(feature = "arrow")]
+pub use store::ParquetGraphStore;
+pub use slicer::{ContextSlicer, validate_hmac_secret, DriftReport, TurnDrift, ReplayHarness, ReplayOutcome, StabilityReport};
+pub use canonical::{
+    to_canonical_bytes, canonical_hash, canonical_hash_hex,
+    canonical_hash_hex_with, verify_canonical_hash_hex, verify_canonical_hash_hex_at_least, HashAlgo,
+    to_jcs_string, jcs_hash, canonical_json,
+};
 pub use canonical_content::{
-    normalize_text, canonical_content, compute_content_hash,
-    verify_content_hash, validate_content_hash, HashValidation,
+    normalize_text, canonical_content, compute_content_hash, compute_content_hash_with,
+    verify_content_hash, validate_content_hash, ContentHashAlgo, HashValidation,
 };
 
 // Atlas re-exports
 pub use atlas::{
     GraphSnapshot, SnapshotInput, SnapshotStore,
-    BatchSlicer, BatchSliceResult, SliceRegistry, SliceRegistryEntry, AnchorSet,
-    OverlapAnalyzer, OverlapGraph, OverlapEdge,
-    TurnInfluence, InfluenceScores, PhaseCounts, BridgeTurn, PhaseTopologyStats,
-    compute_influence, extract_bridges, compute_phase_topology,
-    AtlasBundler, AtlasManifest, AtlasArtifactPaths, PhaseTopology, AtlasStats,
+    BatchSlicer, BatchSliceResult, BatchSliceError, BatchSummary, SliceRegistry, SliceRegistryEntry, AnchorSet, AnchorsJsonlError,
+    OverlapAnalyzer, OverlapGraph, OverlapEdge, OverlapCsvError, OverlapMergeError, Cluster,
+    TurnInfluence, InfluenceScores, PhaseCounts, BridgeTurn, PhaseTopologyStats, InfluenceError,
+    compute_influence, compute_influence_with_overrides, extract_bridges, compute_phase_topology, DEFAULT_MAX_CENTROIDS_PER_PHASE,
+    AtlasBundler, AtlasManifest, AtlasDiff, AtlasComponent, AtlasArtifactPaths, PhaseTopology, AtlasStats,
+    Component, connected_components,
     ATLAS_SCHEMA_VERSION,
 };
 
 // Service re-exports (when service feature is enabled)
 #[cfg(feature = "service")]
-pub use service::{create_router, ServiceState, PolicyRegistry, PolicyRef};
+pub use service::{create_router, ServiceState, PolicyRegistry, PolicyRef, SufficiencyPolicyRegistry};
 
 /// Schema version for all graph kernel types.
 /// Increment on breaking changes to any schema type.
 pub const GRAPH_KERNEL_SCHEMA_VERSION: &str = "1.0.0";
 
+/// Version of the canonical byte layout hashed into `slice_id`.
+/// Bump when `SliceExport::compute_fingerprint` changes its input.
+pub const CANONICAL_SLICE_VERSION: u32 = 2;
+
+/// Canonical layout of slices issued before the layout was versioned.
+/// Its fingerprint input carries no version element.
+pub const LEGACY_CANONICAL_SLICE_VERSION: u32 = 1;
+
 /// Default policy version identifier.
 pub const DEFAULT_POLICY_VERSION: &str = "slice_policy_v1";
 
+/// Semantic version of the slice policy expansion semantics.
+/// Bump the major version when the same policy params would select different turns.
+pub const SLICE_POLICY_SEMANTICS_VERSION: &str = "1.0.0";
+
+/// Minimum HMAC secret length, in bytes, accepted without a warning.
+/// `ContextSlicer::new_strict` rejects shorter secrets.
+pub const MIN_HMAC_SECRET_LEN: usize = 32;
+
+/// Sufficiency policy version identifier.
+pub const SUFFICIENCY_POLICY_VERSION: &str = "sufficiency_policy_v1";
+
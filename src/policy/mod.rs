@@ -2,7 +2,9 @@
 
 pub mod v1;
 pub mod scoring;
+pub mod version;
 
-pub use v1::{SlicePolicyV1, PhaseWeights};
+pub use v1::{SlicePolicyV1, SlicePolicyBuilder, PhaseWeights, ExpansionDirection, TieBreak};
+pub use version::{PolicyVersion, PolicyVersionError};
 pub use scoring::priority_score;
 
@@ -1,7 +1,7 @@
 //! Priority scoring for slice expansion.
 
 use crate::types::TurnSnapshot;
-use super::v1::SlicePolicyV1;
+use super::v1::{SlicePolicyV1, TieBreak};
 
 /// Compute priority score for a turn at a given distance from anchor.
 ///
@@ -12,13 +12,19 @@ use super::v1::SlicePolicyV1;
 /// priority = (phase_weight + salience * salience_weight) * distance_decay^distance
 /// ```
 ///
+/// Turns flagged `phase_unknown` use `policy.unknown_phase_weight` as their
+/// phase weight when it is set.
+///
 /// ## Parameters
 ///
 /// - `turn`: The turn being scored
 /// - `distance`: Graph distance from the anchor turn (0 for anchor itself)
 /// - `policy`: The slice policy with weights and decay
 pub fn priority_score(turn: &TurnSnapshot, distance: u32, policy: &SlicePolicyV1) -> f32 {
-    let phase_score = policy.phase_weights.get(turn.phase);
+    let phase_score = match policy.unknown_phase_weight {
+        Some(weight) if turn.phase_unknown => weight,
+        _ => policy.phase_weights.get(turn.phase),
+    };
     let salience_score = turn.salience * policy.salience_weight;
     let distance_penalty = policy.distance_decay.powi(distance as i32);
 
@@ -34,6 +40,8 @@ pub struct ExpansionCandidate {
     pub distance: u32,
     /// Computed priority score.
     pub priority: f32,
+    /// Ordering among candidates with equal priority and distance.
+    pub tie_break: TieBreak,
 }
 
 impl ExpansionCandidate {
@@ -44,6 +52,7 @@ impl ExpansionCandidate {
             turn,
             distance,
             priority,
+            tie_break: policy.tie_break,
         }
     }
 }
@@ -67,11 +76,20 @@ impl Ord for ExpansionCandidate {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         // Primary: higher priority first
         // Secondary: lower distance first (closer to anchor)
-        // Tertiary: by TurnId for determinism
+        // Tertiary: earlier created_at first, if the tie-break asks for it
+        // Last: by TurnId for determinism
         match self.priority.partial_cmp(&other.priority) {
             Some(std::cmp::Ordering::Equal) | None => {
                 match self.distance.cmp(&other.distance).reverse() {
-                    std::cmp::Ordering::Equal => self.turn.id.cmp(&other.turn.id),
+                    std::cmp::Ordering::Equal => {
+                        let by_time = match self.tie_break {
+                            TieBreak::ById => std::cmp::Ordering::Equal,
+                            TieBreak::ByCreatedAtThenId => {
+                                self.turn.created_at.cmp(&other.turn.created_at).reverse()
+                            }
+                        };
+                        by_time.then_with(|| self.turn.id.cmp(&other.turn.id))
+                    }
                     ord => ord,
                 }
             }
@@ -154,5 +172,26 @@ mod tests {
         // c1 > c3 (same phase but closer distance)
         assert!(c1 > c3);
     }
-}
 
+    #[test]
+    fn test_tie_break_by_created_at() {
+        let mut earlier = make_turn(1, 0.5, Phase::Planning);
+        earlier.created_at = 1000;
+        let mut later = make_turn(2, 0.5, Phase::Planning);
+        later.created_at = 2000;
+
+        let first_popped = |policy: &SlicePolicyV1| {
+            let mut heap = std::collections::BinaryHeap::from([
+                ExpansionCandidate::new(later.clone(), 1, policy),
+                ExpansionCandidate::new(earlier.clone(), 1, policy),
+            ]);
+            heap.pop().unwrap().turn.id
+        };
+
+        // ById favors the larger TurnId regardless of time
+        assert_eq!(first_popped(&SlicePolicyV1::default()), later.id);
+
+        let by_time = SlicePolicyV1::builder().tie_break(TieBreak::ByCreatedAtThenId).build();
+        assert_eq!(first_popped(&by_time), earlier.id);
+    }
+}
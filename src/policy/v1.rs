@@ -9,9 +9,12 @@
 //! This ensures both Rust and Python produce identical `params_hash` values.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use crate::canonical::canonical_hash_hex;
-use crate::types::Phase;
-use crate::DEFAULT_POLICY_VERSION;
+use crate::store::NeighborOrder;
+use crate::types::{EdgeType, Phase, Role};
+use crate::{DEFAULT_POLICY_VERSION, SLICE_POLICY_SEMANTICS_VERSION};
+use super::version::PolicyVersion;
 
 /// Quantization factor for float normalization.
 /// Floats are multiplied by this value and rounded to i64.
@@ -89,8 +92,62 @@ impl PhaseWeights {
     }
 }
 
+/// Which graph directions the slicer follows from each selected turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpansionDirection {
+    /// Follow parents, children, and siblings.
+    #[default]
+    Both,
+    /// Follow parents only (what led to the anchor).
+    Ancestors,
+    /// Follow children only (what followed the anchor).
+    Descendants,
+}
+
+impl ExpansionDirection {
+    /// Whether parents are expanded.
+    pub fn follows_parents(&self) -> bool {
+        matches!(self, Self::Both | Self::Ancestors)
+    }
+
+    /// Whether children are expanded.
+    pub fn follows_children(&self) -> bool {
+        matches!(self, Self::Both | Self::Descendants)
+    }
+
+    /// Whether siblings are expanded.
+    ///
+    /// A sibling is neither an ancestor nor a descendant, so siblings are
+    /// only followed in `Both`.
+    pub fn follows_siblings(&self) -> bool {
+        matches!(self, Self::Both)
+    }
+
+    fn is_both(&self) -> bool {
+        matches!(self, Self::Both)
+    }
+}
+
+/// How expansion orders candidates with equal priority and distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreak {
+    /// By TurnId only.
+    #[default]
+    ById,
+    /// Earlier `created_at` first, then by TurnId.
+    ByCreatedAtThenId,
+}
+
+impl TieBreak {
+    fn is_by_id(&self) -> bool {
+        matches!(self, Self::ById)
+    }
+}
+
 /// Quantize a float to an i64 for deterministic hashing.
-fn quantize_float(value: f32) -> i64 {
+pub(crate) fn quantize_float(value: f32) -> i64 {
     ((value as f64) * FLOAT_QUANTIZATION_FACTOR).round() as i64
 }
 
@@ -106,7 +163,11 @@ struct QuantizedPhaseWeights {
 
 /// Quantized policy parameters for deterministic hashing.
 ///
-/// All floats are quantized to i64 to ensure cross-platform consistency.
+/// All floats are quantized to i64 to ensure cross-platform consistency,
+/// and sets are sorted. Every field after `max_siblings_per_node` was added
+/// later and is omitted from the serialized form at its default (zero,
+/// unset, false, empty, `Both` or `ById`), so pre-existing policies keep
+/// their hash. New fields must follow the same rule.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct QuantizedPolicyParams {
     version: String,
@@ -117,6 +178,40 @@ struct QuantizedPolicyParams {
     distance_decay: i64,
     include_siblings: bool,
     max_siblings_per_node: usize,
+    #[serde(skip_serializing_if = "is_zero")]
+    sibling_distance_offset: u32,
+    #[serde(skip_serializing_if = "ExpansionDirection::is_both")]
+    expansion_direction: ExpansionDirection,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allowed_edge_types: Option<Vec<EdgeType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ancestor_budget_fraction: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_store_calls: Option<usize>,
+    #[serde(skip_serializing_if = "is_false")]
+    truncate_on_store_budget: bool,
+    #[serde(skip_serializing_if = "NeighborOrder::is_by_id")]
+    neighbor_order: NeighborOrder,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_depth_delta: Option<u32>,
+    #[serde(skip_serializing_if = "is_false")]
+    include_root_path: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    complexity_budget: Option<i64>,
+    #[serde(skip_serializing_if = "TieBreak::is_by_id")]
+    tie_break: TieBreak,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    excluded_roles: Vec<Role>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unknown_phase_weight: Option<i64>,
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
 }
 
 /// Slice policy version 1.
@@ -132,6 +227,19 @@ struct QuantizedPolicyParams {
 /// - `distance_decay`: Priority decay per hop (0.9 = 10% loss per hop)
 /// - `include_siblings`: Whether to include sibling turns
 /// - `max_siblings_per_node`: Limit on siblings per parent
+/// - `sibling_distance_offset`: Extra hops charged to siblings
+/// - `expansion_direction`: Which graph directions are followed
+/// - `allowed_edge_types`: Edge types expansion may traverse (None = all)
+/// - `ancestor_budget_fraction`: Share of max_nodes reserved for ancestors
+/// - `max_store_calls`: Ceiling on store queries per slice (None = unlimited)
+/// - `truncate_on_store_budget`: Return a partial slice instead of failing
+/// - `neighbor_order`: Order parents/children are fetched from the store
+/// - `max_depth_delta`: Band of trajectory depths around the anchor (None = any)
+/// - `include_root_path`: Always include the path from the root to the anchor
+/// - `complexity_budget`: Total `trajectory_complexity` the slice may spend (None = flat)
+/// - `tie_break`: Order of candidates with equal priority and distance
+/// - `excluded_roles`: Roles never admitted through expansion
+/// - `unknown_phase_weight`: Phase weight for turns whose phase was unparseable
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlicePolicyV1 {
     /// Policy version identifier.
@@ -150,9 +258,86 @@ pub struct SlicePolicyV1 {
     pub include_siblings: bool,
     /// Maximum siblings to include per parent.
     pub max_siblings_per_node: usize,
+    /// Extra hops added to a sibling's distance (0 = same as the current node).
+    #[serde(default)]
+    pub sibling_distance_offset: u32,
+    /// Graph directions followed during expansion.
+    #[serde(default)]
+    pub expansion_direction: ExpansionDirection,
+    /// Edge types expansion may traverse (`None` = all types).
+    ///
+    /// When set, neighbors reachable only through other edge types are
+    /// skipped and such edges are omitted from the exported slice.
+    #[serde(default)]
+    pub allowed_edge_types: Option<HashSet<EdgeType>>,
+    /// Fraction of `max_nodes` reserved for turns reached through parent
+    /// edges (`None` = no reservation).
+    ///
+    /// Unused reserve is released once no ancestor candidates remain.
+    #[serde(default)]
+    pub ancestor_budget_fraction: Option<f32>,
+    /// Maximum store queries a single slice may issue (`None` = unlimited).
+    ///
+    /// Counts every call, including the anchor lookup and the final edge
+    /// fetch, so values below 2 can never produce a slice.
+    #[serde(default)]
+    pub max_store_calls: Option<usize>,
+    /// When `max_store_calls` runs out during expansion or the root-path
+    /// walk, stop and return the turns selected so far instead of failing
+    /// with `BudgetExhausted`.
+    #[serde(default)]
+    pub truncate_on_store_budget: bool,
+    /// Order in which parents and children are requested from the store.
+    ///
+    /// Candidates are still ranked by priority, so this changes only the
+    /// order of store lookups, never the admitted set (an exhausted
+    /// `max_store_calls` stops expansion before any partly fetched
+    /// neighbors are ranked). The slicer asks the store via
+    /// `get_parents_ordered` / `get_children_ordered`, at no extra calls;
+    /// under `edges_from_traversal` it instead sorts with one `get_turns`
+    /// call per expanded turn, counted by `max_store_calls`.
+    #[serde(default)]
+    pub neighbor_order: NeighborOrder,
+    /// Maximum difference between a turn's `trajectory_depth` and the
+    /// anchor's (`None` = unrestricted).
+    ///
+    /// Turns outside the band are neither selected nor expanded through.
+    #[serde(default)]
+    pub max_depth_delta: Option<u32>,
+    /// Always include the path from the graph root to the anchor.
+    ///
+    /// The path follows the highest-priority parent at each step and is
+    /// added after expansion, beyond `max_radius` and `max_nodes`.
+    #[serde(default)]
+    pub include_root_path: bool,
+    /// Total `trajectory_complexity` admitted turns may spend (`None` = no
+    /// complexity accounting; only `max_nodes` applies).
+    ///
+    /// The anchor is always admitted; expansion stops at the first turn
+    /// whose complexity exceeds the remaining budget.
+    #[serde(default)]
+    pub complexity_budget: Option<f32>,
+    /// Order of candidates whose priority and distance are equal.
+    #[serde(default)]
+    pub tie_break: TieBreak,
+    /// Roles skipped during expansion (empty = all roles).
+    ///
+    /// Matching turns are neither selected nor expanded through. The
+    /// anchor is always admitted, whatever its role.
+    #[serde(default)]
+    pub excluded_roles: HashSet<Role>,
+    /// Phase weight for turns flagged `phase_unknown` (`None` = weight
+    /// them by their placeholder `phase`, i.e. as Exploration).
+    #[serde(default)]
+    pub unknown_phase_weight: Option<f32>,
 }
 
 impl SlicePolicyV1 {
+    /// Start a builder seeded with the default parameters.
+    pub fn builder() -> SlicePolicyBuilder {
+        SlicePolicyBuilder::new()
+    }
+
     /// Create a new policy with custom parameters.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -173,6 +358,19 @@ impl SlicePolicyV1 {
             distance_decay: distance_decay.clamp(0.0, 1.0),
             include_siblings,
             max_siblings_per_node,
+            sibling_distance_offset: 0,
+            expansion_direction: ExpansionDirection::Both,
+            allowed_edge_types: None,
+            ancestor_budget_fraction: None,
+            max_store_calls: None,
+            truncate_on_store_budget: false,
+            neighbor_order: NeighborOrder::ById,
+            max_depth_delta: None,
+            include_root_path: false,
+            complexity_budget: None,
+            tie_break: TieBreak::ById,
+            excluded_roles: HashSet::new(),
+            unknown_phase_weight: None,
         }
     }
 
@@ -181,6 +379,19 @@ impl SlicePolicyV1 {
         &self.version
     }
 
+    /// Get the semantics version implemented by this build.
+    pub fn semantics_version(&self) -> PolicyVersion {
+        PolicyVersion::parse(SLICE_POLICY_SEMANTICS_VERSION)
+            .expect("SLICE_POLICY_SEMANTICS_VERSION is valid semver")
+    }
+
+    /// Check whether slices made under `version` are compatible with this build.
+    ///
+    /// Rejects major-version mismatches.
+    pub fn is_compatible_with(&self, version: &PolicyVersion) -> bool {
+        self.semantics_version().is_compatible_with(version)
+    }
+
     /// Compute a hash of the policy parameters.
     ///
     /// Uses quantized float representation to ensure cross-platform consistency.
@@ -206,6 +417,27 @@ impl SlicePolicyV1 {
             distance_decay: quantize_float(self.distance_decay),
             include_siblings: self.include_siblings,
             max_siblings_per_node: self.max_siblings_per_node,
+            sibling_distance_offset: self.sibling_distance_offset,
+            expansion_direction: self.expansion_direction,
+            allowed_edge_types: self.allowed_edge_types.as_ref().map(|types| {
+                let mut sorted: Vec<EdgeType> = types.iter().copied().collect();
+                sorted.sort();
+                sorted
+            }),
+            ancestor_budget_fraction: self.ancestor_budget_fraction.map(quantize_float),
+            max_store_calls: self.max_store_calls,
+            truncate_on_store_budget: self.truncate_on_store_budget,
+            neighbor_order: self.neighbor_order,
+            max_depth_delta: self.max_depth_delta,
+            include_root_path: self.include_root_path,
+            complexity_budget: self.complexity_budget.map(quantize_float),
+            tie_break: self.tie_break,
+            excluded_roles: {
+                let mut sorted: Vec<Role> = self.excluded_roles.iter().copied().collect();
+                sorted.sort();
+                sorted
+            },
+            unknown_phase_weight: self.unknown_phase_weight.map(quantize_float),
         }
     }
 
@@ -221,6 +453,19 @@ impl SlicePolicyV1 {
             distance_decay: 0.9,
             include_siblings: false,
             max_siblings_per_node: 0,
+            sibling_distance_offset: 0,
+            expansion_direction: ExpansionDirection::Both,
+            allowed_edge_types: None,
+            ancestor_budget_fraction: None,
+            max_store_calls: None,
+            truncate_on_store_budget: false,
+            neighbor_order: NeighborOrder::ById,
+            max_depth_delta: None,
+            include_root_path: false,
+            complexity_budget: None,
+            tie_break: TieBreak::ById,
+            excluded_roles: HashSet::new(),
+            unknown_phase_weight: None,
         }
     }
 }
@@ -236,10 +481,171 @@ impl Default for SlicePolicyV1 {
             distance_decay: 0.9,
             include_siblings: true,
             max_siblings_per_node: 5,
+            sibling_distance_offset: 0,
+            expansion_direction: ExpansionDirection::Both,
+            allowed_edge_types: None,
+            ancestor_budget_fraction: None,
+            max_store_calls: None,
+            truncate_on_store_budget: false,
+            neighbor_order: NeighborOrder::ById,
+            max_depth_delta: None,
+            include_root_path: false,
+            complexity_budget: None,
+            tie_break: TieBreak::ById,
+            excluded_roles: HashSet::new(),
+            unknown_phase_weight: None,
         }
     }
 }
 
+/// Builder for [`SlicePolicyV1`].
+///
+/// Starts from `SlicePolicyV1::default()`; each setter overrides one field.
+/// Values are stored as given, so a built policy has the same `params_hash`
+/// as the equivalent struct literal.
+#[derive(Debug, Clone, Default)]
+pub struct SlicePolicyBuilder {
+    policy: SlicePolicyV1,
+}
+
+impl SlicePolicyBuilder {
+    /// Create a builder with default parameters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the policy version identifier.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.policy.version = version.into();
+        self
+    }
+
+    /// Set the maximum number of turns in the slice.
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.policy.max_nodes = max_nodes;
+        self
+    }
+
+    /// Set the maximum graph distance from the anchor.
+    pub fn max_radius(mut self, max_radius: u32) -> Self {
+        self.policy.max_radius = max_radius;
+        self
+    }
+
+    /// Set the phase importance weights.
+    pub fn phase_weights(mut self, phase_weights: PhaseWeights) -> Self {
+        self.policy.phase_weights = phase_weights;
+        self
+    }
+
+    /// Set the salience weight.
+    pub fn salience_weight(mut self, salience_weight: f32) -> Self {
+        self.policy.salience_weight = salience_weight;
+        self
+    }
+
+    /// Set the per-hop priority decay.
+    pub fn distance_decay(mut self, distance_decay: f32) -> Self {
+        self.policy.distance_decay = distance_decay;
+        self
+    }
+
+    /// Set whether sibling turns are included.
+    pub fn include_siblings(mut self, include_siblings: bool) -> Self {
+        self.policy.include_siblings = include_siblings;
+        self
+    }
+
+    /// Set the maximum siblings included per parent.
+    pub fn max_siblings_per_node(mut self, max_siblings_per_node: usize) -> Self {
+        self.policy.max_siblings_per_node = max_siblings_per_node;
+        self
+    }
+
+    /// Set the extra hops charged to siblings.
+    pub fn sibling_distance_offset(mut self, sibling_distance_offset: u32) -> Self {
+        self.policy.sibling_distance_offset = sibling_distance_offset;
+        self
+    }
+
+    /// Set the graph directions followed during expansion.
+    pub fn expansion_direction(mut self, expansion_direction: ExpansionDirection) -> Self {
+        self.policy.expansion_direction = expansion_direction;
+        self
+    }
+
+    /// Restrict expansion to the given edge types.
+    pub fn allowed_edge_types(mut self, types: impl IntoIterator<Item = EdgeType>) -> Self {
+        self.policy.allowed_edge_types = Some(types.into_iter().collect());
+        self
+    }
+
+    /// Reserve a fraction of `max_nodes` for ancestors.
+    pub fn ancestor_budget_fraction(mut self, fraction: f32) -> Self {
+        self.policy.ancestor_budget_fraction = Some(fraction);
+        self
+    }
+
+    /// Cap the store queries a single slice may issue.
+    pub fn max_store_calls(mut self, max_store_calls: usize) -> Self {
+        self.policy.max_store_calls = Some(max_store_calls);
+        self
+    }
+
+    /// Return a partial slice when the store call cap is reached.
+    pub fn truncate_on_store_budget(mut self, truncate: bool) -> Self {
+        self.policy.truncate_on_store_budget = truncate;
+        self
+    }
+
+    /// Set the order parents and children are fetched in.
+    pub fn neighbor_order(mut self, neighbor_order: NeighborOrder) -> Self {
+        self.policy.neighbor_order = neighbor_order;
+        self
+    }
+
+    /// Restrict the slice to turns within `delta` trajectory depths of the anchor.
+    pub fn max_depth_delta(mut self, delta: u32) -> Self {
+        self.policy.max_depth_delta = Some(delta);
+        self
+    }
+
+    /// Set whether the root-to-anchor path is always included.
+    pub fn include_root_path(mut self, include: bool) -> Self {
+        self.policy.include_root_path = include;
+        self
+    }
+
+    /// Charge each turn its `trajectory_complexity` against `budget`.
+    pub fn complexity_budget(mut self, budget: f32) -> Self {
+        self.policy.complexity_budget = Some(budget);
+        self
+    }
+
+    /// Set how equal-priority candidates are ordered.
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.policy.tie_break = tie_break;
+        self
+    }
+
+    /// Set the roles skipped during expansion.
+    pub fn excluded_roles(mut self, roles: impl IntoIterator<Item = Role>) -> Self {
+        self.policy.excluded_roles = roles.into_iter().collect();
+        self
+    }
+
+    /// Weight turns with an unrecognized phase by `weight`.
+    pub fn unknown_phase_weight(mut self, weight: f32) -> Self {
+        self.policy.unknown_phase_weight = Some(weight);
+        self
+    }
+
+    /// Build the policy.
+    pub fn build(self) -> SlicePolicyV1 {
+        self.policy
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -262,10 +668,171 @@ mod tests {
     #[test]
     fn test_policy_params_hash_changes() {
         let policy1 = SlicePolicyV1::default();
-        let mut policy2 = SlicePolicyV1::default();
-        policy2.max_nodes = 128; // Change a parameter
+        let policy2 = SlicePolicyV1 {
+            max_nodes: 128, // Change a parameter
+            ..SlicePolicyV1::default()
+        };
 
         assert_ne!(policy1.params_hash(), policy2.params_hash());
     }
-}
 
+    #[test]
+    fn test_policy_semantics_compatibility() {
+        let policy = SlicePolicyV1::default();
+        let current = policy.semantics_version();
+
+        assert!(policy.is_compatible_with(&current));
+        assert!(policy.is_compatible_with(&PolicyVersion::new(current.major, current.minor + 1, 0)));
+        assert!(!policy.is_compatible_with(&PolicyVersion::new(current.major + 1, 0, 0)));
+    }
+
+    #[test]
+    fn test_sibling_distance_offset_hash() {
+        // Zero offset must keep the hash from before the field existed
+        assert_eq!(SlicePolicyV1::default().params_hash(), "02005444cae1117d");
+
+        let offset = SlicePolicyV1 {
+            sibling_distance_offset: 1,
+            ..SlicePolicyV1::default()
+        };
+        assert_ne!(offset.params_hash(), SlicePolicyV1::default().params_hash());
+    }
+
+    #[test]
+    fn test_expansion_direction_hash() {
+        // Both must keep the hash from before the field existed
+        assert_eq!(SlicePolicyV1::default().params_hash(), "02005444cae1117d");
+
+        let ancestors = SlicePolicyV1 {
+            expansion_direction: ExpansionDirection::Ancestors,
+            ..SlicePolicyV1::default()
+        };
+        let descendants = SlicePolicyV1 {
+            expansion_direction: ExpansionDirection::Descendants,
+            ..SlicePolicyV1::default()
+        };
+        assert_ne!(ancestors.params_hash(), SlicePolicyV1::default().params_hash());
+        assert_ne!(ancestors.params_hash(), descendants.params_hash());
+    }
+
+    #[test]
+    fn test_neighbor_order_hash() {
+        let by_salience = SlicePolicyV1::builder()
+            .neighbor_order(NeighborOrder::BySalienceDesc)
+            .build();
+        assert_eq!(SlicePolicyV1::default().params_hash(), "02005444cae1117d");
+        assert_ne!(by_salience.params_hash(), SlicePolicyV1::default().params_hash());
+    }
+
+    #[test]
+    fn test_max_depth_delta_hash() {
+        let banded = |delta| SlicePolicyV1::builder().max_depth_delta(delta).build().params_hash();
+        assert_ne!(banded(0), SlicePolicyV1::default().params_hash());
+        assert_ne!(banded(0), banded(1));
+        assert_eq!(banded(2), banded(2));
+    }
+
+    #[test]
+    fn test_include_root_path_hash() {
+        let with_path = SlicePolicyV1::builder().include_root_path(true).build();
+        let without = SlicePolicyV1::builder().include_root_path(false).build();
+        assert_eq!(without.params_hash(), SlicePolicyV1::default().params_hash());
+        assert_ne!(with_path.params_hash(), without.params_hash());
+    }
+
+    #[test]
+    fn test_complexity_budget_hash() {
+        let budgeted = |budget| SlicePolicyV1::builder().complexity_budget(budget).build().params_hash();
+        assert_ne!(budgeted(10.0), SlicePolicyV1::default().params_hash());
+        assert_ne!(budgeted(10.0), budgeted(12.5));
+        assert_eq!(budgeted(10.0), budgeted(10.0));
+    }
+
+    #[test]
+    fn test_tie_break_hash() {
+        let by_time = SlicePolicyV1::builder().tie_break(TieBreak::ByCreatedAtThenId).build();
+        let by_id = SlicePolicyV1::builder().tie_break(TieBreak::ById).build();
+        assert_eq!(by_id.params_hash(), "02005444cae1117d");
+        assert_ne!(by_time.params_hash(), by_id.params_hash());
+    }
+
+    #[test]
+    fn test_excluded_roles_hash() {
+        let none = SlicePolicyV1::builder().excluded_roles([]).build();
+        assert_eq!(none.params_hash(), "02005444cae1117d");
+
+        let system = SlicePolicyV1::builder().excluded_roles([Role::System]).build();
+        let system_tool = SlicePolicyV1::builder().excluded_roles([Role::System, Role::Tool]).build();
+        let tool_system = SlicePolicyV1::builder().excluded_roles([Role::Tool, Role::System]).build();
+        assert_ne!(system.params_hash(), none.params_hash());
+        assert_ne!(system.params_hash(), system_tool.params_hash());
+        assert_eq!(system_tool.params_hash(), tool_system.params_hash());
+    }
+
+    #[test]
+    fn test_unknown_phase_weight_hash() {
+        let unset = SlicePolicyV1::default();
+        assert_eq!(unset.params_hash(), "02005444cae1117d");
+
+        let zero = SlicePolicyV1::builder().unknown_phase_weight(0.0).build();
+        let half = SlicePolicyV1::builder().unknown_phase_weight(0.5).build();
+        assert_ne!(zero.params_hash(), unset.params_hash());
+        assert_ne!(zero.params_hash(), half.params_hash());
+    }
+
+    #[test]
+    fn test_allowed_edge_types_hash() {
+        assert_eq!(SlicePolicyV1::default().params_hash(), "02005444cae1117d");
+
+        let reply_only = SlicePolicyV1 {
+            allowed_edge_types: Some(HashSet::from([EdgeType::Reply])),
+            ..SlicePolicyV1::default()
+        };
+        let reply_branch = SlicePolicyV1 {
+            allowed_edge_types: Some(HashSet::from([EdgeType::Branch, EdgeType::Reply])),
+            ..SlicePolicyV1::default()
+        };
+        let branch_reply = SlicePolicyV1 {
+            allowed_edge_types: Some(HashSet::from([EdgeType::Reply, EdgeType::Branch])),
+            ..SlicePolicyV1::default()
+        };
+        assert_ne!(reply_only.params_hash(), SlicePolicyV1::default().params_hash());
+        assert_ne!(reply_only.params_hash(), reply_branch.params_hash());
+        assert_eq!(reply_branch.params_hash(), branch_reply.params_hash());
+    }
+
+    #[test]
+    fn test_builder_matches_struct_literal() {
+        assert_eq!(SlicePolicyV1::builder().build().params_hash(), "02005444cae1117d");
+
+        let built = SlicePolicyV1::builder()
+            .max_nodes(64)
+            .max_radius(4)
+            .salience_weight(0.5)
+            .distance_decay(0.8)
+            .include_siblings(false)
+            .max_siblings_per_node(0)
+            .sibling_distance_offset(1)
+            .expansion_direction(ExpansionDirection::Ancestors)
+            .allowed_edge_types([EdgeType::Reply, EdgeType::Branch])
+            .ancestor_budget_fraction(0.25)
+            .build();
+        let literal = SlicePolicyV1 {
+            version: DEFAULT_POLICY_VERSION.to_string(),
+            max_nodes: 64,
+            max_radius: 4,
+            phase_weights: PhaseWeights::default(),
+            salience_weight: 0.5,
+            distance_decay: 0.8,
+            include_siblings: false,
+            max_siblings_per_node: 0,
+            sibling_distance_offset: 1,
+            expansion_direction: ExpansionDirection::Ancestors,
+            allowed_edge_types: Some(HashSet::from([EdgeType::Branch, EdgeType::Reply])),
+            ancestor_budget_fraction: Some(0.25),
+            ..Default::default()
+        };
+
+        assert_eq!(built.params_hash(), literal.params_hash());
+    }
+}
@@ -0,0 +1,112 @@
+//! Semantic versioning for slice policy semantics.
+//!
+//! `SlicePolicyV1::version` names the policy family ("slice_policy_v1") but
+//! says nothing about how the expansion algorithm behaves. `PolicyVersion`
+//! tracks the semantics separately so slices produced under one set of
+//! semantics can be detected when replayed under another.
+//!
+//! Compatibility follows semver: versions are compatible iff their major
+//! components match.
+
+use serde::{Deserialize, Serialize};
+
+/// Semantic version of slice policy semantics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PolicyVersion {
+    /// Major version (breaking changes to selection semantics).
+    pub major: u32,
+    /// Minor version (backwards-compatible additions).
+    pub minor: u32,
+    /// Patch version (fixes that do not alter selection).
+    pub patch: u32,
+}
+
+impl PolicyVersion {
+    /// Create a version from its components.
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self { major, minor, patch }
+    }
+
+    /// Parse a `MAJOR.MINOR.PATCH` string.
+    ///
+    /// A leading `v` is accepted. Pre-release and build suffixes are rejected.
+    pub fn parse(s: &str) -> Result<Self, PolicyVersionError> {
+        let trimmed = s.trim();
+        let digits = trimmed.strip_prefix('v').unwrap_or(trimmed);
+
+        let parts: Vec<&str> = digits.split('.').collect();
+        if parts.len() != 3 {
+            return Err(PolicyVersionError::InvalidFormat(s.to_string()));
+        }
+
+        let component = |part: &str| {
+            part.parse::<u32>()
+                .map_err(|_| PolicyVersionError::InvalidComponent(s.to_string(), part.to_string()))
+        };
+
+        Ok(Self {
+            major: component(parts[0])?,
+            minor: component(parts[1])?,
+            patch: component(parts[2])?,
+        })
+    }
+
+    /// Check semver compatibility (same major version).
+    pub fn is_compatible_with(&self, other: &PolicyVersion) -> bool {
+        self.major == other.major
+    }
+}
+
+impl std::fmt::Display for PolicyVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Error when parsing a policy version.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PolicyVersionError {
+    /// Not of the form MAJOR.MINOR.PATCH.
+    #[error("Invalid policy version '{0}': expected MAJOR.MINOR.PATCH")]
+    InvalidFormat(String),
+    /// A component is not a non-negative integer.
+    #[error("Invalid policy version '{0}': component '{1}' is not a number")]
+    InvalidComponent(String, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(PolicyVersion::parse("1.2.3").unwrap(), PolicyVersion::new(1, 2, 3));
+        assert_eq!(PolicyVersion::parse("v2.0.0").unwrap(), PolicyVersion::new(2, 0, 0));
+        assert_eq!(PolicyVersion::new(1, 2, 3).to_string(), "1.2.3");
+    }
+
+    #[test]
+    fn test_parse_invalid_version() {
+        assert!(matches!(
+            PolicyVersion::parse("slice_policy_v1"),
+            Err(PolicyVersionError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            PolicyVersion::parse("1.x.0"),
+            Err(PolicyVersionError::InvalidComponent(_, _))
+        ));
+        assert!(PolicyVersion::parse("1.0.0-beta").is_err());
+    }
+
+    #[test]
+    fn test_compatibility() {
+        let v1_0 = PolicyVersion::new(1, 0, 0);
+        let v1_4 = PolicyVersion::new(1, 4, 2);
+        let v2_0 = PolicyVersion::new(2, 0, 0);
+
+        assert!(v1_0.is_compatible_with(&v1_4));
+        assert!(v1_4.is_compatible_with(&v1_0));
+        assert!(!v1_0.is_compatible_with(&v2_0));
+        assert!(!v2_0.is_compatible_with(&v1_4));
+    }
+}
@@ -8,13 +8,26 @@
 //! - `graph_kernel_token_verifications_total` - Counter of token verifications
 
 use axum::{
-    extract::Request,
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{header, StatusCode},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
+    Json,
 };
-use std::time::Instant;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tracing::info;
 
+use super::routes::ErrorResponse;
+
+/// Header a trusted proxy may send to rate limit by ID instead of address.
+pub const CLIENT_ID_HEADER: &str = "x-client-id";
+
+/// Buckets kept before idle (full) ones are pruned.
+const MAX_TRACKED_BUCKETS: usize = 10_000;
+
 /// Metrics middleware that records request counts and latency.
 ///
 /// Records:
@@ -46,6 +59,186 @@ pub async fn metrics_middleware(request: Request, next: Next) -> Response {
     response
 }
 
+/// Token-bucket limit for one route.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitConfig {
+    /// Sustained rate at which tokens refill.
+    pub requests_per_second: f64,
+    /// Bucket capacity: requests allowed back-to-back (at least 1).
+    pub burst: u32,
+}
+
+impl RateLimitConfig {
+    /// Create a limit of `requests_per_second` with bursts of `burst`.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        Self {
+            requests_per_second,
+            burst,
+        }
+    }
+
+    fn capacity(&self) -> f64 {
+        f64::from(self.burst.max(1))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Per-route, per-client token-bucket rate limiter.
+///
+/// Routes are matched by their router pattern (e.g. `/api/slice`); routes
+/// without a configured limit are not limited.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    limits: HashMap<String, RateLimitConfig>,
+    trusted_proxies: Vec<IpAddr>,
+    buckets: Mutex<HashMap<(String, String), Bucket>>,
+}
+
+impl RateLimiter {
+    /// Create a limiter with no limits configured.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit `route` to `config`, per client.
+    pub fn limit(mut self, route: impl Into<String>, config: RateLimitConfig) -> Self {
+        self.limits.insert(route.into(), config);
+        self
+    }
+
+    /// Honor `X-Client-Id` and `X-Forwarded-For` from these peer addresses.
+    ///
+    /// Both headers are client-controlled, so they are ignored unless the
+    /// request arrives from a listed proxy.
+    pub fn trust_proxies(mut self, proxies: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.trusted_proxies.extend(proxies);
+        self
+    }
+
+    /// Check whether no route is limited.
+    pub fn is_empty(&self) -> bool {
+        self.limits.is_empty()
+    }
+
+    /// Take one token for `client` on `route` at `now`.
+    ///
+    /// Returns how long the client must wait when the bucket is empty.
+    pub fn check(&self, route: &str, client: &str, now: Instant) -> Result<(), Duration> {
+        let Some(config) = self.limits.get(route) else {
+            return Ok(());
+        };
+        let capacity = config.capacity();
+
+        let mut buckets = self.buckets.lock().unwrap();
+        if buckets.len() >= MAX_TRACKED_BUCKETS {
+            // A bucket that has refilled is indistinguishable from a new one
+            buckets.retain(|(route, _), bucket| {
+                self.limits.get(route).is_some_and(|c| {
+                    let elapsed = now.saturating_duration_since(bucket.refilled_at).as_secs_f64();
+                    bucket.tokens + elapsed * c.requests_per_second < c.capacity()
+                })
+            });
+        }
+
+        let bucket = buckets
+            .entry((route.to_string(), client.to_string()))
+            .or_insert(Bucket {
+                tokens: capacity,
+                refilled_at: now,
+            });
+        let elapsed = now.saturating_duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * config.requests_per_second).min(capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else if config.requests_per_second > 0.0 {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / config.requests_per_second))
+        } else {
+            Err(Duration::MAX)
+        }
+    }
+}
+
+/// Rate limiting middleware.
+///
+/// Keys clients by the peer address. Requests from a trusted proxy (see
+/// `RateLimiter::trust_proxies`) are keyed by `X-Client-Id`, else the
+/// nearest untrusted `X-Forwarded-For` address. Requests over the route's
+/// limit get `429 Too Many Requests` with a `Retry-After` header
+/// (whole seconds, at least 1).
+pub async fn rate_limit_middleware(
+    State(limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if limiter.is_empty() {
+        return next.run(request).await;
+    }
+
+    let route = match request.extensions().get::<MatchedPath>() {
+        Some(matched) => matched.as_str().to_string(),
+        None => request.uri().path().to_string(),
+    };
+    let client = client_key(&request, &limiter.trusted_proxies);
+
+    match limiter.check(&route, &client, Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(wait) => {
+            let retry_after = wait.as_secs_f64().ceil().clamp(1.0, u32::MAX as f64) as u64;
+            info!(
+                target: "graph_kernel::metrics",
+                metric_type = "rate_limited",
+                path = %route,
+                client = %client,
+                retry_after_secs = retry_after,
+                "rate_limit_metric"
+            );
+            let body = ErrorResponse::new(
+                "RATE_LIMITED",
+                format!("Rate limit exceeded for {}", route),
+            );
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                Json(body),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// Identify the client of a request for rate limiting.
+fn client_key(request: &Request, trusted_proxies: &[IpAddr]) -> String {
+    let Some(ConnectInfo(peer)) = request.extensions().get::<ConnectInfo<SocketAddr>>() else {
+        return "unknown".to_string();
+    };
+    if !trusted_proxies.contains(&peer.ip()) {
+        return format!("ip:{}", peer.ip());
+    }
+
+    let headers = request.headers();
+    if let Some(id) = headers.get(CLIENT_ID_HEADER).and_then(|v| v.to_str().ok()) {
+        return format!("id:{}", id.trim());
+    }
+    // Proxies append, so walk back past our own proxies to the client
+    let forwarded = headers
+        .get("x-forwarded-for")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| {
+            v.rsplit(',')
+                .filter_map(|hop| hop.trim().parse::<IpAddr>().ok())
+                .find(|ip| !trusted_proxies.contains(ip))
+        });
+    format!("ip:{}", forwarded.unwrap_or(peer.ip()))
+}
+
 /// Normalize path for metrics to avoid high cardinality.
 ///
 /// Replaces UUIDs and other dynamic path segments with placeholders.
@@ -108,6 +301,45 @@ mod tests {
         assert_eq!(normalized, "/api/slice/:id");
     }
     
+    #[test]
+    fn test_token_bucket_refills() {
+        let limiter = RateLimiter::new().limit("/api/slice", RateLimitConfig::new(2.0, 2));
+        let start = Instant::now();
+
+        assert!(limiter.check("/api/slice", "a", start).is_ok());
+        assert!(limiter.check("/api/slice", "a", start).is_ok());
+        let wait = limiter.check("/api/slice", "a", start).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(500));
+
+        // Other clients and unlimited routes are unaffected
+        assert!(limiter.check("/api/slice", "b", start).is_ok());
+        assert!(limiter.check("/health", "a", start).is_ok());
+
+        // Half a second refills one token
+        assert!(limiter.check("/api/slice", "a", start + Duration::from_millis(500)).is_ok());
+    }
+
+    #[test]
+    fn test_client_key_trusts_only_listed_proxies() {
+        let request = |peer: &str, forwarded: &str| {
+            Request::builder()
+                .header("x-forwarded-for", forwarded)
+                .extension(ConnectInfo(SocketAddr::new(peer.parse().unwrap(), 4000)))
+                .body(axum::body::Body::empty())
+                .unwrap()
+        };
+        let proxies: Vec<IpAddr> = vec!["10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap()];
+
+        // A spoofed first hop is skipped; the nearest untrusted hop wins
+        let chained = request("10.0.0.1", "203.0.113.9, 198.51.100.4, 10.0.0.2");
+        assert_eq!(client_key(&chained, &proxies), "ip:198.51.100.4");
+
+        // Headers from an untrusted peer are ignored
+        let direct = request("198.51.100.4", "203.0.113.9");
+        assert_eq!(client_key(&direct, &proxies), "ip:198.51.100.4");
+        assert_eq!(client_key(&chained, &[]), "ip:10.0.0.1");
+    }
+
     #[test]
     fn test_normalize_path_preserves_regular_path() {
         let path = "/health/ready";
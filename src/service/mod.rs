@@ -6,9 +6,15 @@
 //!
 //! - `POST /api/slice` - Construct a context slice around an anchor
 //! - `POST /api/slice/batch` - Batch slice construction
+//! - `POST /api/slice/batch/stream` - Batch slice construction as NDJSON
+//! - `POST /api/slice/compare` - Turn-set diff between two slices
+//! - `POST /api/issue_token` - Issue a token for a client-built selection
 //! - `POST /api/verify_token` - Verify an admissibility token
+//! - `POST /api/is_admissible` - Check turn admissibility within a slice
 //! - `GET /api/policies` - List registered policies
 //! - `POST /api/policies` - Register a new policy
+//! - `GET /api/sufficiency_policies` - List registered sufficiency policies
+//! - `POST /api/sufficiency_policies` - Register a new sufficiency policy
 //! - `GET /health` - Detailed service health check
 //! - `GET /health/live` - Liveness probe
 //! - `GET /health/ready` - Readiness probe
@@ -18,7 +24,10 @@ pub mod middleware;
 pub mod routes;
 pub mod state;
 
-pub use middleware::{metrics_middleware, record_slice_metrics, record_token_verification};
+pub use middleware::{
+    metrics_middleware, rate_limit_middleware, record_slice_metrics, record_token_verification,
+    RateLimitConfig, RateLimiter,
+};
 pub use routes::{create_router, AppState};
-pub use state::{ServiceState, PolicyRegistry, PolicyRef};
+pub use state::{ServiceState, PolicyRegistry, PolicyRef, PolicyRefError, SufficiencyPolicyRegistry, MAX_PARAMS_HASH_LEN};
 
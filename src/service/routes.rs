@@ -1,22 +1,32 @@
 //! Axum routes for the Graph Kernel service.
 
 use axum::{
-    extract::{Json, State},
-    http::StatusCode,
-    response::IntoResponse,
+    body::Body,
+    extract::{Json, Query, State},
+    http::{header, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
     routing::{get, post},
     Router,
 };
+use futures_util::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::convert::Infallible;
 use std::sync::Arc;
 
+use crate::canonical::{canonical_hash_hex, canonical_json};
 use crate::policy::SlicePolicyV1;
-use crate::slicer::ContextSlicer;
-use crate::store::PostgresGraphStore;
-use crate::types::slice::SliceExport;
-use crate::types::TurnId;
+use crate::slicer::{selection_snapshot_hash, ContextSlicer, SlicerError};
+use crate::store::{GraphStore, PostgresGraphStore};
+use crate::types::slice::{SliceExport, EXTERNAL_SELECTION_PREFIX};
+use crate::types::{
+    DiversityMetrics, Edge, FailureKind, SufficiencyCheck, SufficiencyPolicy, TurnId, TokenVerifier,
+    VerificationMode,
+};
 use crate::GRAPH_KERNEL_SCHEMA_VERSION;
 
+use super::middleware::rate_limit_middleware;
 use super::state::{PolicyRef, ServiceState};
 
 /// Type alias for the service state with PostgresGraphStore.
@@ -35,6 +45,25 @@ pub struct SliceRequest {
     pub policy_ref: Option<PolicyRef>,
 }
 
+/// Query parameters of `POST /api/slice`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SliceQuery {
+    /// Reject slices failing the sufficiency check with `422`.
+    #[serde(default)]
+    pub enforce_sufficiency: bool,
+    /// Render the response as canonical JSON (see `canonical_json_response`).
+    #[serde(default)]
+    pub canonical: bool,
+}
+
+/// Query parameters of `POST /api/slice/batch`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CanonicalQuery {
+    /// Render the response as canonical JSON (see `canonical_json_response`).
+    #[serde(default)]
+    pub canonical: bool,
+}
+
 /// Request to construct multiple slices.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BatchSliceRequest {
@@ -51,6 +80,10 @@ pub struct SliceResponse {
     pub slice: SliceExportDto,
     /// Policy used.
     pub policy_ref: PolicyRef,
+    /// Sufficiency check, when a default sufficiency policy is configured
+    /// or enforcement was requested.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sufficiency: Option<SufficiencyCheck>,
 }
 
 /// Batch slice response.
@@ -69,10 +102,35 @@ pub struct BatchSliceResponse {
 /// Slice error for a specific anchor.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SliceError {
+    /// The anchor that failed.
     pub anchor_turn_id: String,
+    /// Machine-readable error code (`INVALID_TURN_ID`, `ANCHOR_NOT_FOUND`,
+    /// `SLICE_FAILED`).
+    pub code: String,
+    /// Error message.
     pub error: String,
 }
 
+/// One line of the streaming batch response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BatchSliceStreamItem {
+    /// A constructed slice.
+    Slice(SliceExportDto),
+    /// The anchor could not be sliced.
+    Error(SliceError),
+}
+
+impl SliceError {
+    /// Error code for a slicer failure.
+    fn code_for(error: &SlicerError) -> &'static str {
+        match error {
+            SlicerError::AnchorNotFound(_) => "ANCHOR_NOT_FOUND",
+            _ => "SLICE_FAILED",
+        }
+    }
+}
+
 /// Serializable slice export.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SliceExportDto {
@@ -84,6 +142,8 @@ pub struct SliceExportDto {
     pub turn_ids: Vec<String>,
     /// Number of edges in the slice.
     pub edge_count: usize,
+    /// Edges in the slice (sorted by parent, child, edge_type).
+    pub edges: Vec<Edge>,
     /// Policy identifier.
     pub policy_id: String,
     /// Policy parameters hash.
@@ -94,6 +154,90 @@ pub struct SliceExportDto {
     pub graph_snapshot_hash: String,
     /// HMAC-signed admissibility token.
     pub admissibility_token: String,
+    /// When the kernel issued the slice (Unix ms), if stamped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued_at_unix_ms: Option<i64>,
+    /// Canonical layout version hashed into `slice_id`.
+    #[serde(default = "legacy_canonical_version")]
+    pub canonical_version: u32,
+}
+
+fn legacy_canonical_version() -> u32 {
+    crate::LEGACY_CANONICAL_SLICE_VERSION
+}
+
+impl SliceExportDto {
+    /// Recompute the slice fingerprint from the DTO fields.
+    ///
+    /// Mirrors `SliceExport::compute_fingerprint`: turn IDs serialize as the
+    /// same UUID strings, so the canonical tuple hashes identically. Clients
+    /// can compare the result against `slice_id` to detect tampering with
+    /// the turn or edge lists without holding full turn snapshots.
+    pub fn recompute_slice_id(&self) -> String {
+        if self.canonical_version == crate::LEGACY_CANONICAL_SLICE_VERSION {
+            canonical_hash_hex(&(
+                &self.anchor_turn_id,
+                &self.turn_ids,
+                &self.edges,
+                &self.policy_id,
+                &self.policy_params_hash,
+                &self.schema_version,
+            ))
+        } else {
+            canonical_hash_hex(&(
+                &self.anchor_turn_id,
+                &self.turn_ids,
+                &self.edges,
+                &self.policy_id,
+                &self.policy_params_hash,
+                &self.schema_version,
+                self.canonical_version,
+            ))
+        }
+    }
+
+    /// Check whether two slices made the same selection.
+    ///
+    /// Mirrors `SliceExport::selection_equals`: compares anchor, turn IDs,
+    /// edges, policy_id, and policy_params_hash, ignoring token and
+    /// snapshot hash.
+    pub fn selection_equals(&self, other: &Self) -> bool {
+        self.anchor_turn_id == other.anchor_turn_id
+            && self.policy_id == other.policy_id
+            && self.policy_params_hash == other.policy_params_hash
+            && self.edges == other.edges
+            && self.turn_ids == other.turn_ids
+    }
+
+    /// Check whether the selection was built by a client rather than the slicer.
+    ///
+    /// Mirrors `SliceExport::is_external_selection`.
+    pub fn is_external_selection(&self) -> bool {
+        self.policy_id.starts_with(EXTERNAL_SELECTION_PREFIX)
+    }
+
+    /// Verify the admissibility token against the DTO fields.
+    ///
+    /// Mirrors `SliceExport::verify_admissibility`. Requires the kernel secret.
+    pub(crate) fn verify_admissibility(&self, hmac_secret: &[u8]) -> bool {
+        use crate::types::slice::{AdmissibilityToken, SliceFingerprint, GraphSnapshotHash};
+
+        let anchor_id = match TurnId::from_str(&self.anchor_turn_id) {
+            Ok(id) => id,
+            Err(_) => return false,
+        };
+
+        AdmissibilityToken::from_string(self.admissibility_token.clone()).verify_hmac_at(
+            hmac_secret,
+            &SliceFingerprint::new(self.slice_id.clone()),
+            &anchor_id,
+            &self.policy_id,
+            &self.policy_params_hash,
+            &GraphSnapshotHash::new(self.graph_snapshot_hash.clone()),
+            &self.schema_version,
+            self.issued_at_unix_ms,
+        )
+    }
 }
 
 impl From<SliceExport> for SliceExportDto {
@@ -103,11 +247,14 @@ impl From<SliceExport> for SliceExportDto {
             anchor_turn_id: slice.anchor_turn_id.to_string(),
             turn_ids: slice.turns.iter().map(|t| t.id.to_string()).collect(),
             edge_count: slice.edges.len(),
+            edges: slice.edges,
             policy_id: slice.policy_id,
             policy_params_hash: slice.policy_params_hash,
             schema_version: slice.schema_version,
             graph_snapshot_hash: slice.graph_snapshot_hash.to_string(),
             admissibility_token: slice.admissibility_token.to_string(),
+            issued_at_unix_ms: slice.issued_at_unix_ms,
+            canonical_version: slice.canonical_version,
         }
     }
 }
@@ -129,6 +276,9 @@ pub struct VerifyTokenRequest {
     pub graph_snapshot_hash: String,
     /// Schema version.
     pub schema_version: String,
+    /// Issue time bound into the token, if the slice was stamped.
+    #[serde(default)]
+    pub issued_at_unix_ms: Option<i64>,
 }
 
 /// Response from token verification.
@@ -138,34 +288,139 @@ pub struct VerifyTokenResponse {
     pub valid: bool,
     /// Reason if invalid.
     pub reason: Option<String>,
+    /// Whether the failure was a malformed request or a genuine HMAC mismatch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure_kind: Option<FailureKind>,
+}
+
+/// Request to check turn admissibility within a slice.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IsAdmissibleRequest {
+    /// Slice ID returned by `/api/slice`.
+    pub slice_id: String,
+    /// Admissibility token returned with the slice.
+    pub admissibility_token: String,
+    /// When the slice was issued (Unix ms), if it was stamped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued_at_unix_ms: Option<i64>,
+    /// The anchor turn the slice was built around.
+    pub anchor_turn_id: String,
+    /// Policy the slice was built with. If not provided, uses default policy.
+    pub policy_ref: Option<PolicyRef>,
+    /// Turn IDs to check against the slice boundary.
+    pub turn_ids: Vec<String>,
+}
+
+/// Admissibility of a single turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnAdmissibility {
+    /// The turn ID that was checked.
+    pub turn_id: String,
+    /// Whether the turn is inside the slice boundary.
+    pub admissible: bool,
+}
+
+/// Per-turn admissibility results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IsAdmissibleResponse {
+    /// The verified slice ID.
+    pub slice_id: String,
+    /// Results in request order.
+    pub results: Vec<TurnAdmissibility>,
+}
+
+/// Request to compare two slices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareSlicesRequest {
+    /// Baseline slice as returned by `/api/slice` (token included).
+    pub left: SliceExportDto,
+    /// Slice compared against the baseline.
+    pub right: SliceExportDto,
+}
+
+/// Turn-set difference between two slices.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareSlicesResponse {
+    /// Verified slice ID of `left`.
+    pub left_slice_id: String,
+    /// Verified slice ID of `right`.
+    pub right_slice_id: String,
+    /// Turns in `right` but not `left` (sorted).
+    pub added: Vec<String>,
+    /// Turns in `left` but not `right` (sorted).
+    pub removed: Vec<String>,
+    /// Whether both slices made the same selection (see
+    /// `SliceExport::selection_equals`).
+    pub selection_equivalent: bool,
+}
+
+/// Request to issue a token for a client-built selection.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTokenRequest {
+    /// The anchor turn ID (must be one of `turn_ids`).
+    pub anchor_turn_id: String,
+    /// Selected turn IDs, strictly ascending.
+    pub turn_ids: Vec<String>,
+    /// Edges between selected turns, strictly ascending by
+    /// (parent, child, edge_type).
+    #[serde(default)]
+    pub edges: Vec<Edge>,
+    /// Policy the selection is issued under. If not provided, uses the
+    /// default policy.
+    pub policy_ref: Option<PolicyRef>,
 }
 
 /// Request to register a new policy.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterPolicyRequest {
+    /// The slice policy to register.
     pub policy: SlicePolicyV1,
 }
 
 /// Response containing a policy reference.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyRefResponse {
+    /// Reference to the registered policy.
     pub policy_ref: PolicyRef,
 }
 
 /// List of registered policies.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PolicyListResponse {
+    /// References to all registered policies.
+    pub policies: Vec<PolicyRef>,
+    /// Fingerprint of the policy registry.
+    pub registry_fingerprint: String,
+}
+
+/// Request to register a new sufficiency policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegisterSufficiencyPolicyRequest {
+    /// The sufficiency policy to register.
+    pub policy: SufficiencyPolicy,
+}
+
+/// List of registered sufficiency policies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SufficiencyPolicyListResponse {
+    /// References to all registered sufficiency policies.
     pub policies: Vec<PolicyRef>,
+    /// Fingerprint of the sufficiency policy registry.
     pub registry_fingerprint: String,
 }
 
 /// Service health response (detailed).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthResponse {
+    /// Overall status ("healthy" or "degraded").
     pub status: String,
+    /// Crate version.
     pub version: String,
+    /// Graph kernel schema version.
     pub schema_version: String,
+    /// Number of registered slice policies.
     pub policy_count: usize,
+    /// Fingerprint of the policy registry.
     pub registry_fingerprint: String,
     /// Database connectivity status.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -175,23 +430,31 @@ pub struct HealthResponse {
 /// Database health information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseHealth {
+    /// Whether the database is reachable.
     pub connected: bool,
+    /// Current number of pooled connections.
     pub pool_size: u32,
+    /// Number of idle connections.
     pub pool_idle: usize,
+    /// Maximum pool size.
     pub pool_max: u32,
 }
 
 /// Simple liveness response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LivenessResponse {
+    /// Liveness status.
     pub status: String,
 }
 
 /// Readiness response with dependency status.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReadinessResponse {
+    /// Whether the service is ready for traffic.
     pub ready: bool,
+    /// Whether the database is reachable.
     pub database: bool,
+    /// Optional status details.
     pub details: Option<String>,
 }
 
@@ -251,11 +514,44 @@ impl IntoResponse for ErrorResponse {
 // Route Handlers
 // ============================================================================
 
+/// Reject malformed policy references before registry lookup.
+fn validate_policy_ref(pref: &PolicyRef) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    pref.validate().map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new("INVALID_POLICY_REF", e.to_string())),
+        )
+    })
+}
+
+/// Serialize a response body, canonically if requested.
+///
+/// With `canonical`, the body is RFC 8785 canonical JSON (sorted keys,
+/// shortest round-trippable numbers), so clients in other languages can
+/// hash it byte-for-byte. Otherwise serde's default output is used.
+fn canonical_json_response<T: Serialize>(value: T, canonical: bool) -> Response {
+    if !canonical {
+        return Json(value).into_response();
+    }
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        canonical_json(&value),
+    )
+        .into_response()
+}
+
 /// Construct a context slice around an anchor turn.
-async fn slice_handler(
-    State(state): State<Arc<AppState>>,
+///
+/// With `?enforce_sufficiency=true`, slices failing the state's default
+/// sufficiency policy (or `SufficiencyPolicy::default()` if none is set)
+/// are rejected with `422 INSUFFICIENT_EVIDENCE`. Slices larger than the
+/// state's `max_response_turns` are rejected with `413 SLICE_TOO_LARGE`.
+/// `?canonical=true` renders the response as canonical JSON.
+async fn slice_handler<S: GraphStore + Send + Sync + 'static>(
+    State(state): State<Arc<ServiceState<S>>>,
+    Query(query): Query<SliceQuery>,
     Json(request): Json<SliceRequest>,
-) -> Result<Json<SliceResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
     // Parse anchor turn ID
     let anchor_id = TurnId::from_str(&request.anchor_turn_id).map_err(|e| {
         (
@@ -267,6 +563,10 @@ async fn slice_handler(
         )
     })?;
 
+    if let Some(ref pref) = request.policy_ref {
+        validate_policy_ref(pref)?;
+    }
+
     // Resolve policy (in a block to ensure guard is dropped before await)
     let (policy, policy_ref, hmac_secret, store) = {
         let registry = state.policy_registry.read().unwrap();
@@ -301,19 +601,59 @@ async fn slice_handler(
         )
     })?;
 
+    let turn_count = bundle.slice().turns.len();
+    if let Some(max_turns) = state.max_response_turns.filter(|&max| turn_count > max) {
+        return Err((
+            StatusCode::PAYLOAD_TOO_LARGE,
+            Json(ErrorResponse::new(
+                "SLICE_TOO_LARGE",
+                format!("Slice has {} turns, limit is {}", turn_count, max_turns),
+            ).with_details(turn_count.to_string())),
+        ));
+    }
+
+    let sufficiency_policy = match (&state.default_sufficiency_policy, query.enforce_sufficiency) {
+        (Some(policy), _) => Some(policy.clone()),
+        (None, true) => Some(SufficiencyPolicy::default()),
+        (None, false) => None,
+    };
+    let sufficiency = sufficiency_policy
+        .map(|policy| policy.check(&DiversityMetrics::from_bundle(&bundle)));
+
+    if let Some(check) = sufficiency.as_ref().filter(|c| query.enforce_sufficiency && !c.is_sufficient) {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ErrorResponse::new(
+                "INSUFFICIENT_EVIDENCE",
+                format!("Slice failed {} sufficiency violation(s)", check.violations.len()),
+            ).with_details(serde_json::to_string(&check.violations).unwrap_or_default())),
+        ));
+    }
+
     // Extract the verified slice for serialization
     // The bundle proves verification occurred - we serialize just the slice data
-    Ok(Json(SliceResponse {
+    let response = SliceResponse {
         slice: bundle.slice().clone().into(),
         policy_ref,
-    }))
+        sufficiency,
+    };
+    Ok(canonical_json_response(response, query.canonical))
 }
 
 /// Construct multiple slices in batch.
-async fn batch_slice_handler(
-    State(state): State<Arc<AppState>>,
+///
+/// Slices larger than the state's `max_response_turns` are reported as
+/// `SLICE_TOO_LARGE` errors in place of the slice.
+/// `?canonical=true` renders the response as canonical JSON.
+async fn batch_slice_handler<S: GraphStore + Send + Sync + 'static>(
+    State(state): State<Arc<ServiceState<S>>>,
+    Query(query): Query<CanonicalQuery>,
     Json(request): Json<BatchSliceRequest>,
-) -> Result<Json<BatchSliceResponse>, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    if let Some(ref pref) = request.policy_ref {
+        validate_policy_ref(pref)?;
+    }
+
     // Resolve policy (in a block to ensure guard is dropped before await)
     let (policy, policy_ref, hmac_secret, store) = {
         let registry = state.policy_registry.read().unwrap();
@@ -344,32 +684,390 @@ async fn batch_slice_handler(
     let mut errors = Vec::new();
 
     for anchor_str in &request.anchor_turn_ids {
-        match TurnId::from_str(anchor_str) {
-            Ok(anchor_id) => match slicer.slice(anchor_id).await {
-                Ok(bundle) => {
-                    // Extract verified slice for serialization
-                    slices.push(bundle.slice().clone().into());
-                }
-                Err(e) => errors.push(SliceError {
-                    anchor_turn_id: anchor_str.clone(),
-                    error: e.to_string(),
-                }),
-            },
-            Err(e) => errors.push(SliceError {
-                anchor_turn_id: anchor_str.clone(),
-                error: format!("Invalid turn ID: {}", e),
-            }),
+        match slice_anchor(&slicer, anchor_str, state.max_response_turns).await {
+            BatchSliceStreamItem::Slice(dto) => slices.push(dto),
+            BatchSliceStreamItem::Error(error) => errors.push(error),
         }
     }
 
-    Ok(Json(BatchSliceResponse {
+    let response = BatchSliceResponse {
         success_count: slices.len(),
         slices,
         policy_ref,
         errors,
+    };
+    Ok(canonical_json_response(response, query.canonical))
+}
+
+/// Construct multiple slices, streaming each as an NDJSON line.
+///
+/// Lines follow the input anchor order and are sent as each slice
+/// completes, so large batches don't hold the response until the end.
+/// `max_response_turns` applies per line, as for the batch endpoint.
+async fn batch_slice_stream_handler<S: GraphStore + Send + Sync + 'static>(
+    State(state): State<Arc<ServiceState<S>>>,
+    Json(request): Json<BatchSliceRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    if let Some(ref pref) = request.policy_ref {
+        validate_policy_ref(pref)?;
+    }
+
+    // Resolve policy (in a block to ensure guard is dropped before await)
+    let (policy, hmac_secret, store) = {
+        let registry = state.policy_registry.read().unwrap();
+        let policy = if let Some(ref pref) = request.policy_ref {
+            registry.resolve(pref).cloned().ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::new(
+                        "POLICY_NOT_FOUND",
+                        format!("Policy not found: {:?}", pref),
+                    )),
+                )
+            })?
+        } else {
+            SlicePolicyV1::default()
+        };
+        (policy, state.hmac_secret().to_vec(), Arc::clone(&state.store))
+    };
+
+    let slicer = Arc::new(ContextSlicer::new(store, policy, hmac_secret));
+    let max_turns = state.max_response_turns;
+
+    let lines = stream::iter(request.anchor_turn_ids).then(move |anchor_str| {
+        let slicer = Arc::clone(&slicer);
+        async move {
+            let item = slice_anchor(&slicer, &anchor_str, max_turns).await;
+            let mut line = serde_json::to_string(&item).expect("stream item serializes");
+            line.push('\n');
+            Ok::<_, Infallible>(line)
+        }
+    });
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(lines),
+    ))
+}
+
+/// Slice one batch anchor, mapping failures to a `SliceError`.
+///
+/// A slice with more than `max_turns` turns becomes a `SLICE_TOO_LARGE` error.
+async fn slice_anchor<S: GraphStore + Send + Sync + 'static>(
+    slicer: &ContextSlicer<S>,
+    anchor_str: &str,
+    max_turns: Option<usize>,
+) -> BatchSliceStreamItem {
+    let anchor_id = match TurnId::from_str(anchor_str) {
+        Ok(anchor_id) => anchor_id,
+        Err(e) => {
+            return BatchSliceStreamItem::Error(SliceError {
+                anchor_turn_id: anchor_str.to_string(),
+                code: "INVALID_TURN_ID".to_string(),
+                error: format!("Invalid turn ID: {}", e),
+            })
+        }
+    };
+
+    match slicer.slice(anchor_id).await {
+        Ok(bundle) => match max_turns.filter(|&max| bundle.num_turns() > max) {
+            Some(max) => BatchSliceStreamItem::Error(SliceError {
+                anchor_turn_id: anchor_str.to_string(),
+                code: "SLICE_TOO_LARGE".to_string(),
+                error: format!("Slice has {} turns, limit is {}", bundle.num_turns(), max),
+            }),
+            // Extract verified slice for serialization
+            None => BatchSliceStreamItem::Slice(bundle.slice().clone().into()),
+        },
+        Err(e) => BatchSliceStreamItem::Error(SliceError {
+            anchor_turn_id: anchor_str.to_string(),
+            code: SliceError::code_for(&e).to_string(),
+            error: e.to_string(),
+        }),
+    }
+}
+
+/// Check which turns are admissible in a slice.
+///
+/// The service keeps no slice store, so the bundle is reconstructed by
+/// re-slicing `anchor_turn_id` under `policy_ref`. The reconstruction must
+/// reproduce `slice_id` and the supplied token must verify against it
+/// before turns are checked with `filter_admissible`, so clients get the
+/// INV-GK-001 boundary check without reimplementing it.
+async fn is_admissible_handler<S: GraphStore + Send + Sync + 'static>(
+    State(state): State<Arc<ServiceState<S>>>,
+    Json(request): Json<IsAdmissibleRequest>,
+) -> Result<Json<IsAdmissibleResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let parse = |s: &String| {
+        TurnId::from_str(s).map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::new(
+                    "INVALID_TURN_ID",
+                    format!("Invalid turn ID: {}", e),
+                ).with_details(s.clone())),
+            )
+        })
+    };
+
+    let anchor_id = parse(&request.anchor_turn_id)?;
+    let requested = request.turn_ids.iter().map(parse).collect::<Result<Vec<_>, _>>()?;
+
+    if let Some(ref pref) = request.policy_ref {
+        validate_policy_ref(pref)?;
+    }
+
+    // Resolve policy (in a block to ensure guard is dropped before await)
+    let (policy, hmac_secret, store) = {
+        let registry = state.policy_registry.read().unwrap();
+        let policy = if let Some(ref pref) = request.policy_ref {
+            registry.resolve(pref).cloned().ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::new(
+                        "POLICY_NOT_FOUND",
+                        format!("Policy not found: {:?}", pref),
+                    )),
+                )
+            })?
+        } else {
+            SlicePolicyV1::default()
+        };
+        (policy, state.hmac_secret().to_vec(), Arc::clone(&state.store))
+    };
+
+    let slicer = ContextSlicer::new(store, policy, hmac_secret);
+    let bundle = slicer.slice(anchor_id).await.map_err(|e| {
+        let status = match e {
+            SlicerError::AnchorNotFound(_) => StatusCode::NOT_FOUND,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        (
+            status,
+            Json(ErrorResponse::new(
+                SliceError::code_for(&e),
+                format!("Slice reconstruction failed: {}", e),
+            )),
+        )
+    })?;
+
+    let mut slice = SliceExportDto::from(bundle.slice().clone());
+    if slice.slice_id != request.slice_id {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "SLICE_STALE",
+                "Re-slicing the anchor no longer reproduces slice_id",
+            ).with_details(request.slice_id.clone())),
+        ));
+    }
+    slice.admissibility_token = request.admissibility_token.clone();
+    slice.issued_at_unix_ms = request.issued_at_unix_ms;
+    verify_slice_dto(&slice, state.hmac_secret())?;
+
+    let admissible = bundle.filter_admissible(&requested);
+    let results = request
+        .turn_ids
+        .iter()
+        .zip(&requested)
+        .map(|(turn_id, id)| TurnAdmissibility {
+            turn_id: turn_id.clone(),
+            admissible: admissible.contains(id),
+        })
+        .collect();
+
+    Ok(Json(IsAdmissibleResponse {
+        slice_id: slice.slice_id,
+        results,
     }))
 }
 
+/// Compare the turn sets of two slices.
+///
+/// Both slices' tokens and slice_ids are verified before comparison.
+/// The service keeps no slice store, so slices are passed inline.
+async fn compare_slices_handler(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<CompareSlicesRequest>,
+) -> Result<Json<CompareSlicesResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let (left, right) = (&request.left, &request.right);
+    verify_slice_dto(left, state.hmac_secret())?;
+    verify_slice_dto(right, state.hmac_secret())?;
+
+    let left_turns: BTreeSet<&String> = left.turn_ids.iter().collect();
+    let right_turns: BTreeSet<&String> = right.turn_ids.iter().collect();
+
+    Ok(Json(CompareSlicesResponse {
+        left_slice_id: left.slice_id.clone(),
+        right_slice_id: right.slice_id.clone(),
+        added: right_turns.difference(&left_turns).map(|id| id.to_string()).collect(),
+        removed: left_turns.difference(&right_turns).map(|id| id.to_string()).collect(),
+        selection_equivalent: left.selection_equals(right),
+    }))
+}
+
+/// Issue a token for a selection the client built itself.
+///
+/// The kernel does not re-run expansion; it only checks that the input is
+/// canonical (turns and edges strictly ascending, anchor included, edges
+/// between selected turns) and that every turn and edge exists in the
+/// store, then signs the selection under the referenced policy's ID with
+/// `EXTERNAL_SELECTION_PREFIX` prepended, so the token cannot be mistaken
+/// for one the slicer issued.
+async fn issue_token_handler<S: GraphStore + Send + Sync + 'static>(
+    State(state): State<Arc<ServiceState<S>>>,
+    Json(request): Json<IssueTokenRequest>,
+) -> Result<Json<SliceExportDto>, (StatusCode, Json<ErrorResponse>)> {
+    let bad_request = |code: &str, error: String, details: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(code, error).with_details(details)),
+        )
+    };
+
+    let parse = |s: &String| {
+        TurnId::from_str(s).map_err(|e| {
+            bad_request("INVALID_TURN_ID", format!("Invalid turn ID: {}", e), s.clone())
+        })
+    };
+    let anchor_id = parse(&request.anchor_turn_id)?;
+    let turn_ids = request.turn_ids.iter().map(parse).collect::<Result<Vec<_>, _>>()?;
+
+    // Canonical input: strictly ascending turns and edges
+    if let Some(pair) = turn_ids.windows(2).find(|pair| pair[0] >= pair[1]) {
+        return Err(bad_request(
+            "NON_CANONICAL_INPUT",
+            "turn_ids must be strictly ascending".to_string(),
+            pair[1].to_string(),
+        ));
+    }
+    if let Some(pair) = request.edges.windows(2).find(|pair| pair[0] >= pair[1]) {
+        return Err(bad_request(
+            "NON_CANONICAL_INPUT",
+            "edges must be strictly ascending by (parent, child, edge_type)".to_string(),
+            format!("{} -> {}", pair[1].parent, pair[1].child),
+        ));
+    }
+    if turn_ids.binary_search(&anchor_id).is_err() {
+        return Err(bad_request(
+            "NON_CANONICAL_INPUT",
+            "anchor_turn_id must be one of turn_ids".to_string(),
+            anchor_id.to_string(),
+        ));
+    }
+    let in_selection = |id: &TurnId| turn_ids.binary_search(id).is_ok();
+    if let Some(edge) = request.edges.iter().find(|e| !in_selection(&e.parent) || !in_selection(&e.child)) {
+        return Err(bad_request(
+            "NON_CANONICAL_INPUT",
+            "edges must connect selected turns".to_string(),
+            format!("{} -> {}", edge.parent, edge.child),
+        ));
+    }
+
+    if let Some(ref pref) = request.policy_ref {
+        validate_policy_ref(pref)?;
+    }
+
+    // Resolve policy (in a block to ensure guard is dropped before await)
+    let (policy, hmac_secret, store) = {
+        let registry = state.policy_registry.read().unwrap();
+        let policy = if let Some(ref pref) = request.policy_ref {
+            registry.resolve(pref).cloned().ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::new(
+                        "POLICY_NOT_FOUND",
+                        format!("Policy not found: {:?}", pref),
+                    )),
+                )
+            })?
+        } else {
+            SlicePolicyV1::default()
+        };
+        (policy, state.hmac_secret().to_vec(), Arc::clone(&state.store))
+    };
+
+    if let Some(max_turns) = state.max_response_turns.filter(|&max| turn_ids.len() > max) {
+        return Err((
+            StatusCode::PAYLOAD_TOO_LARGE,
+            Json(ErrorResponse::new(
+                "SLICE_TOO_LARGE",
+                format!("Slice has {} turns, limit is {}", turn_ids.len(), max_turns),
+            ).with_details(turn_ids.len().to_string())),
+        ));
+    }
+
+    let store_error = |e: S::Error| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("STORE_ERROR", format!("Store query failed: {}", e))),
+        )
+    };
+
+    let turns = store.get_turns(&turn_ids).await.map_err(store_error)?;
+    if let Some(missing) = turn_ids.iter().find(|id| !turns.iter().any(|t| t.id == **id)) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(
+                "TURN_NOT_FOUND",
+                "Selected turn does not exist in the store",
+            ).with_details(missing.to_string())),
+        ));
+    }
+
+    let stored_edges = store.get_edges(&turn_ids).await.map_err(store_error)?;
+    if let Some(edge) = request.edges.iter().find(|e| !stored_edges.contains(e)) {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(
+                "EDGE_NOT_FOUND",
+                "Selected edge does not exist in the store",
+            ).with_details(format!("{} -> {}", edge.parent, edge.child))),
+        ));
+    }
+
+    let graph_snapshot_hash = selection_snapshot_hash(&turns, request.edges.len());
+    let slice = SliceExport::new_with_secret(
+        &hmac_secret,
+        anchor_id,
+        turns,
+        request.edges,
+        format!("{}{}", EXTERNAL_SELECTION_PREFIX, policy.policy_id()),
+        policy.params_hash(),
+        graph_snapshot_hash,
+    );
+
+    Ok(Json(slice.into()))
+}
+
+/// Check a client-supplied slice's token and slice_id.
+fn verify_slice_dto(
+    slice: &SliceExportDto,
+    hmac_secret: &[u8],
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if !slice.verify_admissibility(hmac_secret) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "INVALID_TOKEN",
+                "Admissibility token does not match the slice",
+            ).with_details(slice.slice_id.clone())),
+        ));
+    }
+
+    if slice.recompute_slice_id() != slice.slice_id {
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse::new(
+                "SLICE_ID_MISMATCH",
+                "Slice turns or edges do not match slice_id",
+            ).with_details(slice.slice_id.clone())),
+        ));
+    }
+
+    Ok(())
+}
+
 /// List registered policies.
 async fn list_policies_handler(
     State(state): State<Arc<AppState>>,
@@ -391,6 +1089,27 @@ async fn register_policy_handler(
     Json(PolicyRefResponse { policy_ref })
 }
 
+/// List registered sufficiency policies.
+async fn list_sufficiency_policies_handler(
+    State(state): State<Arc<AppState>>,
+) -> Json<SufficiencyPolicyListResponse> {
+    let registry = state.sufficiency_registry.read().unwrap();
+    Json(SufficiencyPolicyListResponse {
+        policies: registry.list(),
+        registry_fingerprint: registry.fingerprint().to_string(),
+    })
+}
+
+/// Register a new sufficiency policy.
+async fn register_sufficiency_policy_handler(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<RegisterSufficiencyPolicyRequest>,
+) -> Json<PolicyRefResponse> {
+    let mut registry = state.sufficiency_registry.write().unwrap();
+    let policy_ref = registry.register(request.policy);
+    Json(PolicyRefResponse { policy_ref })
+}
+
 /// Health check endpoint (detailed).
 ///
 /// Returns full service status including database health.
@@ -491,6 +1210,10 @@ async fn startup_handler(
 ///
 /// Downstream services can call this to verify a token is valid
 /// without needing access to the HMAC secret.
+///
+/// Malformed requests are reported as `invalid_format`. Only a well-formed
+/// token that fails the HMAC check raises a `TokenVerificationFailure`
+/// incident.
 async fn verify_token_handler(
     State(state): State<Arc<AppState>>,
     Json(request): Json<VerifyTokenRequest>,
@@ -505,6 +1228,7 @@ async fn verify_token_handler(
             return Json(VerifyTokenResponse {
                 valid: false,
                 reason: Some("Invalid anchor_turn_id format".to_string()),
+                failure_kind: Some(FailureKind::InvalidFormat),
             });
         }
     };
@@ -512,19 +1236,34 @@ async fn verify_token_handler(
     
     // Create token and verify
     let token = AdmissibilityToken::from_string(request.admissibility_token.clone());
-    let valid = token.verify_hmac(
-        state.hmac_secret(),
+    let verifier = TokenVerifier::new(VerificationMode::local_secret(state.hmac_secret().to_vec()));
+    let result = verifier.verify_token_at(
+        &token,
         &slice_id,
         &anchor_id,
         &request.policy_id,
         &request.policy_params_hash,
         &graph_snapshot_hash,
         &request.schema_version,
+        request.issued_at_unix_ms,
     );
 
+    if let Some(incident) = result.incident(&slice_id, "verify_token_handler") {
+        incident.log();
+    }
+
+    let reason = match result.failure_kind {
+        None => None,
+        Some(FailureKind::InvalidFormat) => Some("Token is not 32 hex characters".to_string()),
+        Some(FailureKind::HmacMismatch) => Some("Token does not match expected HMAC".to_string()),
+        Some(FailureKind::RemoteUnavailable) => Some("Token could not be checked".to_string()),
+        Some(FailureKind::UnknownPolicy) => Some("No secret is configured for this policy".to_string()),
+    };
+
     Json(VerifyTokenResponse {
-        valid,
-        reason: if valid { None } else { Some("Token does not match expected HMAC".to_string()) },
+        valid: result.is_valid,
+        reason,
+        failure_kind: result.failure_kind,
     })
 }
 
@@ -533,23 +1272,709 @@ async fn verify_token_handler(
 // ============================================================================
 
 /// Create the Axum router for the Graph Kernel service.
+///
+/// Requests are checked against `state.rate_limiter` per matched route.
 pub fn create_router(state: AppState) -> Router {
+    let rate_limiter = Arc::clone(&state.rate_limiter);
     let state = Arc::new(state);
 
     Router::new()
         // Slice operations
         .route("/api/slice", post(slice_handler))
         .route("/api/slice/batch", post(batch_slice_handler))
+        .route("/api/slice/batch/stream", post(batch_slice_stream_handler))
+        .route("/api/slice/compare", post(compare_slices_handler))
+        .route("/api/issue_token", post(issue_token_handler))
         // Token verification
         .route("/api/verify_token", post(verify_token_handler))
+        .route("/api/is_admissible", post(is_admissible_handler))
         // Policy management
         .route("/api/policies", get(list_policies_handler))
         .route("/api/policies", post(register_policy_handler))
+        .route("/api/sufficiency_policies", get(list_sufficiency_policies_handler))
+        .route("/api/sufficiency_policies", post(register_sufficiency_policy_handler))
         // Health checks (Cloud Run compatible)
         .route("/health", get(health_handler))           // Detailed health
         .route("/health/live", get(liveness_handler))    // Liveness probe
         .route("/health/ready", get(readiness_handler))  // Readiness probe
         .route("/health/startup", get(startup_handler))  // Startup probe
+        .route_layer(middleware::from_fn_with_state(rate_limiter, rate_limit_middleware))
         .with_state(state)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::slice::GraphSnapshotHash;
+    use crate::types::{EdgeType, Phase, Role, TurnSnapshot};
+    use axum::body::{to_bytes, Body};
+    use axum::http::{header, Method, Request};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    fn test_state() -> AppState {
+        ServiceState::new(PostgresGraphStore::connect_lazy_for_test(), b"test_secret".to_vec())
+    }
+
+    async fn send<T: serde::de::DeserializeOwned>(
+        router: Router,
+        method: Method,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, T) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(match body {
+                Some(json) => Body::from(json.to_string()),
+                None => Body::empty(),
+            })
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    fn make_turn(id: u128) -> TurnSnapshot {
+        TurnSnapshot::new(
+            TurnId::new(Uuid::from_u128(id)),
+            "session_1".to_string(),
+            Role::User,
+            Phase::Exploration,
+            0.5,
+            0,
+            0,
+            0.5,
+            0.5,
+            1.0,
+            1000,
+        )
+    }
+
+    fn make_slice() -> SliceExport {
+        let anchor = TurnId::new(Uuid::from_u128(2));
+        let turns = vec![make_turn(1), make_turn(2), make_turn(3)];
+        let edges = vec![
+            Edge::new(TurnId::new(Uuid::from_u128(1)), anchor, EdgeType::Reply),
+            Edge::new(anchor, TurnId::new(Uuid::from_u128(3)), EdgeType::Branch),
+        ];
+
+        SliceExport::new_with_secret(
+            b"test_secret",
+            anchor,
+            turns,
+            edges,
+            "slice_policy_v1".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_dto_recompute_slice_id_matches() {
+        let slice = make_slice();
+        let expected = slice.slice_id.to_string();
+
+        let dto = SliceExportDto::from(slice);
+        assert_eq!(dto.edge_count, dto.edges.len());
+        assert_eq!(dto.recompute_slice_id(), expected);
+    }
+
+    #[test]
+    fn test_dto_recompute_slice_id_survives_json_roundtrip() {
+        let dto = SliceExportDto::from(make_slice());
+        let json = serde_json::to_string(&dto).unwrap();
+        let parsed: SliceExportDto = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.recompute_slice_id(), dto.slice_id);
+    }
+
+    #[test]
+    fn test_dto_recompute_slice_id_detects_tampering() {
+        let mut dto = SliceExportDto::from(make_slice());
+        dto.edges.pop();
+
+        assert_ne!(dto.recompute_slice_id(), dto.slice_id);
+    }
+
+    #[tokio::test]
+    async fn test_batch_slice_error_codes() {
+        use crate::store::InMemoryGraphStore;
+
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(make_turn(1));
+        let state = Arc::new(ServiceState::new(store, b"test_secret".to_vec()));
+        let router = Router::new()
+            .route("/api/slice/batch", post(batch_slice_handler::<InMemoryGraphStore>))
+            .with_state(state);
+
+        let existing = TurnId::new(Uuid::from_u128(1)).to_string();
+        let missing = TurnId::new(Uuid::from_u128(99)).to_string();
+        let body = serde_json::json!({ "anchor_turn_ids": [existing, missing, "not-a-uuid"] });
+        let (status, response): (_, BatchSliceResponse) =
+            send(router, Method::POST, "/api/slice/batch", Some(body)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response.success_count, 1);
+        let codes: Vec<_> = response.errors.iter()
+            .map(|e| (e.anchor_turn_id.as_str(), e.code.as_str()))
+            .collect();
+        assert_eq!(codes, vec![(missing.as_str(), "ANCHOR_NOT_FOUND"), ("not-a-uuid", "INVALID_TURN_ID")]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_slice_stream_one_line_per_anchor() {
+        use crate::store::InMemoryGraphStore;
+
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(make_turn(1));
+        store.add_turn(make_turn(2));
+        let state = Arc::new(ServiceState::new(store, b"test_secret".to_vec()));
+        let router = Router::new()
+            .route(
+                "/api/slice/batch/stream",
+                post(batch_slice_stream_handler::<InMemoryGraphStore>),
+            )
+            .with_state(state);
+
+        let first = TurnId::new(Uuid::from_u128(2)).to_string();
+        let missing = TurnId::new(Uuid::from_u128(99)).to_string();
+        let second = TurnId::new(Uuid::from_u128(1)).to_string();
+        let body = serde_json::json!({ "anchor_turn_ids": [first, missing, second] });
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/slice/batch/stream")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/x-ndjson");
+
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let lines: Vec<BatchSliceStreamItem> = std::str::from_utf8(&bytes)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(matches!(&lines[0], BatchSliceStreamItem::Slice(s) if s.anchor_turn_id == first));
+        assert!(matches!(
+            &lines[1],
+            BatchSliceStreamItem::Error(e) if e.anchor_turn_id == missing && e.code == "ANCHOR_NOT_FOUND"
+        ));
+        assert!(matches!(&lines[2], BatchSliceStreamItem::Slice(s) if s.anchor_turn_id == second));
+    }
+
+    #[tokio::test]
+    async fn test_batch_endpoints_enforce_max_response_turns() {
+        use crate::store::InMemoryGraphStore;
+
+        // Anchor 2 pulls in its parent 1; turn 3 stands alone
+        let mut store = InMemoryGraphStore::new();
+        for n in 1..=3 {
+            store.add_turn(make_turn(n));
+        }
+        store.add_edge(Edge::new(TurnId::new(Uuid::from_u128(1)), TurnId::new(Uuid::from_u128(2)), EdgeType::Reply));
+        let state = Arc::new(ServiceState::new(store, b"test_secret".to_vec()).with_max_response_turns(1));
+        let router = Router::new()
+            .route("/api/slice/batch", post(batch_slice_handler::<InMemoryGraphStore>))
+            .route("/api/slice/batch/stream", post(batch_slice_stream_handler::<InMemoryGraphStore>))
+            .with_state(state);
+
+        let large = TurnId::new(Uuid::from_u128(2)).to_string();
+        let small = TurnId::new(Uuid::from_u128(3)).to_string();
+        let body = serde_json::json!({ "anchor_turn_ids": [large, small] });
+
+        let (status, response): (_, BatchSliceResponse) =
+            send(router.clone(), Method::POST, "/api/slice/batch", Some(body.clone())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response.success_count, 1);
+        assert_eq!(response.slices[0].anchor_turn_id, small);
+        assert_eq!(response.errors.len(), 1);
+        assert_eq!((response.errors[0].anchor_turn_id.as_str(), response.errors[0].code.as_str()), (large.as_str(), "SLICE_TOO_LARGE"));
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri("/api/slice/batch/stream")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let bytes = to_bytes(router.oneshot(request).await.unwrap().into_body(), usize::MAX).await.unwrap();
+        let lines: Vec<BatchSliceStreamItem> = std::str::from_utf8(&bytes)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(matches!(&lines[0], BatchSliceStreamItem::Error(e) if e.code == "SLICE_TOO_LARGE"));
+        assert!(matches!(&lines[1], BatchSliceStreamItem::Slice(s) if s.anchor_turn_id == small));
+    }
+
+    #[tokio::test]
+    async fn test_sufficiency_policies_list_defaults() {
+        let router = create_router(test_state());
+
+        let (status, list): (_, SufficiencyPolicyListResponse) =
+            send(router, Method::GET, "/api/sufficiency_policies", None).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            list.policies,
+            vec![PolicyRef::from_sufficiency_policy(&SufficiencyPolicy::default())]
+        );
+        assert!(!list.registry_fingerprint.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_sufficiency_policies_register_and_list() {
+        let state = test_state();
+        let router = create_router(state.clone());
+        let initial_fingerprint = state.sufficiency_registry.read().unwrap().fingerprint().to_string();
+
+        let body = serde_json::json!({ "policy": SufficiencyPolicy::strict() });
+        let (status, registered): (_, PolicyRefResponse) =
+            send(router.clone(), Method::POST, "/api/sufficiency_policies", Some(body)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            registered.policy_ref,
+            PolicyRef::from_sufficiency_policy(&SufficiencyPolicy::strict())
+        );
+
+        let (_, list): (_, SufficiencyPolicyListResponse) =
+            send(router, Method::GET, "/api/sufficiency_policies", None).await;
+
+        assert_eq!(list.policies.len(), 2);
+        assert!(list.policies.contains(&registered.policy_ref));
+        assert_ne!(list.registry_fingerprint, initial_fingerprint);
+    }
+
+    #[tokio::test]
+    async fn test_sufficiency_policies_resolve_registered() {
+        let state = test_state();
+        let router = create_router(state.clone());
+
+        let body = serde_json::json!({ "policy": SufficiencyPolicy::lenient() });
+        let (_, registered): (_, PolicyRefResponse) =
+            send(router, Method::POST, "/api/sufficiency_policies", Some(body)).await;
+
+        let registry = state.sufficiency_registry.read().unwrap();
+        let resolved = registry.resolve(&registered.policy_ref).unwrap();
+        assert_eq!(resolved.min_turns, SufficiencyPolicy::lenient().min_turns);
+        assert!(!resolved.require_exchange);
+    }
+
+    fn admissible_router(state: ServiceState<crate::store::InMemoryGraphStore>) -> Router {
+        use crate::store::InMemoryGraphStore;
+
+        Router::new()
+            .route("/api/slice", post(slice_handler::<InMemoryGraphStore>))
+            .route("/api/is_admissible", post(is_admissible_handler::<InMemoryGraphStore>))
+            .with_state(Arc::new(state))
+    }
+
+    async fn is_admissible_body(router: Router, turn_ids: &[String]) -> serde_json::Value {
+        let (_, response): (_, SliceResponse) =
+            send(router, Method::POST, "/api/slice", Some(anchor_body())).await;
+        let slice = response.slice;
+        serde_json::json!({
+            "slice_id": slice.slice_id,
+            "admissibility_token": slice.admissibility_token,
+            "anchor_turn_id": slice.anchor_turn_id,
+            "turn_ids": turn_ids,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_is_admissible_in_and_out_of_slice() {
+        let router = admissible_router(sufficiency_state());
+        let inside = TurnId::new(Uuid::from_u128(1)).to_string();
+        let outside = TurnId::new(Uuid::from_u128(99)).to_string();
+        let body = is_admissible_body(router.clone(), &[inside.clone(), outside.clone()]).await;
+
+        let (status, response): (_, IsAdmissibleResponse) =
+            send(router, Method::POST, "/api/is_admissible", Some(body.clone())).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response.slice_id, body["slice_id"]);
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(response.results[0].turn_id, inside);
+        assert!(response.results[0].admissible);
+        assert_eq!(response.results[1].turn_id, outside);
+        assert!(!response.results[1].admissible);
+    }
+
+    #[tokio::test]
+    async fn test_is_admissible_rejects_unknown_slice_id() {
+        let router = admissible_router(sufficiency_state());
+        let mut body = is_admissible_body(router.clone(), &[]).await;
+        body["slice_id"] = serde_json::json!(make_slice().slice_id.to_string());
+
+        let (status, error): (_, ErrorResponse) =
+            send(router, Method::POST, "/api/is_admissible", Some(body)).await;
+
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(error.code, "SLICE_STALE");
+    }
+
+    #[tokio::test]
+    async fn test_is_admissible_rejects_foreign_token() {
+        let router = admissible_router(sufficiency_state());
+        let mut body = is_admissible_body(router.clone(), &[]).await;
+        body["admissibility_token"] = serde_json::json!(make_slice().admissibility_token.to_string());
+
+        let (status, error): (_, ErrorResponse) =
+            send(router, Method::POST, "/api/is_admissible", Some(body)).await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(error.code, "INVALID_TOKEN");
+    }
+
+    #[tokio::test]
+    async fn test_compare_slices_one_turn_difference() {
+        let left = make_slice();
+        let anchor = left.anchor_turn_id;
+        let mut turns = left.turns.clone();
+        turns.push(make_turn(4));
+        let mut edges = left.edges.clone();
+        edges.push(Edge::new(anchor, TurnId::new(Uuid::from_u128(4)), EdgeType::Reply));
+        let right = SliceExport::new_with_secret(
+            b"test_secret",
+            anchor,
+            turns,
+            edges,
+            left.policy_id.clone(),
+            left.policy_params_hash.clone(),
+            left.graph_snapshot_hash.clone(),
+        );
+
+        let body = serde_json::json!({
+            "left": SliceExportDto::from(left.clone()),
+            "right": SliceExportDto::from(right),
+        });
+        let (status, response): (_, CompareSlicesResponse) =
+            send(create_router(test_state()), Method::POST, "/api/slice/compare", Some(body)).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response.added, vec![TurnId::new(Uuid::from_u128(4)).to_string()]);
+        assert!(response.removed.is_empty());
+        assert!(!response.selection_equivalent);
+
+        // A slice compared with itself is equivalent
+        let body = serde_json::json!({
+            "left": SliceExportDto::from(left.clone()),
+            "right": SliceExportDto::from(left),
+        });
+        let (status, response): (_, CompareSlicesResponse) =
+            send(create_router(test_state()), Method::POST, "/api/slice/compare", Some(body)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(response.added.is_empty() && response.removed.is_empty());
+        assert!(response.selection_equivalent);
+    }
+
+    fn verify_body(slice: &SliceExport, token: &str) -> serde_json::Value {
+        serde_json::json!({
+            "admissibility_token": token,
+            "slice_id": slice.slice_id.as_str(),
+            "anchor_turn_id": slice.anchor_turn_id.to_string(),
+            "policy_id": slice.policy_id,
+            "policy_params_hash": slice.policy_params_hash,
+            "graph_snapshot_hash": slice.graph_snapshot_hash.as_str(),
+            "schema_version": slice.schema_version,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_verify_token_failure_kinds() {
+        let router = create_router(test_state());
+        let slice = make_slice();
+
+        let body = verify_body(&slice, slice.admissibility_token.as_str());
+        let (_, valid): (_, VerifyTokenResponse) =
+            send(router.clone(), Method::POST, "/api/verify_token", Some(body)).await;
+        assert!(valid.valid);
+        assert_eq!(valid.failure_kind, None);
+
+        let body = verify_body(&slice, "zz-not-hex");
+        let (_, malformed): (_, VerifyTokenResponse) =
+            send(router.clone(), Method::POST, "/api/verify_token", Some(body)).await;
+        assert!(!malformed.valid);
+        assert_eq!(malformed.failure_kind, Some(FailureKind::InvalidFormat));
+
+        let foreign = SliceExport::new_with_secret(
+            b"other_secret",
+            slice.anchor_turn_id,
+            slice.turns.clone(),
+            slice.edges.clone(),
+            slice.policy_id.clone(),
+            slice.policy_params_hash.clone(),
+            slice.graph_snapshot_hash.clone(),
+        );
+        let body = verify_body(&slice, foreign.admissibility_token.as_str());
+        let (_, mismatch): (_, VerifyTokenResponse) =
+            send(router, Method::POST, "/api/verify_token", Some(body)).await;
+        assert!(!mismatch.valid);
+        assert_eq!(mismatch.failure_kind, Some(FailureKind::HmacMismatch));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_returns_429() {
+        use crate::service::middleware::{RateLimitConfig, RateLimiter, CLIENT_ID_HEADER};
+
+        let proxy: std::net::IpAddr = "10.0.0.1".parse().unwrap();
+        let limiter = RateLimiter::new()
+            .limit("/health/live", RateLimitConfig::new(0.5, 2))
+            .trust_proxies([proxy]);
+        let router = create_router(test_state().with_rate_limiter(limiter));
+        let probe = |peer: &str, client: &str| {
+            Request::builder()
+                .uri("/health/live")
+                .header(CLIENT_ID_HEADER, client)
+                .header("x-forwarded-for", client)
+                .extension(axum::extract::ConnectInfo(std::net::SocketAddr::new(peer.parse().unwrap(), 4000)))
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        // Rotating headers does not escape the limit of a direct peer
+        for client in ["client-a", "client-b"] {
+            let response = router.clone().oneshot(probe("192.0.2.7", client)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        let response = router.clone().oneshot(probe("192.0.2.7", "client-c")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // Behind a trusted proxy, clients are told apart by header
+        for _ in 0..2 {
+            let response = router.clone().oneshot(probe("10.0.0.1", "client-a")).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        let response = router.clone().oneshot(probe("10.0.0.1", "client-a")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "2");
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let error: ErrorResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(error.code, "RATE_LIMITED");
+
+        // Limits are per client
+        let response = router.oneshot(probe("10.0.0.1", "client-b")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    fn sufficiency_router(state: ServiceState<crate::store::InMemoryGraphStore>) -> Router {
+        Router::new()
+            .route("/api/slice", post(slice_handler::<crate::store::InMemoryGraphStore>))
+            .with_state(Arc::new(state))
+    }
+
+    fn sufficiency_state() -> ServiceState<crate::store::InMemoryGraphStore> {
+        let mut store = crate::store::InMemoryGraphStore::new();
+        store.add_turn(make_turn(1));
+        store.add_turn(make_turn(2));
+        store.add_edge(Edge::new(
+            TurnId::new(Uuid::from_u128(1)),
+            TurnId::new(Uuid::from_u128(2)),
+            EdgeType::Reply,
+        ));
+        ServiceState::new(store, b"test_secret".to_vec())
+    }
+
+    fn anchor_body() -> serde_json::Value {
+        serde_json::json!({ "anchor_turn_id": TurnId::new(Uuid::from_u128(2)).to_string() })
+    }
+
+    #[tokio::test]
+    async fn test_slice_enforce_sufficiency_pass() {
+        let router = sufficiency_router(
+            sufficiency_state().with_default_sufficiency_policy(SufficiencyPolicy::lenient()),
+        );
+        let (status, response): (_, SliceResponse) = send(
+            router,
+            Method::POST,
+            "/api/slice?enforce_sufficiency=true",
+            Some(anchor_body()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(response.sufficiency.unwrap().is_sufficient);
+    }
+
+    #[tokio::test]
+    async fn test_slice_enforce_sufficiency_fail() {
+        // Two user turns fail the default policy (no exchange, one role)
+        let router = sufficiency_router(sufficiency_state());
+        let (status, error): (_, ErrorResponse) = send(
+            router,
+            Method::POST,
+            "/api/slice?enforce_sufficiency=true",
+            Some(anchor_body()),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error.code, "INSUFFICIENT_EVIDENCE");
+        assert!(error.details.unwrap().contains("NoExchange"));
+    }
+
+    #[tokio::test]
+    async fn test_slice_sufficiency_not_enforced() {
+        // A configured policy is reported but not enforced
+        let router = sufficiency_router(
+            sufficiency_state().with_default_sufficiency_policy(SufficiencyPolicy::strict()),
+        );
+        let (status, response): (_, SliceResponse) =
+            send(router, Method::POST, "/api/slice", Some(anchor_body())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(!response.sufficiency.unwrap().is_sufficient);
+
+        // Without a configured policy no check runs
+        let router = sufficiency_router(sufficiency_state());
+        let (status, response): (_, SliceResponse) =
+            send(router, Method::POST, "/api/slice", Some(anchor_body())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(response.sufficiency.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_slice_canonical_output_is_byte_stable() {
+        // The sufficiency metrics carry HashMaps and floats
+        let render = || async {
+            let router = sufficiency_router(
+                sufficiency_state().with_default_sufficiency_policy(SufficiencyPolicy::lenient()),
+            );
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri("/api/slice?canonical=true")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(anchor_body().to_string()))
+                .unwrap();
+            let response = router.oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            to_bytes(response.into_body(), usize::MAX).await.unwrap()
+        };
+
+        let first = render().await;
+        for _ in 0..5 {
+            assert_eq!(render().await, first);
+        }
+
+        let parsed: SliceResponse = serde_json::from_slice(&first).unwrap();
+        assert_eq!(canonical_json(&parsed).as_bytes(), &first[..]);
+        assert!(first.starts_with(b"{\"policy_ref\":"));
+    }
+
+    #[tokio::test]
+    async fn test_slice_max_response_turns() {
+        // The default policy pulls both turns
+        let router = sufficiency_router(sufficiency_state().with_max_response_turns(1));
+        let (status, error): (_, ErrorResponse) =
+            send(router, Method::POST, "/api/slice", Some(anchor_body())).await;
+        assert_eq!(status, StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(error.code, "SLICE_TOO_LARGE");
+        assert_eq!(error.details.as_deref(), Some("2"));
+
+        let router = sufficiency_router(sufficiency_state().with_max_response_turns(2));
+        let (status, response): (_, SliceResponse) =
+            send(router, Method::POST, "/api/slice", Some(anchor_body())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response.slice.turn_ids.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_slice_malformed_vs_unknown_policy_ref() {
+        let body = |policy_id: &str, params_hash: &str| {
+            let mut body = anchor_body();
+            body["policy_ref"] = serde_json::json!({ "policy_id": policy_id, "params_hash": params_hash });
+            body
+        };
+
+        let router = sufficiency_router(sufficiency_state());
+        let (status, error): (_, ErrorResponse) =
+            send(router, Method::POST, "/api/slice", Some(body("slice_policy_v1", "not a hash"))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(error.code, "INVALID_POLICY_REF");
+
+        let router = sufficiency_router(sufficiency_state());
+        let (status, error): (_, ErrorResponse) =
+            send(router, Method::POST, "/api/slice", Some(body("slice_policy_v1", "0123456789abcdef"))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(error.code, "POLICY_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn test_issue_token_for_external_selection() {
+        use crate::store::InMemoryGraphStore;
+
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let mut store = InMemoryGraphStore::new();
+        for n in 1..=3 {
+            store.add_turn(make_turn(n));
+        }
+        store.add_edge(Edge::new(id(1), id(2), EdgeType::Reply));
+        store.add_edge(Edge::new(id(2), id(3), EdgeType::Branch));
+        let state = Arc::new(ServiceState::new(store, b"test_secret".to_vec()));
+        let router = Router::new()
+            .route("/api/issue_token", post(issue_token_handler::<InMemoryGraphStore>))
+            .route("/api/slice", post(slice_handler::<InMemoryGraphStore>))
+            .with_state(state);
+
+        let body = |turns: &[u128], edges: serde_json::Value| {
+            serde_json::json!({
+                "anchor_turn_id": id(2).to_string(),
+                "turn_ids": turns.iter().map(|&n| id(n).to_string()).collect::<Vec<_>>(),
+                "edges": edges,
+            })
+        };
+        let edge = |p: u128, c: u128, t: EdgeType| serde_json::to_value(Edge::new(id(p), id(c), t)).unwrap();
+        let both_edges = serde_json::json!([edge(1, 2, EdgeType::Reply), edge(2, 3, EdgeType::Branch)]);
+
+        // The same selection the slicer makes is signed, but marked external
+        let (status, issued): (_, SliceExportDto) =
+            send(router.clone(), Method::POST, "/api/issue_token", Some(body(&[1, 2, 3], both_edges.clone()))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(issued.verify_admissibility(b"test_secret"));
+        assert_eq!(issued.recompute_slice_id(), issued.slice_id);
+        assert!(issued.is_external_selection());
+        assert_eq!(issued.policy_id, "external:slice_policy_v1");
+        let (_, sliced): (_, SliceResponse) = send(
+            router.clone(),
+            Method::POST,
+            "/api/slice",
+            Some(serde_json::json!({ "anchor_turn_id": id(2).to_string() })),
+        )
+        .await;
+        assert!(!sliced.slice.is_external_selection());
+        assert_eq!(issued.turn_ids, sliced.slice.turn_ids);
+        assert_eq!(issued.edges, sliced.slice.edges);
+        assert_ne!(issued.slice_id, sliced.slice.slice_id);
+
+        let rejections = [
+            (body(&[2, 1, 3], both_edges.clone()), StatusCode::BAD_REQUEST, "NON_CANONICAL_INPUT"),
+            (
+                body(&[1, 2, 3], serde_json::json!([edge(2, 3, EdgeType::Branch), edge(1, 2, EdgeType::Reply)])),
+                StatusCode::BAD_REQUEST,
+                "NON_CANONICAL_INPUT",
+            ),
+            (body(&[1, 3], serde_json::json!([])), StatusCode::BAD_REQUEST, "NON_CANONICAL_INPUT"),
+            (body(&[1, 2, 99], serde_json::json!([])), StatusCode::NOT_FOUND, "TURN_NOT_FOUND"),
+            (
+                body(&[1, 2, 3], serde_json::json!([edge(1, 3, EdgeType::Reply)])),
+                StatusCode::NOT_FOUND,
+                "EDGE_NOT_FOUND",
+            ),
+        ];
+        for (request, expected_status, expected_code) in rejections {
+            let (status, error): (_, ErrorResponse) =
+                send(router.clone(), Method::POST, "/api/issue_token", Some(request)).await;
+            assert_eq!(status, expected_status);
+            assert_eq!(error.code, expected_code);
+        }
+    }
+}
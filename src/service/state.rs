@@ -1,14 +1,33 @@
 //! Service state management.
 //!
-//! Contains the PolicyRegistry and shared service state.
+//! Contains the PolicyRegistry, SufficiencyPolicyRegistry, and shared service state.
 
 use std::collections::BTreeMap;
+use std::str::FromStr;
 use std::sync::{Arc, RwLock};
 use serde::{Deserialize, Serialize};
 
 use crate::canonical::canonical_hash_hex;
 use crate::policy::SlicePolicyV1;
 use crate::store::GraphStore;
+use crate::types::SufficiencyPolicy;
+
+use super::middleware::RateLimiter;
+
+/// Why a policy reference is malformed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PolicyRefError {
+    /// `policy_id` is empty or contains characters outside `[A-Za-z0-9_.-]`.
+    #[error("Invalid policy_id: '{0}'")]
+    InvalidPolicyId(String),
+    /// `params_hash` is empty, too long, or contains whitespace, control
+    /// characters, non-ASCII or `|`.
+    #[error("Invalid params_hash: '{0}' (expected 1-128 printable ASCII characters without '|')")]
+    InvalidParamsHash(String),
+    /// The string form is not `policy_id:params_hash`.
+    #[error("Invalid policy reference: '{0}' (expected policy_id:params_hash)")]
+    InvalidFormat(String),
+}
 
 /// Reference to a registered policy by hash.
 ///
@@ -17,10 +36,13 @@ use crate::store::GraphStore;
 pub struct PolicyRef {
     /// Policy type identifier (e.g., "slice_policy_v1")
     pub policy_id: String,
-    /// xxHash64 of canonical policy JSON
+    /// xxHash64 of canonical policy JSON, or a slicer's params hash override
     pub params_hash: String,
 }
 
+/// Longest `params_hash` a `PolicyRef` accepts.
+pub const MAX_PARAMS_HASH_LEN: usize = 128;
+
 impl PolicyRef {
     /// Create a policy reference from a SlicePolicyV1.
     pub fn from_policy(policy: &SlicePolicyV1) -> Self {
@@ -30,6 +52,14 @@ impl PolicyRef {
         }
     }
 
+    /// Create a policy reference from a SufficiencyPolicy.
+    pub fn from_sufficiency_policy(policy: &SufficiencyPolicy) -> Self {
+        Self {
+            policy_id: policy.policy_id().to_string(),
+            params_hash: policy.params_hash(),
+        }
+    }
+
     /// Create a reference with explicit values.
     pub fn new(policy_id: impl Into<String>, params_hash: impl Into<String>) -> Self {
         Self {
@@ -37,6 +67,55 @@ impl PolicyRef {
             params_hash: params_hash.into(),
         }
     }
+
+    /// Check that the reference is well-formed.
+    ///
+    /// `policy_id` must be non-empty `[A-Za-z0-9_.-]`. `params_hash` must be
+    /// 1 to `MAX_PARAMS_HASH_LEN` printable ASCII characters other than `|`
+    /// (the token field separator), which admits both `canonical_hash_hex`
+    /// output and the external hashes `ContextSlicer::with_params_hash_override`
+    /// stamps, such as `shared-scheme:0f1e2d3c`.
+    pub fn validate(&self) -> Result<(), PolicyRefError> {
+        let id_ok = !self.policy_id.is_empty()
+            && self
+                .policy_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+        if !id_ok {
+            return Err(PolicyRefError::InvalidPolicyId(self.policy_id.clone()));
+        }
+
+        let hash_ok = !self.params_hash.is_empty()
+            && self.params_hash.len() <= MAX_PARAMS_HASH_LEN
+            && self
+                .params_hash
+                .chars()
+                .all(|c| c.is_ascii_graphic() && c != '|');
+        if !hash_ok {
+            return Err(PolicyRefError::InvalidParamsHash(self.params_hash.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for PolicyRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.policy_id, self.params_hash)
+    }
+}
+
+impl FromStr for PolicyRef {
+    type Err = PolicyRefError;
+
+    /// Parse `policy_id:params_hash`, validating both parts.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (policy_id, params_hash) = s
+            .split_once(':')
+            .ok_or_else(|| PolicyRefError::InvalidFormat(s.to_string()))?;
+        let policy_ref = Self::new(policy_id, params_hash);
+        policy_ref.validate()?;
+        Ok(policy_ref)
+    }
 }
 
 /// Registry of immutable policies with stable hashes.
@@ -121,6 +200,88 @@ impl Default for PolicyRegistry {
     }
 }
 
+/// Registry of immutable sufficiency policies with stable hashes.
+///
+/// Mirrors `PolicyRegistry` so promotion thresholds can be managed
+/// centrally and referenced by PolicyRef.
+#[derive(Debug, Clone)]
+pub struct SufficiencyPolicyRegistry {
+    policies: BTreeMap<PolicyRef, SufficiencyPolicy>,
+    registry_fingerprint: String,
+}
+
+impl SufficiencyPolicyRegistry {
+    /// Create a new empty registry.
+    pub fn new() -> Self {
+        let mut registry = Self {
+            policies: BTreeMap::new(),
+            registry_fingerprint: String::new(),
+        };
+        registry.update_fingerprint();
+        registry
+    }
+
+    /// Create a registry with the default sufficiency policy pre-registered.
+    pub fn with_defaults() -> Self {
+        let mut registry = Self::new();
+        registry.register(SufficiencyPolicy::default());
+        registry
+    }
+
+    /// Register a policy and return its reference.
+    ///
+    /// If the policy already exists (same hash), returns the existing reference.
+    pub fn register(&mut self, policy: SufficiencyPolicy) -> PolicyRef {
+        let policy_ref = PolicyRef::from_sufficiency_policy(&policy);
+
+        if !self.policies.contains_key(&policy_ref) {
+            self.policies.insert(policy_ref.clone(), policy);
+            self.update_fingerprint();
+        }
+
+        policy_ref
+    }
+
+    /// Resolve a policy reference to the actual policy.
+    pub fn resolve(&self, policy_ref: &PolicyRef) -> Option<&SufficiencyPolicy> {
+        self.policies.get(policy_ref)
+    }
+
+    /// Get all registered policy references.
+    pub fn list(&self) -> Vec<PolicyRef> {
+        self.policies.keys().cloned().collect()
+    }
+
+    /// Get the registry fingerprint.
+    ///
+    /// This changes whenever policies are added/removed.
+    pub fn fingerprint(&self) -> &str {
+        &self.registry_fingerprint
+    }
+
+    /// Get the number of registered policies.
+    pub fn len(&self) -> usize {
+        self.policies.len()
+    }
+
+    /// Check if the registry is empty.
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    /// Update the registry fingerprint.
+    fn update_fingerprint(&mut self) {
+        let refs: Vec<_> = self.policies.keys().collect();
+        self.registry_fingerprint = canonical_hash_hex(&refs);
+    }
+}
+
+impl Default for SufficiencyPolicyRegistry {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
 /// Shared service state.
 ///
 /// Contains the graph store, policy registry, and HMAC secret for token signing.
@@ -129,6 +290,15 @@ pub struct ServiceState<S: GraphStore + Send + Sync + 'static> {
     pub store: Arc<S>,
     /// Registry of available policies.
     pub policy_registry: Arc<RwLock<PolicyRegistry>>,
+    /// Registry of available sufficiency policies.
+    pub sufficiency_registry: Arc<RwLock<SufficiencyPolicyRegistry>>,
+    /// Sufficiency policy checked on `/api/slice` responses (`None` = only
+    /// when enforcement is requested, using `SufficiencyPolicy::default()`).
+    pub default_sufficiency_policy: Option<SufficiencyPolicy>,
+    /// Per-route request limits (none by default).
+    pub rate_limiter: Arc<RateLimiter>,
+    /// Largest slice (in turns) `/api/slice` will return (`None` = no limit).
+    pub max_response_turns: Option<usize>,
     /// HMAC secret for signing admissibility tokens.
     hmac_secret: Arc<Vec<u8>>,
 }
@@ -143,6 +313,10 @@ impl<S: GraphStore + Send + Sync + 'static> ServiceState<S> {
         Self {
             store: Arc::new(store),
             policy_registry: Arc::new(RwLock::new(PolicyRegistry::with_defaults())),
+            sufficiency_registry: Arc::new(RwLock::new(SufficiencyPolicyRegistry::with_defaults())),
+            default_sufficiency_policy: None,
+            rate_limiter: Arc::new(RateLimiter::new()),
+            max_response_turns: None,
             hmac_secret: Arc::new(hmac_secret),
         }
     }
@@ -152,10 +326,38 @@ impl<S: GraphStore + Send + Sync + 'static> ServiceState<S> {
         Self {
             store: Arc::new(store),
             policy_registry: Arc::new(RwLock::new(registry)),
+            sufficiency_registry: Arc::new(RwLock::new(SufficiencyPolicyRegistry::with_defaults())),
+            default_sufficiency_policy: None,
+            rate_limiter: Arc::new(RateLimiter::new()),
+            max_response_turns: None,
             hmac_secret: Arc::new(hmac_secret),
         }
     }
 
+    /// Replace the sufficiency policy registry.
+    pub fn with_sufficiency_registry(mut self, registry: SufficiencyPolicyRegistry) -> Self {
+        self.sufficiency_registry = Arc::new(RwLock::new(registry));
+        self
+    }
+
+    /// Check every `/api/slice` response against `policy`.
+    pub fn with_default_sufficiency_policy(mut self, policy: SufficiencyPolicy) -> Self {
+        self.default_sufficiency_policy = Some(policy);
+        self
+    }
+
+    /// Replace the rate limiter applied by `create_router`.
+    pub fn with_rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.rate_limiter = Arc::new(limiter);
+        self
+    }
+
+    /// Reject `/api/slice` responses with more than `max_turns` turns.
+    pub fn with_max_response_turns(mut self, max_turns: usize) -> Self {
+        self.max_response_turns = Some(max_turns);
+        self
+    }
+
     /// Create service state from environment variables.
     ///
     /// Reads `KERNEL_HMAC_SECRET` from environment.
@@ -187,6 +389,10 @@ impl<S: GraphStore + Send + Sync + 'static> Clone for ServiceState<S> {
         Self {
             store: Arc::clone(&self.store),
             policy_registry: Arc::clone(&self.policy_registry),
+            sufficiency_registry: Arc::clone(&self.sufficiency_registry),
+            default_sufficiency_policy: self.default_sufficiency_policy.clone(),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+            max_response_turns: self.max_response_turns,
             hmac_secret: Arc::clone(&self.hmac_secret),
         }
     }
@@ -232,6 +438,26 @@ mod tests {
         assert_ne!(registry.fingerprint(), initial_fingerprint);
     }
 
+    #[test]
+    fn test_policy_ref_parse_and_validate() {
+        let policy_ref = PolicyRef::from_policy(&SlicePolicyV1::default());
+        assert_eq!(policy_ref.validate(), Ok(()));
+        assert_eq!(policy_ref.to_string().parse::<PolicyRef>(), Ok(policy_ref));
+
+        assert!(matches!("slice_policy_v1".parse::<PolicyRef>(), Err(PolicyRefError::InvalidFormat(_))));
+        assert!(matches!(":02005444cae1117d".parse::<PolicyRef>(), Err(PolicyRefError::InvalidPolicyId(_))));
+        assert!(matches!("slice policy:02005444cae1117d".parse::<PolicyRef>(), Err(PolicyRefError::InvalidPolicyId(_))));
+        assert!(matches!("slice_policy_v1:".parse::<PolicyRef>(), Err(PolicyRefError::InvalidParamsHash(_))));
+        assert!(matches!("slice_policy_v1:abc def".parse::<PolicyRef>(), Err(PolicyRefError::InvalidParamsHash(_))));
+        assert!(matches!("slice_policy_v1:abc|def".parse::<PolicyRef>(), Err(PolicyRefError::InvalidParamsHash(_))));
+        let too_long = format!("slice_policy_v1:{}", "a".repeat(MAX_PARAMS_HASH_LEN + 1));
+        assert!(matches!(too_long.parse::<PolicyRef>(), Err(PolicyRefError::InvalidParamsHash(_))));
+
+        // Params hash overrides are accepted verbatim
+        let overridden: PolicyRef = "slice_policy_v1:shared-scheme:0f1e2d3c".parse().unwrap();
+        assert_eq!(overridden.params_hash, "shared-scheme:0f1e2d3c");
+    }
+
     #[test]
     fn test_policy_ref_from_policy() {
         let policy = SlicePolicyV1::default();
@@ -241,5 +467,27 @@ mod tests {
         assert_eq!(ref1, ref2);
         assert_eq!(ref1.policy_id, "slice_policy_v1");
     }
-}
 
+    #[test]
+    fn test_sufficiency_registry_register() {
+        let mut registry = SufficiencyPolicyRegistry::new();
+
+        let ref1 = registry.register(SufficiencyPolicy::strict());
+        let ref2 = registry.register(SufficiencyPolicy::strict());
+
+        assert_eq!(ref1, ref2);
+        assert_eq!(ref1.policy_id, "sufficiency_policy_v1");
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_sufficiency_registry_fingerprint_changes() {
+        let mut registry = SufficiencyPolicyRegistry::with_defaults();
+        let initial_fingerprint = registry.fingerprint().to_string();
+
+        registry.register(SufficiencyPolicy::lenient());
+
+        assert_ne!(registry.fingerprint(), initial_fingerprint);
+        assert_eq!(registry.len(), 2);
+    }
+}
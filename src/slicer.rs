@@ -3,12 +3,18 @@
 //! The slicer expands around an anchor turn using a priority queue,
 //! respecting budget caps and producing a deterministic slice.
 
-use std::collections::{BinaryHeap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::policy::{SlicePolicyV1, scoring::ExpansionCandidate};
-use crate::store::GraphStore;
-use crate::types::{TurnId, TurnSnapshot, SliceExport, GraphSnapshotHash, AdmissibleEvidenceBundle, VerificationError};
+use serde::{Deserialize, Serialize};
+
+use crate::policy::{SlicePolicyV1, PolicyVersion, scoring::ExpansionCandidate};
+use crate::canonical::canonical_hash_hex;
+use crate::MIN_HMAC_SECRET_LEN;
+use crate::store::{GraphStore, NeighborOrder};
+use crate::types::{Edge, TurnId, TurnSnapshot, SliceExport, SliceFingerprint, GraphSnapshotHash, AdmissibleEvidenceBundle, VerificationError};
+use crate::types::{Incident, IncidentType, AuditSink, NoOpAuditSink, ReplayProvenance, SecretResolver};
+use crate::types::AdmissionReason;
 
 /// Error type for slicer operations.
 #[derive(Debug, thiserror::Error)]
@@ -22,6 +28,26 @@ pub enum SlicerError {
     /// Verification error (should never happen - internal consistency violation).
     #[error("Internal verification error: {0}")]
     VerificationError(#[from] VerificationError),
+    /// The slice cannot be reproduced under this slicer's policy and store.
+    #[error("Slice not reproducible under current policy: {0}")]
+    NotReproducible(String),
+    /// The policy's `max_store_calls` ran out before the slice was complete.
+    #[error("Store call budget of {0} exhausted")]
+    BudgetExhausted(usize),
+    /// A turn pinned with `with_pinned_turns` is not in the store.
+    #[error("Pinned turn not found: {0}")]
+    PinnedTurnNotFound(TurnId),
+    /// The HMAC secret is shorter than the required minimum.
+    #[error("HMAC secret is {len} bytes, minimum is {min}")]
+    WeakSecret {
+        /// Length of the rejected secret.
+        len: usize,
+        /// Required minimum length.
+        min: usize,
+    },
+    /// The slicer's secret resolver has no secret for the policy.
+    #[error("No HMAC secret configured for policy: {0}")]
+    NoSecretForPolicy(String),
 }
 
 impl SlicerError {
@@ -31,6 +57,221 @@ impl SlicerError {
     }
 }
 
+/// Check that an HMAC secret is at least `min_len` bytes.
+///
+/// HMAC accepts keys of any size, so an empty or one-byte secret would
+/// silently issue forgeable tokens. `MIN_HMAC_SECRET_LEN` is the
+/// recommended minimum.
+pub fn validate_hmac_secret(secret: &[u8], min_len: usize) -> Result<(), SlicerError> {
+    if secret.len() < min_len {
+        return Err(SlicerError::WeakSecret {
+            len: secret.len(),
+            min: min_len,
+        });
+    }
+    Ok(())
+}
+
+/// A turn whose stored content no longer matches a slice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TurnDrift {
+    /// The drifted turn.
+    pub turn_id: TurnId,
+    /// Content hash recorded in the slice.
+    pub expected_hash: String,
+    /// Content hash in the current graph (`None` if the turn is gone or unhashed).
+    pub current_hash: Option<String>,
+}
+
+/// Content drift found in one stored slice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DriftReport {
+    /// The scanned slice.
+    pub slice_id: SliceFingerprint,
+    /// Drifted turns, sorted by turn ID (empty if the slice is clean).
+    pub drifted_turns: Vec<TurnDrift>,
+}
+
+impl DriftReport {
+    /// Whether any turn in the slice has drifted.
+    pub fn has_drift(&self) -> bool {
+        !self.drifted_turns.is_empty()
+    }
+
+    /// One `ContentHashMismatch` incident per drifted turn, sorted by turn ID.
+    ///
+    /// A turn shared by several drifted slices is reported once.
+    pub fn incidents(reports: &[DriftReport]) -> Vec<Incident> {
+        let mut by_turn: BTreeMap<TurnId, &TurnDrift> = BTreeMap::new();
+        for drift in reports.iter().flat_map(|r| &r.drifted_turns) {
+            by_turn.entry(drift.turn_id).or_insert(drift);
+        }
+
+        by_turn
+            .into_values()
+            .map(|drift| {
+                Incident::new(
+                    IncidentType::ContentHashMismatch {
+                        turn_id: drift.turn_id,
+                        expected_hash: drift.expected_hash.clone(),
+                        computed_hash: drift.current_hash.clone().unwrap_or_else(|| "missing".to_string()),
+                    },
+                    "drift_scan",
+                )
+            })
+            .collect()
+    }
+}
+
+/// How an anchor's slice changed across a sequence of graph snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StabilityReport {
+    /// The anchor that was sliced.
+    pub anchor_turn_id: TurnId,
+    /// Slice ID in each snapshot, in input order.
+    pub slice_ids: Vec<SliceFingerprint>,
+    /// Jaccard similarity of the turn sets of consecutive snapshots
+    /// (`len = snapshots - 1`).
+    pub pairwise_jaccard: Vec<f32>,
+    /// Mean of `pairwise_jaccard` (1.0 with fewer than two snapshots).
+    pub stability_score: f32,
+}
+
+/// Graph snapshot hash of a selection, as `ContextSlicer::slice` issues it.
+///
+/// Prefers content hashes for true immutability and falls back to
+/// turn/edge stats when any turn lacks one.
+#[allow(deprecated)]
+pub(crate) fn selection_snapshot_hash(turns: &[TurnSnapshot], edge_count: usize) -> GraphSnapshotHash {
+    if turns.iter().all(|t| t.content_hash.is_some()) {
+        // Use content-derived hash (production mode)
+        let mut turn_hashes: Vec<(TurnId, String)> = turns
+            .iter()
+            .map(|t| (t.id, t.content_hash.clone().unwrap()))
+            .collect();
+        // Sort by TurnId for determinism
+        turn_hashes.sort_by_key(|a| a.0);
+
+        GraphSnapshotHash::from_content_hashes(
+            &turn_hashes,
+            edge_count as u64,
+            crate::GRAPH_KERNEL_SCHEMA_VERSION,
+        )
+    } else {
+        // Fall back to stats-based hash (backwards compatibility)
+        let max_created_at = turns.iter()
+            .map(|t| t.created_at)
+            .max()
+            .unwrap_or(0);
+        GraphSnapshotHash::from_stats(
+            max_created_at,
+            turns.len() as u64,
+            edge_count as u64,
+            crate::GRAPH_KERNEL_SCHEMA_VERSION,
+        )
+    }
+}
+
+/// Counts store queries against `SlicePolicyV1::max_store_calls`.
+///
+/// One call is held back for the final edge fetch, so expansion can always
+/// be finalized once it stops.
+struct StoreCallBudget {
+    remaining: Option<usize>,
+}
+
+impl StoreCallBudget {
+    fn new(limit: Option<usize>) -> Self {
+        Self {
+            remaining: limit.map(|limit| limit.saturating_sub(1)),
+        }
+    }
+
+    /// Spend one call; returns false when none are left.
+    fn charge(&mut self) -> bool {
+        match &mut self.remaining {
+            None => true,
+            Some(0) => false,
+            Some(remaining) => {
+                *remaining -= 1;
+                true
+            }
+        }
+    }
+}
+
+/// Result of replaying a recorded slice.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ReplayOutcome {
+    /// Re-slicing reproduced the recorded fingerprint.
+    Match,
+    /// Re-slicing produced a different fingerprint (graph or pipeline changed).
+    Drift {
+        /// Fingerprint recorded in the provenance.
+        expected: String,
+        /// Fingerprint produced by the replay.
+        actual: String,
+    },
+    /// The provenance cannot be replayed with this slicer.
+    Incompatible {
+        /// Why the replay was not attempted or did not complete.
+        reason: String,
+    },
+}
+
+/// Re-executes recorded slices to check the provenance Replay Contract.
+///
+/// Given identical provenance, re-slicing the recorded anchor must yield
+/// the recorded `slice_fingerprint`. A mismatch is reported as `Drift`.
+pub struct ReplayHarness;
+
+impl ReplayHarness {
+    /// Re-slice the provenance's anchor and compare fingerprints.
+    ///
+    /// Returns `Incompatible` when the provenance has no anchor, when the
+    /// slicer's policy ID or params hash differ from the recorded retrieval
+    /// parameters (an empty recorded hash is not checked), or when slicing
+    /// fails (e.g. the anchor no longer exists).
+    pub async fn replay<S: GraphStore + Send + Sync + 'static>(
+        provenance: &ReplayProvenance,
+        slicer: &ContextSlicer<S>,
+    ) -> ReplayOutcome {
+        let incompatible = |reason: String| ReplayOutcome::Incompatible { reason };
+
+        let Some(anchor_id) = provenance.anchor_turn_id else {
+            return incompatible("provenance has no anchor_turn_id".to_string());
+        };
+
+        let params = &provenance.retrieval_params;
+        if params.slice_policy_version != slicer.policy().policy_id() {
+            return incompatible(format!(
+                "policy {} recorded, slicer uses {}",
+                params.slice_policy_version,
+                slicer.policy().policy_id()
+            ));
+        }
+        let params_hash = slicer.params_hash();
+        if !params.policy_params_hash.is_empty() && params.policy_params_hash != params_hash {
+            return incompatible(format!(
+                "params hash {} recorded, slicer uses {}",
+                params.policy_params_hash, params_hash
+            ));
+        }
+
+        match slicer.slice(anchor_id).await {
+            Ok(bundle) if bundle.slice_id().as_str() == provenance.slice_fingerprint => {
+                ReplayOutcome::Match
+            }
+            Ok(bundle) => ReplayOutcome::Drift {
+                expected: provenance.slice_fingerprint.clone(),
+                actual: bundle.slice_id().to_string(),
+            },
+            Err(e) => incompatible(e.to_string()),
+        }
+    }
+}
+
 /// Deterministic context slicer.
 ///
 /// Expands around an anchor turn to produce a context slice.
@@ -42,9 +283,25 @@ impl SlicerError {
 /// 3. While frontier not empty and nodes < max_nodes:
 ///    - Pop highest priority candidate
 ///    - Add to slice
-///    - Add unvisited parents/children to frontier (distance + 1)
-///    - If include_siblings: add siblings up to limit
-/// 4. Return slice (sorted for determinism)
+///    - Add unvisited parents/children to frontier (distance + 1), as allowed
+///      by `expansion_direction` and `allowed_edge_types`
+///    - If include_siblings and direction is `Both`: add siblings up to limit
+///      (distance + sibling_distance_offset)
+///    - If `ancestor_budget_fraction` is set, non-ancestor turns beyond their
+///      share of max_nodes are deferred until no ancestor candidates remain
+///    - If `max_store_calls` runs out, fail with `BudgetExhausted` or, with
+///      `truncate_on_store_budget`, stop and keep the turns selected so far
+///    - If `max_depth_delta` is set, turns whose `trajectory_depth` is
+///      outside the band around the anchor's are skipped
+///    - Turns whose role is in `excluded_roles` are skipped
+///    - If `complexity_budget` is set, each turn spends its
+///      `trajectory_complexity`; stop at the first turn that does not fit
+/// 4. If `include_root_path` is set, walk from the anchor to the root via
+///    the highest-priority parent and add that path (cut short, like
+///    expansion, when `max_store_calls` runs out under
+///    `truncate_on_store_budget`)
+/// 5. Return slice (sorted for determinism)
+/// 6. Report the issued bundle to the audit sink
 ///
 /// ## Security
 ///
@@ -55,6 +312,23 @@ pub struct ContextSlicer<S: GraphStore> {
     policy: SlicePolicyV1,
     /// HMAC secret for signing admissibility tokens.
     hmac_secret: Vec<u8>,
+    /// Per-policy secrets, used instead of `hmac_secret` when set.
+    secret_resolver: Option<Arc<dyn SecretResolver>>,
+    /// Receives every issued bundle.
+    audit_sink: Arc<dyn AuditSink>,
+    /// Whether issued slices carry `issued_at_unix_ms`.
+    stamp_issued_at: bool,
+    /// Externally supplied `policy_params_hash`, replacing `policy.params_hash()`.
+    params_hash_override: Option<String>,
+    /// Turns added to every slice after expansion, sorted and deduplicated.
+    pinned_turns: Vec<TurnId>,
+    /// Whether slice edges are collected during expansion instead of
+    /// re-queried afterwards.
+    edges_from_traversal: bool,
+    /// Whether undirected edges are normalized with `Edge::canonicalize`.
+    canonical_edges: bool,
+    /// Whether issued slices carry `unverified_turn_provenance`.
+    annotate_provenance: bool,
 }
 
 impl<S: GraphStore + Send + Sync + 'static> ContextSlicer<S> {
@@ -64,14 +338,171 @@ impl<S: GraphStore + Send + Sync + 'static> ContextSlicer<S> {
     /// * `store` - The graph store backend
     /// * `policy` - Slice policy configuration
     /// * `hmac_secret` - Secret key for signing admissibility tokens (32+ bytes recommended)
+    ///
+    /// Logs a warning when the secret is shorter than `MIN_HMAC_SECRET_LEN`;
+    /// use `new_strict` to reject it instead.
     pub fn new(store: Arc<S>, policy: SlicePolicyV1, hmac_secret: Vec<u8>) -> Self {
-        Self { store, policy, hmac_secret }
+        if let Err(e) = validate_hmac_secret(&hmac_secret, MIN_HMAC_SECRET_LEN) {
+            tracing::warn!(error = %e, "Weak HMAC secret; admissibility tokens may be forgeable");
+        }
+        Self::new_unchecked(store, policy, hmac_secret)
+    }
+
+    /// Create a context slicer, rejecting secrets shorter than `min_secret_len`.
+    ///
+    /// Returns `WeakSecret` instead of warning. Pass `MIN_HMAC_SECRET_LEN`
+    /// for the recommended minimum.
+    pub fn new_strict(
+        store: Arc<S>,
+        policy: SlicePolicyV1,
+        hmac_secret: Vec<u8>,
+        min_secret_len: usize,
+    ) -> Result<Self, SlicerError> {
+        validate_hmac_secret(&hmac_secret, min_secret_len)?;
+        Ok(Self::new_unchecked(store, policy, hmac_secret))
+    }
+
+    fn new_unchecked(store: Arc<S>, policy: SlicePolicyV1, hmac_secret: Vec<u8>) -> Self {
+        Self {
+            store,
+            policy,
+            hmac_secret,
+            secret_resolver: None,
+            audit_sink: Arc::new(NoOpAuditSink),
+            stamp_issued_at: false,
+            params_hash_override: None,
+            pinned_turns: Vec::new(),
+            edges_from_traversal: false,
+            canonical_edges: false,
+            annotate_provenance: false,
+        }
+    }
+
+    /// Create a context slicer that signs with a per-policy secret.
+    ///
+    /// Tokens are signed with `resolver.secret_for(policy_id)`. Slicing
+    /// fails with `NoSecretForPolicy` if the resolver has no secret for
+    /// this slicer's policy. Logs a warning when the resolved secret is
+    /// shorter than `MIN_HMAC_SECRET_LEN`.
+    pub fn new_with_resolver(
+        store: Arc<S>,
+        policy: SlicePolicyV1,
+        resolver: Arc<dyn SecretResolver>,
+    ) -> Self {
+        if let Some(Err(e)) = resolver
+            .secret_for(policy.policy_id())
+            .map(|secret| validate_hmac_secret(secret, MIN_HMAC_SECRET_LEN))
+        {
+            tracing::warn!(error = %e, "Weak HMAC secret; admissibility tokens may be forgeable");
+        }
+        let mut slicer = Self::new_unchecked(store, policy, Vec::new());
+        slicer.secret_resolver = Some(resolver);
+        slicer
+    }
+
+    /// Secret that signs this slicer's tokens.
+    fn signing_secret(&self) -> Result<&[u8], SlicerError> {
+        match &self.secret_resolver {
+            Some(resolver) => resolver
+                .secret_for(self.policy.policy_id())
+                .ok_or_else(|| SlicerError::NoSecretForPolicy(self.policy.policy_id().to_string())),
+            None => Ok(&self.hmac_secret),
+        }
+    }
+
+    /// Stamp issued slices with the current time.
+    ///
+    /// The timestamp is bound into the token (not `slice_id`), so consumers
+    /// can reject stale evidence with `AdmissibleEvidenceBundle::is_stale`.
+    /// Off by default because it makes tokens differ between runs.
+    pub fn with_issue_timestamps(mut self, enabled: bool) -> Self {
+        self.stamp_issued_at = enabled;
+        self
+    }
+
+    /// Use `hash` as the slices' `policy_params_hash` instead of
+    /// `SlicePolicyV1::params_hash`.
+    ///
+    /// For deployments that fingerprint policies with a shared
+    /// cross-language scheme. The value is copied verbatim into every slice
+    /// and therefore into `slice_id` and the token.
+    ///
+    /// **This bypasses the kernel's float quantization.** The caller is
+    /// responsible for the hash being deterministic and for changing it
+    /// whenever the policy changes; a stale override makes slices from
+    /// different policies indistinguishable. Pinned turns and slicer options
+    /// are not folded into an override, so the external hash must cover them.
+    pub fn with_params_hash_override(mut self, hash: String) -> Self {
+        self.params_hash_override = Some(hash);
+        self
+    }
+
+    /// Always include `ids` in the slice, regardless of expansion.
+    ///
+    /// Pinned turns are fetched after expansion and added to the selection
+    /// even beyond `max_radius` or `max_nodes`; edges between them and the
+    /// rest of the slice are included as usual. Their sorted IDs are folded
+    /// into `params_hash`, so pinning shows up in `slice_id` and the token,
+    /// unless `with_params_hash_override` is set: an override is always
+    /// emitted unchanged, and must itself account for the pins.
+    /// Slicing fails with `PinnedTurnNotFound` if a pinned turn is missing.
+    pub fn with_pinned_turns(mut self, mut ids: Vec<TurnId>) -> Self {
+        ids.sort();
+        ids.dedup();
+        self.pinned_turns = ids;
+        self
+    }
+
+    /// Build slice edges from the traversal instead of a final query.
+    ///
+    /// Each expanded turn is fetched with one `get_incident_edges` call in
+    /// place of separate parent and child lookups, and the typed edges are
+    /// kept. Afterwards only edges between turns that were never expanded
+    /// (max-radius leaves, root path, pinned turns) are fetched with
+    /// `get_edges`. The resulting edge set, and so `slice_id`, is identical
+    /// to the default path. Fewer store calls are charged against
+    /// `max_store_calls`, so budget-truncated slices may include more turns;
+    /// the option is therefore folded into `params_hash`.
+    pub fn with_edges_from_traversal(mut self, enabled: bool) -> Self {
+        self.edges_from_traversal = enabled;
+        self
+    }
+
+    /// Normalize the orientation of undirected edges in issued slices.
+    ///
+    /// For stores that record symmetric relations (`Related`) in either
+    /// direction: with this enabled, `(a, b)` and `(b, a)` export as the
+    /// same edge, so the recorded orientation no longer changes `slice_id`.
+    /// Directed edges keep their orientation. Off by default because it
+    /// changes `slice_id` for slices with reversed undirected edges; the
+    /// option is folded into `params_hash`.
+    pub fn with_canonical_edges(mut self, enabled: bool) -> Self {
+        self.canonical_edges = enabled;
+        self
+    }
+
+    /// Record why each turn was admitted in `SliceExport::unverified_turn_provenance`.
+    ///
+    /// The annotation is metadata: `slice_id` and the token are the same
+    /// with or without it, so verification does not vouch for it.
+    pub fn with_turn_provenance(mut self, enabled: bool) -> Self {
+        self.annotate_provenance = enabled;
+        self
+    }
+
+    /// Record every issued slice to `sink`.
+    ///
+    /// The sink is called after the bundle is built, so it never influences
+    /// slice contents or `slice_id`.
+    pub fn with_audit_sink(mut self, sink: Arc<dyn AuditSink>) -> Self {
+        self.audit_sink = sink;
+        self
     }
 
     /// Create a slicer for testing (uses empty secret, tokens not cryptographically valid).
     #[cfg(test)]
     pub fn new_for_test(store: Arc<S>, policy: SlicePolicyV1) -> Self {
-        Self::new(store, policy, b"test_secret_for_unit_tests".to_vec())
+        Self::new_unchecked(store, policy, b"test_secret_for_unit_tests".to_vec())
     }
 
     /// Create a context slice around an anchor turn.
@@ -86,23 +517,73 @@ impl<S: GraphStore + Send + Sync + 'static> ContextSlicer<S> {
     /// we enforce **INV-GK-003: No Phantom Authority** at the API boundary.
     /// Downstream systems cannot accidentally operate on unverified slices.
     pub async fn slice(&self, anchor_id: TurnId) -> Result<AdmissibleEvidenceBundle, SlicerError> {
+        let mut budget = StoreCallBudget::new(self.policy.max_store_calls);
+        let budget_exhausted = || SlicerError::BudgetExhausted(self.policy.max_store_calls.unwrap_or(0));
+        let mut truncated = false;
+
         // Get anchor turn
+        if !budget.charge() {
+            return Err(budget_exhausted());
+        }
         let anchor = self.store.get_turn(&anchor_id).await
             .map_err(|e| SlicerError::StoreError(e.to_string()))?
-            .ok_or_else(|| SlicerError::AnchorNotFound(anchor_id))?;
+            .ok_or(SlicerError::AnchorNotFound(anchor_id))?;
 
         // Initialize state
         let mut selected: Vec<TurnSnapshot> = Vec::new();
         let mut visited: HashSet<TurnId> = HashSet::new();
         let mut frontier: BinaryHeap<ExpansionCandidate> = BinaryHeap::new();
+        // How each pushed turn was reached; only selected entries are kept
+        let mut reasons: HashMap<TurnId, AdmissionReason> = HashMap::from([(anchor_id, AdmissionReason::Anchor)]);
+
+        // Depth band around the anchor and excluded roles, if the policy
+        // sets them (the anchor itself is never filtered)
+        let anchor_depth = anchor.trajectory_depth;
+        let eligible = |turn: &TurnSnapshot| {
+            let in_depth_band = match self.policy.max_depth_delta {
+                Some(delta) => turn.trajectory_depth.abs_diff(anchor_depth) <= delta,
+                None => true,
+            };
+            in_depth_band && !self.policy.excluded_roles.contains(&turn.role)
+        };
 
         // Start with anchor
         let anchor_candidate = ExpansionCandidate::new(anchor, 0, &self.policy);
         frontier.push(anchor_candidate);
         visited.insert(anchor_id);
 
+        // Ancestor budget reservation: turns reached through parent edges
+        // are tracked so other turns can be held back once they have used
+        // their share of max_nodes.
+        let mut ancestors: HashSet<TurnId> = HashSet::new();
+        let mut non_ancestor_cap = self.policy.ancestor_budget_fraction.map(|fraction| {
+            let reserved = (fraction.clamp(0.0, 1.0) * self.policy.max_nodes as f32).ceil() as usize;
+            self.policy.max_nodes.saturating_sub(reserved)
+        });
+        let mut non_ancestor_count = 0usize;
+        let mut deferred: Vec<ExpansionCandidate> = Vec::new();
+
+        // Complexity left to spend, if the policy sets a budget
+        let mut complexity_left = self.policy.complexity_budget;
+
+        // Edges seen while expanding, and the turns whose incident edges
+        // are all known (only with `edges_from_traversal`)
+        let mut traversed_edges: Vec<Edge> = Vec::new();
+        let mut expanded: HashSet<TurnId> = HashSet::new();
+
         // Expand
-        while let Some(candidate) = frontier.pop() {
+        'expand: loop {
+            let candidate = match frontier.pop() {
+                Some(candidate) => candidate,
+                None if !deferred.is_empty() => {
+                    // Reserve is no longer reachable: release held-back turns
+                    frontier.extend(deferred.drain(..));
+                    non_ancestor_cap = None;
+                    continue;
+                }
+                None => break,
+            };
+
             // Check budget
             if selected.len() >= self.policy.max_nodes {
                 break;
@@ -117,7 +598,28 @@ impl<S: GraphStore + Send + Sync + 'static> ContextSlicer<S> {
             let next_distance = candidate.distance + 1;
             let current_distance = candidate.distance;
 
-            // Add to selected
+            // Hold back non-ancestor turns (never the anchor) beyond their share
+            let is_ancestor = ancestors.contains(&turn_id);
+            if let Some(cap) = non_ancestor_cap {
+                if !is_ancestor && turn_id != anchor_id && non_ancestor_count >= cap {
+                    deferred.push(candidate);
+                    continue;
+                }
+            }
+
+            // Charge complexity (the anchor is always admitted)
+            if let Some(left) = complexity_left.as_mut() {
+                let cost = candidate.turn.trajectory_complexity.max(0.0);
+                if turn_id != anchor_id && cost > *left {
+                    break;
+                }
+                *left -= cost;
+            }
+
+            // Add to selected, counting only turns actually admitted
+            if !is_ancestor {
+                non_ancestor_count += 1;
+            }
             selected.push(candidate.turn);
 
             // Skip expansion if at max radius
@@ -125,121 +627,575 @@ impl<S: GraphStore + Send + Sync + 'static> ContextSlicer<S> {
                 continue;
             }
 
+            let direction = self.policy.expansion_direction;
+            let siblings_enabled = self.policy.include_siblings
+                && self.policy.max_siblings_per_node > 0
+                && direction.follows_siblings();
+            let edge_filter = self.policy.allowed_edge_types.as_ref();
+
+            let (mut parents, mut children) = if self.edges_from_traversal {
+                // One incident-edge query replaces the parent and child lookups
+                if !budget.charge() {
+                    truncated = true;
+                    break 'expand;
+                }
+                let incident = self.store.get_incident_edges(&turn_id).await
+                    .map_err(|e| SlicerError::StoreError(e.to_string()))?;
+                let parents: Vec<TurnId> = incident.iter()
+                    .filter(|e| direction.follows_parents() && e.child == turn_id)
+                    .map(|e| e.parent)
+                    .collect();
+                let children: Vec<TurnId> = incident.iter()
+                    .filter(|e| direction.follows_children() && e.parent == turn_id)
+                    .map(|e| e.child)
+                    .collect();
+                traversed_edges.extend(incident);
+                expanded.insert(turn_id);
+                match self.order_neighbors(parents, children, &mut budget).await? {
+                    Some(ordered) => ordered,
+                    None => {
+                        truncated = true;
+                        break 'expand;
+                    }
+                }
+            } else {
+                // The store applies `neighbor_order`, in its query if it can
+                let order = self.policy.neighbor_order;
+                let parents = if direction.follows_parents() {
+                    if !budget.charge() {
+                        truncated = true;
+                        break 'expand;
+                    }
+                    self.store.get_parents_ordered(&turn_id, order).await
+                        .map_err(|e| SlicerError::StoreError(e.to_string()))?
+                } else {
+                    Vec::new()
+                };
+
+                let children = if direction.follows_children() {
+                    if !budget.charge() {
+                        truncated = true;
+                        break 'expand;
+                    }
+                    self.store.get_children_ordered(&turn_id, order).await
+                        .map_err(|e| SlicerError::StoreError(e.to_string()))?
+                } else {
+                    Vec::new()
+                };
+
+                (parents, children)
+            };
+
+            let mut siblings = if siblings_enabled {
+                if !budget.charge() {
+                    truncated = true;
+                    break 'expand;
+                }
+                self.store.get_siblings(&turn_id, self.policy.max_siblings_per_node).await
+                    .map_err(|e| SlicerError::StoreError(e.to_string()))?
+            } else {
+                Vec::new()
+            };
+
+            // Drop neighbors only reachable through disallowed edge types
+            if let Some(allowed) = edge_filter {
+                let mut neighborhood = vec![turn_id];
+                neighborhood.extend(parents.iter().chain(&children).chain(&siblings).copied());
+                if !budget.charge() {
+                    truncated = true;
+                    break 'expand;
+                }
+                let edges = self.store.get_edges(&neighborhood).await
+                    .map_err(|e| SlicerError::StoreError(e.to_string()))?;
+                let allowed_edges: HashSet<(TurnId, TurnId)> = edges
+                    .into_iter()
+                    .filter(|e| allowed.contains(&e.edge_type))
+                    .map(|e| (e.parent, e.child))
+                    .collect();
+
+                parents.retain(|p| allowed_edges.contains(&(*p, turn_id)));
+                children.retain(|c| allowed_edges.contains(&(turn_id, *c)));
+                // A sibling needs an allowed edge from an allowed parent
+                // (siblings only expand under `Both`, so parents are known)
+                siblings.retain(|s| parents.iter().any(|p| allowed_edges.contains(&(*p, *s))));
+            }
+
             // Expand to parents
-            let parents = self.store.get_parents(&turn_id).await
-                .map_err(|e| SlicerError::StoreError(e.to_string()))?;
-            
             for parent_id in parents {
                 if !visited.contains(&parent_id) {
+                    if !budget.charge() {
+                        truncated = true;
+                        break 'expand;
+                    }
                     visited.insert(parent_id);
+                    ancestors.insert(parent_id);
                     if let Some(parent) = self.store.get_turn(&parent_id).await
-                        .map_err(|e| SlicerError::StoreError(e.to_string()))? 
+                        .map_err(|e| SlicerError::StoreError(e.to_string()))?
+                        .filter(|t| eligible(t))
                     {
                         let candidate = ExpansionCandidate::new(parent, next_distance, &self.policy);
+                        reasons.insert(parent_id, AdmissionReason::ParentHop);
                         frontier.push(candidate);
                     }
                 }
             }
 
             // Expand to children
-            let children = self.store.get_children(&turn_id).await
-                .map_err(|e| SlicerError::StoreError(e.to_string()))?;
-            
             for child_id in children {
                 if !visited.contains(&child_id) {
+                    if !budget.charge() {
+                        truncated = true;
+                        break 'expand;
+                    }
                     visited.insert(child_id);
                     if let Some(child) = self.store.get_turn(&child_id).await
-                        .map_err(|e| SlicerError::StoreError(e.to_string()))? 
+                        .map_err(|e| SlicerError::StoreError(e.to_string()))?
+                        .filter(|t| eligible(t))
                     {
                         let candidate = ExpansionCandidate::new(child, next_distance, &self.policy);
+                        reasons.insert(child_id, AdmissionReason::ChildHop);
                         frontier.push(candidate);
                     }
                 }
             }
 
             // Expand to siblings if enabled
-            if self.policy.include_siblings && self.policy.max_siblings_per_node > 0 {
-                let siblings = self.store.get_siblings(&turn_id, self.policy.max_siblings_per_node).await
-                    .map_err(|e| SlicerError::StoreError(e.to_string()))?;
-                
-                for sibling_id in siblings {
-                    if !visited.contains(&sibling_id) {
-                        visited.insert(sibling_id);
-                        if let Some(sibling) = self.store.get_turn(&sibling_id).await
-                            .map_err(|e| SlicerError::StoreError(e.to_string()))? 
-                        {
-                            // Siblings are at the same distance as the current node
-                            let candidate = ExpansionCandidate::new(sibling, current_distance, &self.policy);
-                            frontier.push(candidate);
-                        }
+            for sibling_id in siblings {
+                if !visited.contains(&sibling_id) {
+                    if !budget.charge() {
+                        truncated = true;
+                        break 'expand;
+                    }
+                    visited.insert(sibling_id);
+                    if let Some(sibling) = self.store.get_turn(&sibling_id).await
+                        .map_err(|e| SlicerError::StoreError(e.to_string()))?
+                        .filter(|t| eligible(t))
+                    {
+                        // Siblings are at the current node's distance plus the policy offset
+                        let sibling_distance = current_distance.saturating_add(self.policy.sibling_distance_offset);
+                        let candidate = ExpansionCandidate::new(sibling, sibling_distance, &self.policy);
+                        reasons.insert(sibling_id, AdmissionReason::Sibling);
+                        frontier.push(candidate);
                     }
                 }
             }
         }
 
-        // Collect edges between selected turns
-        let selected_ids: Vec<TurnId> = selected.iter().map(|t| t.id).collect();
-        let edges = self.store.get_edges(&selected_ids).await
-            .map_err(|e| SlicerError::StoreError(e.to_string()))?;
+        if truncated && !self.policy.truncate_on_store_budget {
+            return Err(budget_exhausted());
+        }
 
-        // Compute graph snapshot hash from selected turns
-        // Prefer content hashes for true immutability, fall back to stats
-        #[allow(deprecated)]
-        let graph_snapshot_hash = {
-            // Check if all turns have content hashes
-            let all_have_hashes = selected.iter().all(|t| t.content_hash.is_some());
-            
-            if all_have_hashes {
-                // Use content-derived hash (production mode)
-                let mut turn_hashes: Vec<(TurnId, String)> = selected
-                    .iter()
-                    .map(|t| (t.id, t.content_hash.clone().unwrap()))
+        // Force the root-to-anchor path into the slice
+        if self.policy.include_root_path {
+            let mut selected_ids: HashSet<TurnId> = selected.iter().map(|t| t.id).collect();
+            let mut on_path: HashSet<TurnId> = HashSet::from([anchor_id]);
+            let mut current = anchor_id;
+            let mut distance = 0u32;
+            loop {
+                if !budget.charge() {
+                    if self.policy.truncate_on_store_budget {
+                        break;
+                    }
+                    return Err(budget_exhausted());
+                }
+                let parent_ids: Vec<TurnId> = self.store.get_parents(&current).await
+                    .map_err(|e| SlicerError::StoreError(e.to_string()))?
+                    .into_iter()
+                    .filter(|id| !on_path.contains(id))
                     .collect();
-                // Sort by TurnId for determinism
-                turn_hashes.sort_by(|a, b| a.0.cmp(&b.0));
-                
-                GraphSnapshotHash::from_content_hashes(
-                    &turn_hashes,
-                    edges.len() as u64,
-                    crate::GRAPH_KERNEL_SCHEMA_VERSION,
-                )
-            } else {
-                // Fall back to stats-based hash (backwards compatibility)
-                let max_created_at = selected.iter()
-                    .map(|t| t.created_at)
-                    .max()
-                    .unwrap_or(0);
-                GraphSnapshotHash::from_stats(
-                    max_created_at,
-                    selected.len() as u64,
-                    edges.len() as u64,
-                    crate::GRAPH_KERNEL_SCHEMA_VERSION,
-                )
+                if parent_ids.is_empty() {
+                    break;
+                }
+                if !budget.charge() {
+                    if self.policy.truncate_on_store_budget {
+                        break;
+                    }
+                    return Err(budget_exhausted());
+                }
+                distance = distance.saturating_add(1);
+                let best = self.store.get_turns(&parent_ids).await
+                    .map_err(|e| SlicerError::StoreError(e.to_string()))?
+                    .into_iter()
+                    .map(|t| ExpansionCandidate::new(t, distance, &self.policy))
+                    .max();
+                let Some(best) = best else {
+                    break;
+                };
+                current = best.turn.id;
+                on_path.insert(current);
+                if selected_ids.insert(current) {
+                    reasons.insert(current, AdmissionReason::RootPath);
+                    selected.push(best.turn);
+                }
+            }
+        }
+
+        // Add pinned turns the expansion did not reach
+        let missing_pins: Vec<TurnId> = self
+            .pinned_turns
+            .iter()
+            .filter(|id| !selected.iter().any(|t| t.id == **id))
+            .copied()
+            .collect();
+        if !missing_pins.is_empty() {
+            if !budget.charge() {
+                return Err(budget_exhausted());
+            }
+            let pinned = self.store.get_turns(&missing_pins).await
+                .map_err(|e| SlicerError::StoreError(e.to_string()))?;
+            if let Some(id) = missing_pins.iter().find(|id| !pinned.iter().any(|t| t.id == **id)) {
+                return Err(SlicerError::PinnedTurnNotFound(*id));
+            }
+            reasons.extend(pinned.iter().map(|t| (t.id, AdmissionReason::Pinned)));
+            selected.extend(pinned);
+        }
+
+        // Collect edges between selected turns (uses the held-back call)
+        let selected_ids: Vec<TurnId> = selected.iter().map(|t| t.id).collect();
+        let mut edges = if self.edges_from_traversal {
+            let in_slice: HashSet<TurnId> = selected_ids.iter().copied().collect();
+            let mut edges: Vec<Edge> = traversed_edges
+                .into_iter()
+                .filter(|e| in_slice.contains(&e.parent) && in_slice.contains(&e.child))
+                .collect();
+            // Every edge touching an expanded turn is known; only edges
+            // among the remaining turns need a query
+            let unexpanded: Vec<TurnId> = selected_ids
+                .iter()
+                .filter(|id| !expanded.contains(id))
+                .copied()
+                .collect();
+            if !unexpanded.is_empty() {
+                edges.extend(self.store.get_edges(&unexpanded).await
+                    .map_err(|e| SlicerError::StoreError(e.to_string()))?);
             }
+            edges.sort();
+            edges.dedup();
+            edges
+        } else {
+            self.store.get_edges(&selected_ids).await
+                .map_err(|e| SlicerError::StoreError(e.to_string()))?
         };
+        if let Some(allowed) = &self.policy.allowed_edge_types {
+            edges.retain(|e| allowed.contains(&e.edge_type));
+        }
+        if self.canonical_edges {
+            edges = edges.into_iter().map(Edge::canonicalize).collect();
+            edges.sort();
+            edges.dedup();
+        }
+
+        // Compute graph snapshot hash from selected turns
+        let graph_snapshot_hash = selection_snapshot_hash(&selected, edges.len());
+        let unverified_turn_provenance = self.annotate_provenance.then(|| {
+            selected
+                .iter()
+                .filter_map(|t| reasons.get(&t.id).map(|reason| (t.id, *reason)))
+                .collect::<BTreeMap<TurnId, AdmissionReason>>()
+        });
 
         // Create slice export with HMAC-signed token
-        let slice = SliceExport::new_with_secret(
-            &self.hmac_secret,
+        let secret = self.signing_secret()?;
+        let mut slice = SliceExport::new_with_secret(
+            secret,
             anchor_id,
             selected,
             edges,
             self.policy.policy_id().to_string(),
-            self.policy.params_hash(),
+            self.params_hash(),
             graph_snapshot_hash,
         );
+        slice.unverified_turn_provenance = unverified_turn_provenance;
+        slice.policy_semantics_version = Some(self.policy.semantics_version());
+        let slice = if self.stamp_issued_at {
+            slice.with_issued_at(secret, Some(chrono::Utc::now().timestamp_millis()))
+        } else {
+            slice
+        };
 
         // Wrap in AdmissibleEvidenceBundle (verification always passes since we just issued the token)
         // This enforces INV-GK-003: No Phantom Authority at the API boundary
-        let bundle = AdmissibleEvidenceBundle::from_verified(slice, &self.hmac_secret)?;
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret)?;
+        self.audit_sink.record_slice_issued(&bundle);
         Ok(bundle)
     }
 
+    /// Sort parent and child IDs taken from incident edges into `neighbor_order`.
+    ///
+    /// `BySalienceDesc` looks both lists up with a single `get_turns` call,
+    /// charged against `budget`; returns `None` if the budget is spent.
+    async fn order_neighbors(
+        &self,
+        mut parents: Vec<TurnId>,
+        mut children: Vec<TurnId>,
+        budget: &mut StoreCallBudget,
+    ) -> Result<Option<(Vec<TurnId>, Vec<TurnId>)>, SlicerError> {
+        for ids in [&mut parents, &mut children] {
+            ids.sort();
+            ids.dedup();
+        }
+        if self.policy.neighbor_order == NeighborOrder::ById
+            || (parents.is_empty() && children.is_empty())
+        {
+            return Ok(Some((parents, children)));
+        }
+
+        if !budget.charge() {
+            return Ok(None);
+        }
+        let ids: Vec<TurnId> = parents.iter().chain(&children).copied().collect();
+        let salience: HashMap<TurnId, f32> = self.store.get_turns(&ids).await
+            .map_err(|e| SlicerError::StoreError(e.to_string()))?
+            .into_iter()
+            .map(|t| (t.id, t.salience))
+            .collect();
+        crate::store::sort_ids_by_salience(&mut parents, &salience);
+        crate::store::sort_ids_by_salience(&mut children, &salience);
+        Ok(Some((parents, children)))
+    }
+
+    /// Find the smallest `max_radius` and `max_nodes` that reproduce `slice`.
+    ///
+    /// Starting from this slicer's policy, binary-searches first the radius
+    /// and then the node cap for the smallest values whose slice around the
+    /// same anchor still contains every turn of `slice`. All other policy
+    /// parameters are kept. Probe slices are not reported to the audit sink.
+    ///
+    /// The node search is exact (a lower cap selects a prefix of the same
+    /// expansion). The radius search assumes coverage does not shrink as the
+    /// radius grows, which holds unless far turns outrank near ones under a
+    /// tight node cap. Either way the result is deterministic.
+    ///
+    /// Returns `NotReproducible` if the current policy itself does not cover
+    /// the slice.
+    pub async fn minimal_policy_for(&self, slice: &SliceExport) -> Result<SlicePolicyV1, SlicerError> {
+        let anchor_id = slice.anchor_turn_id;
+        let target: Vec<TurnId> = slice.turns.iter().map(|t| t.id).collect();
+
+        let mut policy = self.policy.clone();
+        if !self.covers(&policy, anchor_id, &target).await? {
+            return Err(SlicerError::NotReproducible(slice.slice_id.to_string()));
+        }
+
+        // Smallest covering radius
+        let (mut lo, mut hi) = (0, policy.max_radius);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let mut probe = policy.clone();
+            probe.max_radius = mid;
+            if self.covers(&probe, anchor_id, &target).await? {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        policy.max_radius = lo;
+
+        // Smallest covering node cap at that radius
+        let (mut lo, mut hi) = (target.len().clamp(1, policy.max_nodes), policy.max_nodes);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let mut probe = policy.clone();
+            probe.max_nodes = mid;
+            if self.covers(&probe, anchor_id, &target).await? {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        policy.max_nodes = lo;
+
+        Ok(policy)
+    }
+
+    /// Whether slicing `anchor_id` under `policy` selects every turn in `target`.
+    async fn covers(&self, policy: &SlicePolicyV1, anchor_id: TurnId, target: &[TurnId]) -> Result<bool, SlicerError> {
+        let probe = self.probe(self.store.clone(), policy.clone());
+        let bundle = probe.slice(anchor_id).await?;
+        Ok(target.iter().all(|id| bundle.is_turn_admissible(id)))
+    }
+
+    /// Check stored slices for content drift against the current graph.
+    ///
+    /// All referenced turns are fetched in a single `get_turns` call. A turn
+    /// has drifted when the slice recorded a content hash and the store now
+    /// holds a different one, or no longer holds the turn. Turns sliced
+    /// without a content hash cannot be checked and are skipped.
+    ///
+    /// Slices stamped with a different policy semantics version are passed
+    /// to `check_policy_version`, which reports them to the audit sink.
+    ///
+    /// Returns one report per slice, sorted by `slice_id`. Use
+    /// `DriftReport::incidents` to raise incidents for the drifted turns.
+    pub async fn scan_drift(&self, slices: &[SliceExport]) -> Result<Vec<DriftReport>, SlicerError> {
+        for prior in slices.iter().filter_map(|s| s.policy_semantics_version.as_ref()) {
+            self.check_policy_version(prior);
+        }
+
+        let ids: Vec<TurnId> = slices
+            .iter()
+            .flat_map(|s| &s.turns)
+            .filter(|t| t.content_hash.is_some())
+            .map(|t| t.id)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let current: HashMap<TurnId, Option<String>> = self.store.get_turns(&ids).await
+            .map_err(|e| SlicerError::StoreError(e.to_string()))?
+            .into_iter()
+            .map(|t| (t.id, t.content_hash))
+            .collect();
+
+        let mut reports: Vec<DriftReport> = slices
+            .iter()
+            .map(|slice| {
+                let mut drifted_turns: Vec<TurnDrift> = slice
+                    .turns
+                    .iter()
+                    .filter_map(|turn| {
+                        let expected = turn.content_hash.as_ref()?;
+                        let current_hash = current.get(&turn.id).cloned().flatten();
+                        (current_hash.as_ref() != Some(expected)).then(|| TurnDrift {
+                            turn_id: turn.id,
+                            expected_hash: expected.clone(),
+                            current_hash,
+                        })
+                    })
+                    .collect();
+                drifted_turns.sort_by_key(|d| d.turn_id);
+
+                DriftReport {
+                    slice_id: slice.slice_id.clone(),
+                    drifted_turns,
+                }
+            })
+            .collect();
+
+        reports.sort_by(|a, b| a.slice_id.as_str().cmp(b.slice_id.as_str()));
+        Ok(reports)
+    }
+
+    /// Measure how stable `anchor`'s slice is across graph snapshots.
+    ///
+    /// Slices the anchor in each store with this slicer's policy and secret
+    /// (probe slices are not reported to the audit sink), then compares the
+    /// turn sets of consecutive snapshots. A score of 1.0 means the slice
+    /// selected the same turns every time. Fails if any snapshot cannot be
+    /// sliced, e.g. because the anchor is missing from it.
+    pub async fn stability(&self, anchor: TurnId, stores: &[Arc<S>]) -> Result<StabilityReport, SlicerError> {
+        let mut slice_ids = Vec::with_capacity(stores.len());
+        let mut turn_sets: Vec<BTreeSet<TurnId>> = Vec::with_capacity(stores.len());
+        for store in stores {
+            let probe = self.probe(Arc::clone(store), self.policy.clone());
+            let bundle = probe.slice(anchor).await?;
+            slice_ids.push(bundle.slice_id().clone());
+            turn_sets.push(bundle.turn_ids().into_iter().collect());
+        }
+
+        let pairwise_jaccard: Vec<f32> = turn_sets
+            .windows(2)
+            .map(|pair| {
+                let union = pair[0].union(&pair[1]).count();
+                if union == 0 {
+                    1.0
+                } else {
+                    pair[0].intersection(&pair[1]).count() as f32 / union as f32
+                }
+            })
+            .collect();
+
+        let stability_score = if pairwise_jaccard.is_empty() {
+            1.0
+        } else {
+            pairwise_jaccard.iter().sum::<f32>() / pairwise_jaccard.len() as f32
+        };
+
+        Ok(StabilityReport {
+            anchor_turn_id: anchor,
+            slice_ids,
+            pairwise_jaccard,
+            stability_score,
+        })
+    }
+
+    /// A slicer with this one's secret, hash override, pins and edge
+    /// handling but no audit sink, so its slices stay comparable with this slicer's own.
+    fn probe(&self, store: Arc<S>, policy: SlicePolicyV1) -> ContextSlicer<S> {
+        let mut probe = ContextSlicer::new_unchecked(store, policy, self.hmac_secret.clone());
+        probe.secret_resolver = self.secret_resolver.clone();
+        probe.params_hash_override = self.params_hash_override.clone();
+        probe.pinned_turns = self.pinned_turns.clone();
+        probe.edges_from_traversal = self.edges_from_traversal;
+        probe.canonical_edges = self.canonical_edges;
+        probe
+    }
+
+    /// Check the current policy semantics against those of a prior slice.
+    ///
+    /// Returns a `PolicyVersionMismatch` incident when `prior` differs from
+    /// this build's semantics version; severity is raised when the major
+    /// versions differ. The incident is also logged as a warning and
+    /// recorded with the audit sink. Slices carry the version they were
+    /// issued under in `SliceExport::policy_semantics_version`.
+    pub fn check_policy_version(&self, prior: &PolicyVersion) -> Option<Incident> {
+        let current = self.policy.semantics_version();
+        if current == *prior {
+            return None;
+        }
+
+        let compatible = self.policy.is_compatible_with(prior);
+        tracing::warn!(
+            policy_id = %self.policy.policy_id(),
+            prior_version = %prior,
+            current_version = %current,
+            compatible,
+            "Slicing under different policy semantics than prior slice"
+        );
+
+        let incident = Incident::new(
+            IncidentType::PolicyVersionMismatch {
+                policy_id: self.policy.policy_id().to_string(),
+                prior_version: prior.to_string(),
+                current_version: current.to_string(),
+                compatible,
+            },
+            "context_slicer",
+        );
+        self.audit_sink.record_incident(&incident);
+        Some(incident)
+    }
+
     /// Get the policy.
     pub fn policy(&self) -> &SlicePolicyV1 {
         &self.policy
     }
 
+    /// The `policy_params_hash` stamped into issued slices.
+    ///
+    /// The override from `with_params_hash_override`, verbatim, if set.
+    /// Otherwise `policy.params_hash()`, combined with the sorted pinned IDs
+    /// when turns are pinned and with the slicer options when
+    /// `edges_from_traversal` or `canonical_edges` is enabled.
+    pub fn params_hash(&self) -> String {
+        if let Some(hash) = &self.params_hash_override {
+            return hash.clone();
+        }
+        let mut hash = self.policy.params_hash();
+        if !self.pinned_turns.is_empty() {
+            hash = canonical_hash_hex(&(hash, &self.pinned_turns));
+        }
+        if self.edges_from_traversal || self.canonical_edges {
+            hash = canonical_hash_hex(&(
+                hash,
+                "slicer_options",
+                self.edges_from_traversal,
+                self.canonical_edges,
+            ));
+        }
+        hash
+    }
+
     /// Get a reference to the store.
     pub fn store(&self) -> &S {
         &self.store
@@ -250,7 +1206,8 @@ impl<S: GraphStore + Send + Sync + 'static> ContextSlicer<S> {
 mod tests {
     use super::*;
     use crate::store::InMemoryGraphStore;
-    use crate::types::{Edge, Role, Phase, EdgeType};
+    use crate::policy::ExpansionDirection;
+    use crate::types::{Edge, Role, Phase, EdgeType, Severity};
     use uuid::Uuid;
 
     fn make_turn(id: u128, salience: f32, phase: Phase, depth: u32) -> TurnSnapshot {
@@ -300,6 +1257,89 @@ mod tests {
         assert!(bundle.is_turn_admissible(&anchor_id));
     }
 
+    #[tokio::test]
+    async fn test_unknown_phase_weight() {
+        // Anchor 1 with children 2 (Synthesis) and 3 (unparseable phase)
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(make_turn(1, 0.5, Phase::Planning, 0));
+        store.add_turn(make_turn(2, 0.5, Phase::Synthesis, 1));
+        store.add_turn(make_turn(3, 0.5, Phase::Synthesis, 1).with_parsed_phase(Some("brainstorming")));
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        store.add_edge(Edge::new(id(1), id(2), EdgeType::Reply));
+        store.add_edge(Edge::new(id(1), id(3), EdgeType::Reply));
+        let store = Arc::new(store);
+
+        let policy = SlicePolicyV1 {
+            max_nodes: 2,
+            ..SlicePolicyV1::minimal()
+        };
+        let unknown = store.get_turn(&id(3)).await.unwrap().unwrap();
+        assert!(unknown.phase_unknown);
+        assert_eq!(unknown.phase, Phase::Exploration);
+
+        // Without a fallback the unknown turn weighs as Exploration and loses
+        let bundle = ContextSlicer::new_for_test(store.clone(), policy.clone())
+            .slice(id(1))
+            .await
+            .unwrap();
+        assert_eq!(bundle.slice().turns.iter().map(|t| t.id).collect::<Vec<_>>(), vec![id(1), id(2)]);
+        assert_eq!(bundle.unknown_phase_count(), 0);
+
+        let policy = SlicePolicyV1 {
+            unknown_phase_weight: Some(2.0),
+            ..policy
+        };
+        assert_eq!(
+            crate::policy::priority_score(&unknown, 1, &policy),
+            (2.0 + 0.5 * policy.salience_weight) * policy.distance_decay
+        );
+        let bundle = ContextSlicer::new_for_test(store, policy).slice(id(1)).await.unwrap();
+        assert_eq!(bundle.slice().turns.iter().map(|t| t.id).collect::<Vec<_>>(), vec![id(1), id(3)]);
+        assert_eq!(bundle.unknown_phase_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_turn_provenance() {
+        // Anchor 2 with parent 1 and children 3 and 4
+        let mut store = InMemoryGraphStore::new();
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        for n in 1..=4 {
+            store.add_turn(make_turn(n, 0.5, Phase::Synthesis, n as u32));
+        }
+        store.add_edge(Edge::new(id(1), id(2), EdgeType::Reply));
+        store.add_edge(Edge::new(id(2), id(3), EdgeType::Reply));
+        store.add_edge(Edge::new(id(2), id(4), EdgeType::Reply));
+        let store = Arc::new(store);
+        let secret = b"production_secret_key_32bytes!!".to_vec();
+
+        let plain = ContextSlicer::new(store.clone(), SlicePolicyV1::minimal(), secret.clone())
+            .slice(id(2))
+            .await
+            .unwrap();
+        assert!(plain.slice().unverified_turn_provenance.is_none());
+
+        let annotated = ContextSlicer::new(store, SlicePolicyV1::minimal(), secret.clone())
+            .with_turn_provenance(true)
+            .slice(id(2))
+            .await
+            .unwrap();
+        let reasons = annotated.slice().unverified_turn_provenance.as_ref().unwrap();
+        assert_eq!(reasons.len(), annotated.num_turns());
+        assert_eq!(reasons[&id(1)], AdmissionReason::ParentHop);
+        assert_eq!(reasons[&id(2)], AdmissionReason::Anchor);
+        assert_eq!(reasons[&id(3)], AdmissionReason::ChildHop);
+        assert_eq!(reasons[&id(4)], AdmissionReason::ChildHop);
+
+        // The annotation is outside the fingerprint and token
+        assert_eq!(annotated.slice_id(), plain.slice_id());
+        assert!(annotated.slice().verify_token(&secret));
+
+        // ...so editing it does not invalidate the token
+        let mut edited = annotated.slice().clone();
+        edited.unverified_turn_provenance.as_mut().unwrap().insert(id(3), AdmissionReason::Anchor);
+        assert!(edited.verify_token(&secret));
+    }
+
     #[tokio::test]
     async fn test_slice_respects_max_nodes() {
         let store = build_linear_graph(100);
@@ -369,12 +1409,73 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_bundle_is_verified_proof() {
-        // This test demonstrates that receiving an AdmissibleEvidenceBundle
-        // is cryptographic proof of kernel authorization
+    async fn test_per_policy_secrets() {
+        use crate::types::{FailureKind, TokenVerifier, VerificationMode};
+        use std::collections::HashMap;
+
         let store = build_linear_graph(5);
-        let policy = SlicePolicyV1::minimal();
-        let secret = b"production_secret_key_32bytes!!".to_vec();
+        let tenant = |name: &str| SlicePolicyV1 {
+            version: name.to_string(),
+            ..SlicePolicyV1::minimal()
+        };
+        let secret_a = b"tenant_a_secret_32_bytes_minimum".to_vec();
+        let secret_b = b"tenant_b_secret_32_bytes_minimum".to_vec();
+        let resolver: Arc<dyn SecretResolver> = Arc::new(HashMap::from([
+            ("tenant_a".to_string(), secret_a.clone()),
+            ("tenant_b".to_string(), secret_b.clone()),
+        ]));
+
+        let anchor_id = TurnId::new(Uuid::from_u128(3));
+        let slice = ContextSlicer::new_with_resolver(store.clone(), tenant("tenant_a"), resolver.clone())
+            .slice(anchor_id)
+            .await
+            .unwrap()
+            .slice()
+            .clone();
+        assert!(slice.verify_token(&secret_a));
+        assert!(!slice.verify_token(&secret_b));
+
+        let verifier = TokenVerifier::new(VerificationMode::per_policy(resolver.clone()));
+        assert!(verifier.verify_slice(&slice).is_valid);
+
+        // Relabelled as tenant B, the token is checked against B's secret
+        let mut relabelled = slice.clone();
+        relabelled.policy_id = "tenant_b".to_string();
+        let result = verifier.verify_slice(&relabelled);
+        assert!(!result.is_valid);
+        assert_eq!(result.failure_kind, Some(FailureKind::HmacMismatch));
+
+        // Policies without a key can neither sign nor verify
+        relabelled.policy_id = "tenant_c".to_string();
+        assert_eq!(verifier.verify_slice(&relabelled).failure_kind, Some(FailureKind::UnknownPolicy));
+        let err = ContextSlicer::new_with_resolver(store, tenant("tenant_c"), resolver)
+            .slice(anchor_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SlicerError::NoSecretForPolicy(p) if p == "tenant_c"));
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_short_secret() {
+        let store = build_linear_graph(2);
+        let policy = SlicePolicyV1::minimal();
+
+        let result = ContextSlicer::new_strict(store.clone(), policy.clone(), b"k".to_vec(), MIN_HMAC_SECRET_LEN);
+        assert!(matches!(result, Err(SlicerError::WeakSecret { len: 1, min: 32 })));
+        assert!(ContextSlicer::new_strict(store.clone(), policy.clone(), Vec::new(), MIN_HMAC_SECRET_LEN).is_err());
+
+        let secret = vec![7u8; MIN_HMAC_SECRET_LEN];
+        assert!(ContextSlicer::new_strict(store, policy, secret, MIN_HMAC_SECRET_LEN).is_ok());
+        assert!(validate_hmac_secret(b"short", 4).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_bundle_is_verified_proof() {
+        // This test demonstrates that receiving an AdmissibleEvidenceBundle
+        // is cryptographic proof of kernel authorization
+        let store = build_linear_graph(5);
+        let policy = SlicePolicyV1::minimal();
+        let secret = b"production_secret_key_32bytes!!".to_vec();
         let slicer = ContextSlicer::new(store, policy, secret);
 
         let anchor_id = TurnId::new(Uuid::from_u128(3));
@@ -391,5 +1492,844 @@ mod tests {
         assert!(!graph_hash.as_str().is_empty());
         assert!(!policy_id.is_empty());
     }
-}
 
+    #[tokio::test]
+    async fn test_sibling_distance_offset_demotes_siblings() {
+        // Parent 1 -> anchor 2, parent 1 -> sibling 3, anchor 2 -> child 4
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(make_turn(1, 0.0, Phase::Exploration, 0));
+        store.add_turn(make_turn(2, 0.5, Phase::Consolidation, 1));
+        store.add_turn(make_turn(3, 0.4, Phase::Consolidation, 1));
+        store.add_turn(make_turn(4, 0.5, Phase::Consolidation, 2));
+
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        store.add_edge(Edge::new(id(1), id(2), EdgeType::Reply));
+        store.add_edge(Edge::new(id(1), id(3), EdgeType::Branch));
+        store.add_edge(Edge::new(id(2), id(4), EdgeType::Reply));
+        let store = Arc::new(store);
+
+        // Budget of 2: anchor plus whichever candidate ranks highest
+        let same_distance = SlicePolicyV1 {
+            max_nodes: 2,
+            ..SlicePolicyV1::default()
+        };
+        let slicer = ContextSlicer::new_for_test(Arc::clone(&store), same_distance);
+        let bundle = slicer.slice(id(2)).await.unwrap();
+        assert!(bundle.slice().contains_turn(&id(3)));
+        assert!(!bundle.slice().contains_turn(&id(4)));
+
+        let offset = SlicePolicyV1 {
+            max_nodes: 2,
+            sibling_distance_offset: 1,
+            ..SlicePolicyV1::default()
+        };
+        let slicer = ContextSlicer::new_for_test(store, offset);
+        let bundle = slicer.slice(id(2)).await.unwrap();
+        assert!(bundle.slice().contains_turn(&id(4)));
+        assert!(!bundle.slice().contains_turn(&id(3)));
+    }
+
+    #[tokio::test]
+    async fn test_expansion_direction_on_linear_graph() {
+        let store = build_linear_graph(10);
+        let anchor_id = TurnId::new(Uuid::from_u128(5));
+
+        let ancestors = SlicePolicyV1 {
+            expansion_direction: ExpansionDirection::Ancestors,
+            ..SlicePolicyV1::minimal()
+        };
+        let slicer = ContextSlicer::new_for_test(Arc::clone(&store), ancestors);
+        let bundle = slicer.slice(anchor_id).await.unwrap();
+        let ids = bundle.turn_ids();
+        assert!(ids.contains(&anchor_id));
+        assert!(ids.len() > 1);
+        assert!(ids.iter().all(|id| *id <= anchor_id));
+
+        let descendants = SlicePolicyV1 {
+            expansion_direction: ExpansionDirection::Descendants,
+            ..SlicePolicyV1::minimal()
+        };
+        let slicer = ContextSlicer::new_for_test(store, descendants);
+        let bundle = slicer.slice(anchor_id).await.unwrap();
+        let ids = bundle.turn_ids();
+        assert!(ids.len() > 1);
+        assert!(ids.iter().all(|id| *id >= anchor_id));
+    }
+
+    #[tokio::test]
+    async fn test_allowed_edge_types_excludes_branches() {
+        // 1 -> 2 -> 3 is the main thread; 2 -> 4 is a branch, 1 -> 5 a reference
+        let mut store = InMemoryGraphStore::new();
+        for n in 1..=5 {
+            store.add_turn(make_turn(n, 0.5, Phase::Consolidation, 0));
+        }
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        store.add_edge(Edge::new(id(1), id(2), EdgeType::Reply));
+        store.add_edge(Edge::new(id(2), id(3), EdgeType::Reply));
+        store.add_edge(Edge::new(id(2), id(4), EdgeType::Branch));
+        store.add_edge(Edge::new(id(1), id(5), EdgeType::Reference));
+        let store = Arc::new(store);
+
+        let all = ContextSlicer::new_for_test(Arc::clone(&store), SlicePolicyV1::default());
+        let bundle = all.slice(id(2)).await.unwrap();
+        assert_eq!(bundle.num_turns(), 5);
+
+        let reply_only = SlicePolicyV1 {
+            allowed_edge_types: Some(HashSet::from([EdgeType::Reply])),
+            ..SlicePolicyV1::default()
+        };
+        let slicer = ContextSlicer::new_for_test(store, reply_only);
+        let bundle = slicer.slice(id(2)).await.unwrap();
+
+        assert_eq!(bundle.turn_ids(), vec![id(1), id(2), id(3)]);
+        assert!(bundle.slice().edges.iter().all(|e| e.edge_type == EdgeType::Reply));
+    }
+
+    #[tokio::test]
+    async fn test_ancestor_budget_fraction_reserves_ancestors() {
+        // Low-priority chain 1 -> ... -> 10 (anchor), anchor has 20 high-priority children
+        let mut store = InMemoryGraphStore::new();
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        for n in 1..=10 {
+            store.add_turn(make_turn(n, 0.0, Phase::Exploration, n as u32));
+            if n > 1 {
+                store.add_edge(Edge::new(id(n - 1), id(n), EdgeType::Reply));
+            }
+        }
+        for n in 100..120 {
+            store.add_turn(make_turn(n, 1.0, Phase::Synthesis, 11));
+            store.add_edge(Edge::new(id(10), id(n), EdgeType::Reply));
+        }
+        let store = Arc::new(store);
+        let ancestor_count = |ids: &[TurnId]| ids.iter().filter(|t| **t < id(10)).count();
+
+        let unreserved = SlicePolicyV1 {
+            max_nodes: 6,
+            max_radius: 20,
+            ..SlicePolicyV1::minimal()
+        };
+        let slicer = ContextSlicer::new_for_test(Arc::clone(&store), unreserved.clone());
+        let bundle = slicer.slice(id(10)).await.unwrap();
+        assert_eq!(ancestor_count(&bundle.turn_ids()), 0);
+
+        let reserved = SlicePolicyV1 {
+            ancestor_budget_fraction: Some(0.5),
+            ..unreserved.clone()
+        };
+        assert_ne!(reserved.params_hash(), unreserved.params_hash());
+        let slicer = ContextSlicer::new_for_test(Arc::clone(&store), reserved);
+        let bundle = slicer.slice(id(10)).await.unwrap();
+        assert_eq!(bundle.num_turns(), 6);
+        assert_eq!(ancestor_count(&bundle.turn_ids()), 3);
+
+        // With no ancestors the reserve is released back to other turns
+        let slicer = ContextSlicer::new_for_test(
+            store,
+            SlicePolicyV1 { ancestor_budget_fraction: Some(0.5), ..unreserved },
+        );
+        let bundle = slicer.slice(id(1)).await.unwrap();
+        assert_eq!(bundle.num_turns(), 6);
+    }
+
+    #[test]
+    fn test_check_policy_version() {
+        let slicer = ContextSlicer::new_for_test(build_linear_graph(1), SlicePolicyV1::minimal());
+        let current = slicer.policy().semantics_version();
+
+        assert!(slicer.check_policy_version(&current).is_none());
+
+        let minor = PolicyVersion::new(current.major, current.minor + 1, 0);
+        let incident = slicer.check_policy_version(&minor).unwrap();
+        assert_eq!(incident.severity, Severity::Low);
+
+        let major = PolicyVersion::new(current.major + 1, 0, 0);
+        let incident = slicer.check_policy_version(&major).unwrap();
+        assert_eq!(incident.severity, Severity::High);
+        assert!(matches!(
+            incident.incident_type,
+            IncidentType::PolicyVersionMismatch { compatible: false, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scan_drift_reports_policy_version_to_audit_sink() {
+        let sink = Arc::new(crate::types::InMemoryAuditSink::new());
+        let slicer = ContextSlicer::new_for_test(build_linear_graph(3), SlicePolicyV1::minimal())
+            .with_audit_sink(sink.clone());
+        let current = slicer.policy().semantics_version();
+
+        let mut slice = slicer.slice(TurnId::new(Uuid::from_u128(2))).await.unwrap().slice().clone();
+        assert_eq!(slice.policy_semantics_version, Some(current));
+        slicer.scan_drift(std::slice::from_ref(&slice)).await.unwrap();
+        assert!(sink.incidents().is_empty());
+
+        slice.policy_semantics_version = Some(PolicyVersion::new(current.major + 1, 0, 0));
+        slicer.scan_drift(&[slice]).await.unwrap();
+        let incidents = sink.incidents();
+        assert_eq!(incidents.len(), 1);
+        assert!(matches!(
+            incidents[0].incident_type,
+            IncidentType::PolicyVersionMismatch { compatible: false, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_audit_sink_records_each_issued_slice() {
+        use crate::types::InMemoryAuditSink;
+
+        let store = build_linear_graph(5);
+        let anchor_id = TurnId::new(Uuid::from_u128(3));
+        let sink = Arc::new(InMemoryAuditSink::new());
+
+        let audited = ContextSlicer::new_for_test(store.clone(), SlicePolicyV1::minimal())
+            .with_audit_sink(sink.clone());
+        let bundle = audited.slice(anchor_id).await.unwrap();
+
+        assert_eq!(sink.entries(), vec![bundle.slice_id().to_string()]);
+
+        // Auditing does not change the slice
+        let plain = ContextSlicer::new_for_test(store, SlicePolicyV1::minimal());
+        assert_eq!(plain.slice(anchor_id).await.unwrap().slice_id(), bundle.slice_id());
+
+        // Failed slices are not recorded
+        assert!(audited.slice(TurnId::new(Uuid::from_u128(99))).await.is_err());
+        assert_eq!(sink.entries().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_params_hash_override_is_used_verbatim() {
+        let store = build_linear_graph(5);
+        let anchor_id = TurnId::new(Uuid::from_u128(3));
+
+        let internal = ContextSlicer::new_for_test(store.clone(), SlicePolicyV1::minimal())
+            .slice(anchor_id).await.unwrap();
+        let external = ContextSlicer::new_for_test(store, SlicePolicyV1::minimal())
+            .with_params_hash_override("shared-scheme:0f1e2d3c".to_string())
+            .slice(anchor_id).await.unwrap();
+
+        let (_, _, _, params_hash, _) = external.provenance();
+        assert_eq!(params_hash, "shared-scheme:0f1e2d3c");
+        assert_eq!(external.turn_ids(), internal.turn_ids());
+        assert_ne!(external.slice_id(), internal.slice_id());
+
+        // Pins are not folded into an override
+        let pinned = TurnId::new(Uuid::from_u128(1));
+        let slicer = ContextSlicer::new_for_test(build_linear_graph(20), SlicePolicyV1::minimal())
+            .with_params_hash_override("shared-scheme:0f1e2d3c".to_string())
+            .with_pinned_turns(vec![pinned]);
+        assert_eq!(slicer.params_hash(), "shared-scheme:0f1e2d3c");
+        let bundle = slicer.slice(TurnId::new(Uuid::from_u128(15))).await.unwrap();
+        assert_eq!(bundle.policy_params_hash(), "shared-scheme:0f1e2d3c");
+        assert!(bundle.is_turn_admissible(&pinned));
+    }
+
+    /// Store wrapper that counts every query.
+    struct CountingStore {
+        inner: InMemoryGraphStore,
+        calls: std::sync::atomic::AtomicUsize,
+        fetched: std::sync::Mutex<Vec<TurnId>>,
+    }
+
+    impl CountingStore {
+        fn count(&self) {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        fn take(&self) -> usize {
+            self.calls.swap(0, std::sync::atomic::Ordering::SeqCst)
+        }
+
+        /// IDs passed to `get_turn` since the last call, in order.
+        fn take_fetched(&self) -> Vec<TurnId> {
+            std::mem::take(&mut *self.fetched.lock().unwrap())
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl GraphStore for CountingStore {
+        type Error = crate::store::memory::InMemoryError;
+
+        async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error> {
+            self.count();
+            self.fetched.lock().unwrap().push(*id);
+            self.inner.get_turn(id).await
+        }
+
+        async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error> {
+            self.count();
+            self.inner.get_turns(ids).await
+        }
+
+        async fn get_parents(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+            self.count();
+            self.inner.get_parents(id).await
+        }
+
+        async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+            self.count();
+            self.inner.get_children(id).await
+        }
+
+        async fn get_parents_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+            self.count();
+            self.inner.get_parents_ordered(id, order).await
+        }
+
+        async fn get_children_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+            self.count();
+            self.inner.get_children_ordered(id, order).await
+        }
+
+        async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+            self.count();
+            self.inner.get_siblings(id, limit).await
+        }
+
+        async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error> {
+            self.count();
+            self.inner.get_edges(turn_ids).await
+        }
+
+        async fn get_incident_edges(&self, id: &TurnId) -> Result<Vec<Edge>, Self::Error> {
+            self.count();
+            self.inner.get_incident_edges(id).await
+        }
+
+        async fn turns_by_phase(&self, phase: Phase, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+            self.count();
+            self.inner.turns_by_phase(phase, limit).await
+        }
+
+        async fn recent_turns(&self, session_id: &str, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+            self.count();
+            self.inner.recent_turns(session_id, limit).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_max_store_calls_is_respected() {
+        // Root with 50 children: one hop fans out to 50 turn fetches
+        let mut inner = InMemoryGraphStore::new();
+        inner.add_turn(make_turn(1, 0.5, Phase::Planning, 0));
+        for n in 2..=51u128 {
+            inner.add_turn(make_turn(n, 0.5, Phase::Planning, 1));
+            inner.add_edge(Edge::reply(TurnId::new(Uuid::from_u128(1)), TurnId::new(Uuid::from_u128(n))));
+        }
+        let store = Arc::new(CountingStore { inner, calls: Default::default(), fetched: Default::default() });
+        let anchor_id = TurnId::new(Uuid::from_u128(1));
+        let limited = SlicePolicyV1::builder().max_nodes(100).max_store_calls(10).build();
+
+        let unlimited = ContextSlicer::new_for_test(store.clone(), SlicePolicyV1::builder().max_nodes(100).build());
+        let full = unlimited.slice(anchor_id).await.unwrap();
+        assert!(store.take() > 10);
+        assert_eq!(full.num_turns(), 51);
+
+        // Failing mode stops at the ceiling
+        let slicer = ContextSlicer::new_for_test(store.clone(), limited.clone());
+        assert!(matches!(slicer.slice(anchor_id).await, Err(SlicerError::BudgetExhausted(10))));
+        assert!(store.take() <= 10);
+
+        // Truncating mode returns the same partial slice every time
+        let truncating = SlicePolicyV1 { truncate_on_store_budget: true, ..limited.clone() };
+        assert_ne!(truncating.params_hash(), limited.params_hash());
+        let slicer = ContextSlicer::new_for_test(store.clone(), truncating);
+        let first = slicer.slice(anchor_id).await.unwrap();
+        assert!(store.take() <= 10);
+        let second = slicer.slice(anchor_id).await.unwrap();
+        assert_eq!(first.slice_id(), second.slice_id());
+        assert!(first.num_turns() < full.num_turns());
+        assert!(first.is_turn_admissible(&anchor_id));
+
+        // Salience lookups for neighbor ordering are charged too
+        store.take();
+        let by_salience = SlicePolicyV1 {
+            neighbor_order: NeighborOrder::BySalienceDesc,
+            ..limited
+        };
+        for from_traversal in [false, true] {
+            let slicer = ContextSlicer::new_for_test(store.clone(), by_salience.clone())
+                .with_edges_from_traversal(from_traversal);
+            assert!(matches!(slicer.slice(anchor_id).await, Err(SlicerError::BudgetExhausted(10))));
+            assert!(store.take() <= 10);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pinned_turn_beyond_max_radius() {
+        let store = build_linear_graph(20);
+        let policy = SlicePolicyV1::minimal();
+        let anchor = TurnId::new(Uuid::from_u128(15));
+        let pinned = TurnId::new(Uuid::from_u128(1));
+
+        let plain = ContextSlicer::new_for_test(Arc::clone(&store), policy.clone());
+        let plain_bundle = plain.slice(anchor).await.unwrap();
+        assert!(!plain_bundle.is_turn_admissible(&pinned));
+
+        let slicer = ContextSlicer::new_for_test(Arc::clone(&store), policy)
+            .with_pinned_turns(vec![pinned, pinned]);
+        let bundle = slicer.slice(anchor).await.unwrap();
+        assert!(bundle.is_turn_admissible(&pinned));
+        assert_eq!(bundle.num_turns(), plain_bundle.num_turns() + 1);
+
+        // Pinning is visible in provenance
+        assert_ne!(bundle.policy_params_hash(), plain_bundle.policy_params_hash());
+        assert_eq!(bundle.policy_params_hash(), slicer.params_hash());
+        assert_ne!(bundle.slice_id(), plain_bundle.slice_id());
+
+        // An override is emitted unchanged, with or without pins
+        let with_override = |pins: Vec<TurnId>| {
+            ContextSlicer::new_for_test(Arc::clone(&store), SlicePolicyV1::minimal())
+                .with_params_hash_override("external".to_string())
+                .with_pinned_turns(pins)
+        };
+        let overridden = with_override(vec![pinned]).slice(anchor).await.unwrap();
+        let unpinned = with_override(vec![]).slice(anchor).await.unwrap();
+        assert_eq!(overridden.policy_params_hash(), "external");
+        assert_eq!(unpinned.policy_params_hash(), "external");
+        assert_eq!(overridden.turn_ids(), bundle.turn_ids());
+        assert_ne!(overridden.slice_id(), unpinned.slice_id());
+
+        let missing = TurnId::new(Uuid::from_u128(999));
+        let result = ContextSlicer::new_for_test(store, SlicePolicyV1::minimal())
+            .with_pinned_turns(vec![missing])
+            .slice(anchor)
+            .await;
+        assert!(matches!(result, Err(SlicerError::PinnedTurnNotFound(id)) if id == missing));
+    }
+
+    #[tokio::test]
+    async fn test_neighbor_order_keeps_selection() {
+        let store = build_linear_graph(10);
+        let anchor = TurnId::new(Uuid::from_u128(5));
+        let by_id = ContextSlicer::new_for_test(Arc::clone(&store), SlicePolicyV1::minimal());
+        let mut policy = SlicePolicyV1::minimal();
+        policy.neighbor_order = crate::store::NeighborOrder::BySalienceDesc;
+        let by_salience = ContextSlicer::new_for_test(store, policy);
+
+        let a = by_id.slice(anchor).await.unwrap();
+        let b = by_salience.slice(anchor).await.unwrap();
+
+        // Candidates are ranked by priority either way; the order is recorded
+        assert_eq!(a.turn_ids(), b.turn_ids());
+        assert_ne!(a.policy_params_hash(), b.policy_params_hash());
+        assert_eq!(b.slice_id(), by_salience.slice(anchor).await.unwrap().slice_id());
+    }
+
+    #[tokio::test]
+    async fn test_neighbor_order_reaches_store_queries() {
+        // Anchor 1 with children 2..=5; higher IDs are more salient
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(make_turn(1, 0.5, Phase::Planning, 0));
+        for n in 2..=5u128 {
+            store.add_turn(make_turn(n, n as f32 / 10.0, Phase::Planning, 1));
+            store.add_edge(Edge::reply(id(1), id(n)));
+        }
+        let store = Arc::new(CountingStore { inner: store, calls: Default::default(), fetched: Default::default() });
+
+        let mut lookups = Vec::new();
+        let mut selections = Vec::new();
+        for order in [NeighborOrder::ById, NeighborOrder::BySalienceDesc] {
+            let mut policy = SlicePolicyV1::minimal();
+            policy.neighbor_order = order;
+            let bundle = ContextSlicer::new_for_test(store.clone(), policy).slice(id(1)).await.unwrap();
+            lookups.push((store.take(), store.take_fetched()));
+            selections.push(bundle.turn_ids());
+        }
+
+        // Children are looked up in the requested order, at no extra calls
+        assert_eq!(lookups[0].1[1..5], [id(2), id(3), id(4), id(5)]);
+        assert_eq!(lookups[1].1[1..5], [id(5), id(4), id(3), id(2)]);
+        assert_eq!(lookups[0].0, lookups[1].0);
+        assert_eq!(selections[0], selections[1]);
+    }
+
+    #[tokio::test]
+    async fn test_max_depth_delta_bands_slice() {
+        // Turn i sits at trajectory depth i
+        let store = build_linear_graph(40);
+        let mut policy = SlicePolicyV1::minimal();
+        policy.max_nodes = 40;
+        policy.max_radius = 40;
+        policy.max_depth_delta = Some(3);
+        let slicer = ContextSlicer::new_for_test(store, policy);
+
+        let bundle = slicer.slice(TurnId::new(Uuid::from_u128(20))).await.unwrap();
+        let expected: Vec<TurnId> = (17..=23).map(|i| TurnId::new(Uuid::from_u128(i))).collect();
+        assert_eq!(bundle.turn_ids(), expected);
+        assert!(bundle.slice().turns.iter().all(|t| t.trajectory_depth.abs_diff(20) <= 3));
+    }
+
+    #[tokio::test]
+    async fn test_excluded_roles_skip_system_turns() {
+        // 1 -> 2 (system) -> 3, 1 -> 4
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let mut store = InMemoryGraphStore::new();
+        for i in 1..=4u128 {
+            let mut turn = make_turn(i, 0.5, Phase::Consolidation, 1);
+            if i == 2 {
+                turn.role = Role::System;
+            }
+            store.add_turn(turn);
+        }
+        store.add_edge(Edge::reply(id(1), id(2)));
+        store.add_edge(Edge::reply(id(2), id(3)));
+        store.add_edge(Edge::reply(id(1), id(4)));
+        let store = Arc::new(store);
+
+        let policy = SlicePolicyV1::builder()
+            .max_nodes(10)
+            .max_radius(3)
+            .include_siblings(false)
+            .excluded_roles([Role::System])
+            .build();
+        let slicer = ContextSlicer::new_for_test(store, policy);
+
+        // The system turn is skipped, and so is everything only reachable through it
+        let bundle = slicer.slice(id(1)).await.unwrap();
+        assert_eq!(bundle.turn_ids(), vec![id(1), id(4)]);
+        assert!(bundle.slice().turns.iter().all(|t| t.role == Role::User));
+
+        // An excluded anchor is still admitted
+        let bundle = slicer.slice(id(2)).await.unwrap();
+        assert_eq!(bundle.turn_ids(), vec![id(1), id(2), id(3), id(4)]);
+    }
+
+    #[tokio::test]
+    async fn test_canonical_edges_ignore_related_orientation() {
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let graph = |reference: Edge| {
+            let mut store = InMemoryGraphStore::new();
+            for i in 1..=3u128 {
+                store.add_turn(make_turn(i, 0.5, Phase::Consolidation, 1));
+            }
+            store.add_edge(Edge::reply(id(1), id(2)));
+            store.add_edge(reference);
+            Arc::new(store)
+        };
+        let forward = graph(Edge::new(id(2), id(3), EdgeType::Related));
+        let backward = graph(Edge::new(id(3), id(2), EdgeType::Related));
+        let policy = SlicePolicyV1::minimal();
+
+        let slice = |store: Arc<InMemoryGraphStore>, canonical: bool| {
+            let slicer = ContextSlicer::new_for_test(store, policy.clone()).with_canonical_edges(canonical);
+            async move { slicer.slice(id(1)).await.unwrap() }
+        };
+
+        // Recorded orientation changes slice_id unless edges are canonical
+        let plain = slice(forward.clone(), false).await;
+        assert_ne!(plain.slice_id(), slice(backward.clone(), false).await.slice_id());
+        let a = slice(forward, true).await;
+        let b = slice(backward, true).await;
+        assert_eq!(a.slice_id(), b.slice_id());
+        assert_eq!(a.slice().edges, vec![Edge::reply(id(1), id(2)), Edge::new(id(2), id(3), EdgeType::Related)]);
+        assert_ne!(a.slice().policy_params_hash, plain.slice().policy_params_hash);
+    }
+
+    #[tokio::test]
+    async fn test_edges_from_traversal_match_get_edges() {
+        // Diamond 1 -> {2, 3} -> 4 plus a 2 -> 3 reference between
+        // turns that stay unexpanded at radius 1
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let mut store = InMemoryGraphStore::new();
+        for i in 1..=6u128 {
+            store.add_turn(make_turn(i, 0.1 * i as f32, Phase::Consolidation, i as u32));
+        }
+        store.add_edge(Edge::new(id(1), id(2), EdgeType::Reply));
+        store.add_edge(Edge::new(id(1), id(3), EdgeType::Branch));
+        store.add_edge(Edge::new(id(2), id(3), EdgeType::Reference));
+        store.add_edge(Edge::new(id(2), id(4), EdgeType::Reply));
+        store.add_edge(Edge::new(id(3), id(4), EdgeType::Reply));
+        store.add_edge(Edge::new(id(4), id(5), EdgeType::Branch));
+        store.add_edge(Edge::new(id(5), id(6), EdgeType::Reply));
+        let store = Arc::new(store);
+
+        for direction in [ExpansionDirection::Both, ExpansionDirection::Ancestors, ExpansionDirection::Descendants] {
+            for radius in 0..=3 {
+                for anchor in 1..=6 {
+                    let policy = SlicePolicyV1 {
+                        max_radius: radius,
+                        expansion_direction: direction,
+                        ..SlicePolicyV1::minimal()
+                    };
+                    let queried = ContextSlicer::new_for_test(store.clone(), policy.clone())
+                        .slice(id(anchor))
+                        .await
+                        .unwrap();
+                    let traversed = ContextSlicer::new_for_test(store.clone(), policy)
+                        .with_edges_from_traversal(true)
+                        .slice(id(anchor))
+                        .await
+                        .unwrap();
+
+                    assert_eq!(traversed.slice().edges, queried.slice().edges);
+                    assert_eq!(traversed.turn_ids(), queried.turn_ids());
+                    // The option is visible in provenance
+                    assert_ne!(traversed.slice().policy_params_hash, queried.slice().policy_params_hash);
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_complexity_budget_limits_costly_turns() {
+        let graph = |complexity: f32| {
+            let mut store = InMemoryGraphStore::new();
+            for i in 1..=20u128 {
+                let mut turn = make_turn(i, 0.5, Phase::Consolidation, i as u32);
+                turn.trajectory_complexity = complexity;
+                store.add_turn(turn);
+                if i > 1 {
+                    store.add_edge(Edge::reply(
+                        TurnId::new(Uuid::from_u128(i - 1)),
+                        TurnId::new(Uuid::from_u128(i)),
+                    ));
+                }
+            }
+            Arc::new(store)
+        };
+        let mut policy = SlicePolicyV1::minimal();
+        policy.max_nodes = 20;
+        policy.max_radius = 20;
+        policy.complexity_budget = Some(10.0);
+        let anchor = TurnId::new(Uuid::from_u128(10));
+
+        let simple = ContextSlicer::new_for_test(graph(1.0), policy.clone()).slice(anchor).await.unwrap();
+        let complex = ContextSlicer::new_for_test(graph(4.0), policy.clone()).slice(anchor).await.unwrap();
+
+        assert_eq!(simple.num_turns(), 10);
+        assert_eq!(complex.num_turns(), 2);
+        assert!(complex.slice().turns.iter().map(|t| t.trajectory_complexity).sum::<f32>() <= 10.0);
+
+        // Without a budget only max_nodes applies
+        policy.complexity_budget = None;
+        let unbudgeted = ContextSlicer::new_for_test(graph(4.0), policy).slice(anchor).await.unwrap();
+        assert_eq!(unbudgeted.num_turns(), 20);
+    }
+
+    #[tokio::test]
+    async fn test_include_root_path_beyond_radius() {
+        let store = build_linear_graph(50);
+        let mut policy = SlicePolicyV1::minimal();
+        policy.max_radius = 2;
+        policy.include_root_path = true;
+        let slicer = ContextSlicer::new_for_test(store, policy);
+
+        let bundle = slicer.slice(TurnId::new(Uuid::from_u128(40))).await.unwrap();
+        assert!(bundle.is_turn_admissible(&TurnId::new(Uuid::from_u128(1))));
+
+        // The whole ancestor chain plus the radius-2 neighborhood
+        let expected: Vec<TurnId> = (1..=42).map(|i| TurnId::new(Uuid::from_u128(i))).collect();
+        assert_eq!(bundle.turn_ids(), expected);
+    }
+
+    #[tokio::test]
+    async fn test_include_root_path_honours_store_budget_mode() {
+        let store = build_linear_graph(50);
+        let anchor = TurnId::new(Uuid::from_u128(40));
+        let mut policy = SlicePolicyV1::minimal();
+        policy.max_radius = 2;
+        policy.include_root_path = true;
+        policy.max_store_calls = Some(40);
+
+        let slicer = ContextSlicer::new_for_test(Arc::clone(&store), policy.clone());
+        assert!(matches!(slicer.slice(anchor).await, Err(SlicerError::BudgetExhausted(40))));
+
+        // Truncating mode keeps the part of the path walked so far
+        policy.truncate_on_store_budget = true;
+        let slicer = ContextSlicer::new_for_test(store, policy);
+        let bundle = slicer.slice(anchor).await.unwrap();
+        assert!(!bundle.is_turn_admissible(&TurnId::new(Uuid::from_u128(1))));
+        assert!(bundle.is_turn_admissible(&TurnId::new(Uuid::from_u128(30))));
+        assert!(bundle.num_turns() > 5);
+        assert_eq!(bundle.slice_id(), slicer.slice(anchor).await.unwrap().slice_id());
+    }
+
+    #[tokio::test]
+    async fn test_stability_across_snapshots() {
+        let stores = vec![build_linear_graph(5), build_linear_graph(5), build_linear_graph(6)];
+        let slicer = ContextSlicer::new_for_test(Arc::clone(&stores[0]), SlicePolicyV1::minimal());
+        let anchor = TurnId::new(Uuid::from_u128(5));
+
+        let report = slicer.stability(anchor, &stores).await.unwrap();
+        assert_eq!(report.slice_ids.len(), 3);
+        assert_eq!(report.slice_ids[0], report.slice_ids[1]);
+        assert_ne!(report.slice_ids[1], report.slice_ids[2]);
+
+        // Turn 6 joins the slice {2, 3, 4, 5} in the last snapshot
+        assert_eq!(report.pairwise_jaccard, vec![1.0, 0.8]);
+        assert!(report.stability_score < 1.0);
+        assert!((report.stability_score - 0.9).abs() < 1e-6);
+
+        assert_eq!(slicer.stability(anchor, &stores).await.unwrap(), report);
+    }
+
+    #[tokio::test]
+    async fn test_replay_harness_match_and_drift() {
+        use crate::types::{EmbeddingModelRef, NormalizationVersion, ProvenanceBuilder, RetrievalParams};
+
+        let anchor_id = TurnId::new(Uuid::from_u128(3));
+        let policy = SlicePolicyV1::minimal();
+        let original = ContextSlicer::new_for_test(build_linear_graph(5), policy.clone());
+        let bundle = original.slice(anchor_id).await.unwrap();
+
+        let provenance = |anchor: Option<TurnId>| {
+            let builder = ProvenanceBuilder::new()
+                .embedding_model(EmbeddingModelRef::new("model", "v1", 8))
+                .normalization(NormalizationVersion::current())
+                .retrieval_params(
+                    RetrievalParams::new(10, 0.5, policy.policy_id())
+                        .with_policy_params_hash(policy.params_hash()),
+                )
+                .graph_snapshot(bundle.graph_snapshot_hash().clone())
+                .slice_fingerprint(bundle.slice_id().as_str());
+            match anchor {
+                Some(id) => builder.anchor_turn_id(id),
+                None => builder,
+            }
+            .build()
+            .unwrap()
+        };
+        let recorded = provenance(Some(anchor_id));
+
+        // Unchanged graph reproduces the slice
+        assert_eq!(ReplayHarness::replay(&recorded, &original).await, ReplayOutcome::Match);
+
+        // A new turn next to the anchor changes the selection
+        let mut mutated = (*build_linear_graph(5)).clone();
+        mutated.add_turn(make_turn(9, 0.9, Phase::Synthesis, 3));
+        mutated.add_edge(Edge::new(anchor_id, TurnId::new(Uuid::from_u128(9)), EdgeType::Branch));
+        let replayed = ContextSlicer::new_for_test(Arc::new(mutated), policy.clone());
+        match ReplayHarness::replay(&recorded, &replayed).await {
+            ReplayOutcome::Drift { expected, actual } => {
+                assert_eq!(expected, bundle.slice_id().as_str());
+                assert_ne!(actual, expected);
+            }
+            other => panic!("expected drift, got {:?}", other),
+        }
+
+        // Missing anchor or different policy cannot be replayed
+        assert!(matches!(
+            ReplayHarness::replay(&provenance(None), &original).await,
+            ReplayOutcome::Incompatible { .. }
+        ));
+        let other_policy = ContextSlicer::new_for_test(build_linear_graph(5), SlicePolicyV1::default());
+        assert!(matches!(
+            ReplayHarness::replay(&recorded, &other_policy).await,
+            ReplayOutcome::Incompatible { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scan_drift_reports_shared_drifted_turn_once() {
+        let hashed = |id: u128, hash: &str| {
+            make_turn(id, 0.5, Phase::Consolidation, id as u32).with_content_hash(Some(hash.to_string()))
+        };
+        let build = |turn_2_hash: &str| {
+            let mut store = InMemoryGraphStore::new();
+            store.add_turn(hashed(1, "h1"));
+            store.add_turn(hashed(2, turn_2_hash));
+            store.add_turn(hashed(3, "h3"));
+            store.add_turn(hashed(4, "h4"));
+            for i in 1..4u128 {
+                store.add_edge(Edge::new(
+                    TurnId::new(Uuid::from_u128(i)),
+                    TurnId::new(Uuid::from_u128(i + 1)),
+                    EdgeType::Reply,
+                ));
+            }
+            Arc::new(store)
+        };
+
+        let mut policy = SlicePolicyV1::minimal();
+        policy.max_radius = 1;
+        let original = ContextSlicer::new_for_test(build("h2"), policy.clone());
+        let slices: Vec<SliceExport> = vec![
+            original.slice(TurnId::new(Uuid::from_u128(1))).await.unwrap().slice().clone(),
+            original.slice(TurnId::new(Uuid::from_u128(3))).await.unwrap().slice().clone(),
+            original.slice(TurnId::new(Uuid::from_u128(4))).await.unwrap().slice().clone(),
+        ];
+
+        // Unchanged graph: nothing drifts
+        let clean = original.scan_drift(&slices).await.unwrap();
+        assert_eq!(clean.len(), 3);
+        assert!(clean.iter().all(|r| !r.has_drift()));
+
+        // Turn 2 is edited: slices anchored at 1 and 3 contain it, 4 does not
+        let drifted = ContextSlicer::new_for_test(build("h2_edited"), policy);
+        let reports = drifted.scan_drift(&slices).await.unwrap();
+
+        assert!(reports.windows(2).all(|w| w[0].slice_id.as_str() < w[1].slice_id.as_str()));
+
+        assert_eq!(reports.iter().filter(|r| r.has_drift()).count(), 2);
+        for report in reports.iter().filter(|r| r.has_drift()) {
+            assert_eq!(report.drifted_turns, vec![TurnDrift {
+                turn_id: TurnId::new(Uuid::from_u128(2)),
+                expected_hash: "h2".to_string(),
+                current_hash: Some("h2_edited".to_string()),
+            }]);
+        }
+
+        let incidents = DriftReport::incidents(&reports);
+        assert_eq!(incidents.len(), 1);
+        assert!(matches!(
+            &incidents[0].incident_type,
+            IncidentType::ContentHashMismatch { computed_hash, .. } if computed_hash == "h2_edited"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_issue_timestamps_do_not_change_slice_id() {
+        let store = build_linear_graph(5);
+        let anchor_id = TurnId::new(Uuid::from_u128(3));
+
+        let plain = ContextSlicer::new_for_test(store.clone(), SlicePolicyV1::minimal());
+        let stamped = ContextSlicer::new_for_test(store, SlicePolicyV1::minimal())
+            .with_issue_timestamps(true);
+
+        let before = chrono::Utc::now().timestamp_millis();
+        let plain_bundle = plain.slice(anchor_id).await.unwrap();
+        let stamped_bundle = stamped.slice(anchor_id).await.unwrap();
+
+        assert_eq!(plain_bundle.issued_at_unix_ms(), None);
+        assert!(stamped_bundle.issued_at_unix_ms().unwrap() >= before);
+        assert_eq!(plain_bundle.slice_id(), stamped_bundle.slice_id());
+        assert_ne!(plain_bundle.admissibility_token(), stamped_bundle.admissibility_token());
+    }
+
+    #[tokio::test]
+    async fn test_minimal_policy_recovers_radius() {
+        let store = build_linear_graph(20);
+        let anchor_id = TurnId::new(Uuid::from_u128(10));
+
+        // Radius 2 around turn 10 on a chain: turns 8..=12
+        let mut known = SlicePolicyV1::minimal();
+        known.max_nodes = 50;
+        known.max_radius = 2;
+        let target = ContextSlicer::new_for_test(store.clone(), known)
+            .slice(anchor_id).await.unwrap();
+        assert_eq!(target.num_turns(), 5);
+
+        let mut generous = SlicePolicyV1::minimal();
+        generous.max_nodes = 50;
+        generous.max_radius = 8;
+        let slicer = ContextSlicer::new_for_test(store.clone(), generous);
+
+        let minimal = slicer.minimal_policy_for(target.slice()).await.unwrap();
+        assert_eq!(minimal.max_radius, 2);
+        assert_eq!(minimal.max_nodes, 5);
+        assert_eq!(slicer.minimal_policy_for(target.slice()).await.unwrap().params_hash(), minimal.params_hash());
+
+        // The minimal policy reproduces the slice exactly
+        let reproduced = ContextSlicer::new_for_test(store.clone(), minimal)
+            .slice(anchor_id).await.unwrap();
+        assert_eq!(reproduced.turn_ids(), target.turn_ids());
+
+        // A policy too small to cover the slice cannot reproduce it
+        let mut tight = SlicePolicyV1::minimal();
+        tight.max_radius = 1;
+        let err = ContextSlicer::new_for_test(store, tight)
+            .minimal_policy_for(target.slice()).await.unwrap_err();
+        assert!(matches!(err, SlicerError::NotReproducible(_)));
+    }
+}
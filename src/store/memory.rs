@@ -1,9 +1,10 @@
 //! In-memory graph store for testing.
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
 use async_trait::async_trait;
 
-use crate::types::{TurnId, TurnSnapshot, Edge};
+use crate::types::{TurnId, TurnSnapshot, Edge, Phase};
 use super::GraphStore;
 
 /// Error type for in-memory store.
@@ -16,9 +17,24 @@ pub enum InMemoryError {
 
 /// In-memory graph store for testing.
 ///
-/// Uses BTreeMap/BTreeSet for deterministic iteration order.
+/// Uses BTreeMap/BTreeSet for deterministic iteration order. State is
+/// shared copy-on-write, so clones and checkpoints are cheap until mutated.
 #[derive(Debug, Clone, Default)]
 pub struct InMemoryGraphStore {
+    state: Arc<StoreState>,
+}
+
+/// Saved graph state of an `InMemoryGraphStore`.
+///
+/// Created by `InMemoryGraphStore::checkpoint`; holds a shared reference,
+/// so taking one does not copy the graph.
+#[derive(Debug, Clone)]
+pub struct StoreCheckpoint {
+    state: Arc<StoreState>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct StoreState {
     /// Turns by ID.
     turns: BTreeMap<TurnId, TurnSnapshot>,
     /// Parent -> Children mapping.
@@ -37,44 +53,62 @@ impl InMemoryGraphStore {
 
     /// Add a turn to the store.
     pub fn add_turn(&mut self, turn: TurnSnapshot) {
-        self.turns.insert(turn.id, turn);
+        Arc::make_mut(&mut self.state).turns.insert(turn.id, turn);
     }
 
     /// Add an edge to the store.
     pub fn add_edge(&mut self, edge: Edge) {
+        let state = Arc::make_mut(&mut self.state);
+
         // Update parent -> child mapping
-        self.children
+        state.children
             .entry(edge.parent)
             .or_default()
             .insert(edge.child);
         
         // Update child -> parent mapping
-        self.parents
+        state.parents
             .entry(edge.child)
             .or_default()
             .insert(edge.parent);
         
-        self.edges.push(edge);
+        state.edges.push(edge);
+    }
+
+    /// Capture the current turns and edges.
+    ///
+    /// O(1): the state is shared until the next mutation copies it.
+    pub fn checkpoint(&self) -> StoreCheckpoint {
+        StoreCheckpoint {
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// Reset the store to a previously captured state.
+    ///
+    /// The checkpoint stays valid and can be restored again.
+    pub fn restore(&mut self, checkpoint: &StoreCheckpoint) {
+        self.state = Arc::clone(&checkpoint.state);
     }
 
     /// Get all turns.
     pub fn all_turns(&self) -> Vec<&TurnSnapshot> {
-        self.turns.values().collect()
+        self.state.turns.values().collect()
     }
 
     /// Get number of turns.
     pub fn num_turns(&self) -> usize {
-        self.turns.len()
+        self.state.turns.len()
     }
 
     /// Get number of edges.
     pub fn num_edges(&self) -> usize {
-        self.edges.len()
+        self.state.edges.len()
     }
 
     /// Get all edges.
     pub fn all_edges(&self) -> &[Edge] {
-        &self.edges
+        &self.state.edges
     }
 }
 
@@ -83,24 +117,24 @@ impl GraphStore for InMemoryGraphStore {
     type Error = InMemoryError;
 
     async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error> {
-        Ok(self.turns.get(id).cloned())
+        Ok(self.state.turns.get(id).cloned())
     }
 
     async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error> {
         Ok(ids.iter()
-            .filter_map(|id| self.turns.get(id).cloned())
+            .filter_map(|id| self.state.turns.get(id).cloned())
             .collect())
     }
 
     async fn get_parents(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
-        Ok(self.parents
+        Ok(self.state.parents
             .get(id)
             .map(|set| set.iter().copied().collect())
             .unwrap_or_default())
     }
 
     async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
-        Ok(self.children
+        Ok(self.state.children
             .get(id)
             .map(|set| set.iter().copied().collect())
             .unwrap_or_default())
@@ -108,7 +142,7 @@ impl GraphStore for InMemoryGraphStore {
 
     async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
         // Get parents of this turn
-        let parents: Vec<TurnId> = self.parents
+        let parents: Vec<TurnId> = self.state.parents
             .get(id)
             .map(|set| set.iter().copied().collect())
             .unwrap_or_default();
@@ -117,7 +151,7 @@ impl GraphStore for InMemoryGraphStore {
         
         // For each parent, get their children (our siblings)
         for parent_id in parents {
-            if let Some(children) = self.children.get(&parent_id) {
+            if let Some(children) = self.state.children.get(&parent_id) {
                 for child_id in children {
                     if child_id != id {
                         siblings.insert(*child_id);
@@ -128,7 +162,7 @@ impl GraphStore for InMemoryGraphStore {
         
         // Sort by salience (desc) then by TurnId for determinism
         let mut sibling_list: Vec<_> = siblings.into_iter()
-            .filter_map(|sid| self.turns.get(&sid).map(|t| (sid, t.salience)))
+            .filter_map(|sid| self.state.turns.get(&sid).map(|t| (sid, t.salience)))
             .collect();
         
         sibling_list.sort_by(|a, b| {
@@ -146,7 +180,7 @@ impl GraphStore for InMemoryGraphStore {
     async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error> {
         let id_set: BTreeSet<_> = turn_ids.iter().copied().collect();
         
-        let mut result: Vec<Edge> = self.edges.iter()
+        let mut result: Vec<Edge> = self.state.edges.iter()
             .filter(|e| id_set.contains(&e.parent) && id_set.contains(&e.child))
             .cloned()
             .collect();
@@ -156,12 +190,55 @@ impl GraphStore for InMemoryGraphStore {
         
         Ok(result)
     }
+
+    async fn get_incident_edges(&self, id: &TurnId) -> Result<Vec<Edge>, Self::Error> {
+        let mut result: Vec<Edge> = self.state.edges.iter()
+            .filter(|e| e.parent == *id || e.child == *id)
+            .cloned()
+            .collect();
+
+        result.sort();
+        result.dedup();
+
+        Ok(result)
+    }
+
+    async fn turns_by_phase(&self, phase: Phase, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        let mut matching: Vec<(TurnId, f32)> = self.state.turns.values()
+            .filter(|t| t.phase == phase)
+            .map(|t| (t.id, t.salience))
+            .collect();
+
+        // Sort by salience (desc) then by TurnId for determinism
+        matching.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Ok(matching.into_iter()
+            .take(limit)
+            .map(|(id, _)| id)
+            .collect())
+    }
+
+    async fn recent_turns(&self, session_id: &str, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        let mut matching: Vec<(i64, TurnId)> = self.state.turns.values()
+            .filter(|t| t.session_id == session_id)
+            .map(|t| (t.created_at, t.id))
+            .collect();
+
+        // Newest first; ties broken by TurnId (desc) for determinism
+        matching.sort_by(|a, b| b.cmp(a));
+
+        Ok(matching.into_iter()
+            .take(limit)
+            .map(|(_, id)| id)
+            .collect())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Role, Phase, EdgeType};
+    use crate::store::NeighborOrder;
+    use crate::types::{Role, EdgeType};
     use uuid::Uuid;
 
     fn make_turn(id: u128, salience: f32) -> TurnSnapshot {
@@ -223,6 +300,40 @@ mod tests {
         assert_eq!(parents_of_3[0], id2);
     }
 
+    #[tokio::test]
+    async fn test_ordered_neighbors() {
+        let mut store = InMemoryGraphStore::new();
+
+        // Hub 10 with parents 1-3 and children 4-6
+        let saliences = [(1, 0.2), (2, 0.9), (3, 0.5), (4, 0.5), (5, 0.5), (6, 0.8)];
+        store.add_turn(make_turn(10, 0.5));
+        for (id, salience) in saliences {
+            store.add_turn(make_turn(id, salience));
+        }
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        for n in 1..=3 {
+            store.add_edge(Edge::new(id(n), id(10), EdgeType::Reply));
+        }
+        for n in 4..=6 {
+            store.add_edge(Edge::new(id(10), id(n), EdgeType::Reply));
+        }
+
+        let by_id = store.get_parents_ordered(&id(10), NeighborOrder::ById).await.unwrap();
+        assert_eq!(by_id, store.get_parents(&id(10)).await.unwrap());
+        assert_eq!(by_id, vec![id(1), id(2), id(3)]);
+
+        let by_salience = store.get_parents_ordered(&id(10), NeighborOrder::BySalienceDesc).await.unwrap();
+        assert_eq!(by_salience, vec![id(2), id(3), id(1)]);
+
+        // Salience ties fall back to TurnId
+        let children = store.get_children_ordered(&id(10), NeighborOrder::BySalienceDesc).await.unwrap();
+        assert_eq!(children, vec![id(6), id(4), id(5)]);
+        assert_eq!(
+            store.get_children_ordered(&id(10), NeighborOrder::BySalienceDesc).await.unwrap(),
+            children
+        );
+    }
+
     #[tokio::test]
     async fn test_siblings() {
         let mut store = InMemoryGraphStore::new();
@@ -280,5 +391,103 @@ mod tests {
         assert_eq!(edges[0].parent, id1);
         assert_eq!(edges[0].child, id2);
     }
-}
 
+    #[tokio::test]
+    async fn test_turns_by_phase() {
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(make_turn(1, 0.3));
+        store.add_turn(make_turn(2, 0.9));
+        store.add_turn(make_turn(3, 0.9));
+        let mut other = make_turn(4, 1.0);
+        other.phase = Phase::Synthesis;
+        store.add_turn(other);
+
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        // Salience desc, ties broken by TurnId; other phases excluded
+        let turns = store.turns_by_phase(Phase::Consolidation, 10).await.unwrap();
+        assert_eq!(turns, vec![id(2), id(3), id(1)]);
+
+        let limited = store.turns_by_phase(Phase::Consolidation, 2).await.unwrap();
+        assert_eq!(limited, vec![id(2), id(3)]);
+
+        let synthesis = store.turns_by_phase(Phase::Synthesis, 10).await.unwrap();
+        assert_eq!(synthesis, vec![id(4)]);
+        assert!(store.turns_by_phase(Phase::Planning, 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_exists_agrees_with_get_turn() {
+        let mut store = InMemoryGraphStore::new();
+        store.add_turn(make_turn(1, 0.5));
+
+        for n in [1u128, 2] {
+            let id = TurnId::new(Uuid::from_u128(n));
+            let present = store.get_turn(&id).await.unwrap().is_some();
+            assert_eq!(store.exists(&id).await.unwrap(), present);
+        }
+        assert!(store.exists(&TurnId::new(Uuid::from_u128(1))).await.unwrap());
+        assert!(!store.exists(&TurnId::new(Uuid::from_u128(2))).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_recent_turns() {
+        let mut store = InMemoryGraphStore::new();
+        let at = |id: u128, created_at: i64| {
+            let mut turn = make_turn(id, 0.5);
+            turn.created_at = created_at;
+            turn
+        };
+        store.add_turn(at(1, 1000));
+        store.add_turn(at(2, 3000));
+        store.add_turn(at(3, 2000));
+        store.add_turn(at(4, 3000));
+        let mut other_session = at(5, 9000);
+        other_session.session_id = "session_2".to_string();
+        store.add_turn(other_session);
+
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        // created_at desc, ties broken by TurnId desc; other sessions excluded
+        let recent = store.recent_turns("session_1", 10).await.unwrap();
+        assert_eq!(recent, vec![id(4), id(2), id(3), id(1)]);
+
+        let limited = store.recent_turns("session_1", 2).await.unwrap();
+        assert_eq!(limited, vec![id(4), id(2)]);
+
+        assert_eq!(store.recent_turns("session_2", 10).await.unwrap(), vec![id(5)]);
+        assert!(store.recent_turns("missing", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_restore_reproduces_slice() {
+        use crate::policy::SlicePolicyV1;
+        use crate::slicer::ContextSlicer;
+
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let mut store = InMemoryGraphStore::new();
+        for n in 1..=3 {
+            store.add_turn(make_turn(n, 0.5));
+        }
+        store.add_edge(Edge::new(id(1), id(2), EdgeType::Reply));
+        store.add_edge(Edge::new(id(2), id(3), EdgeType::Reply));
+
+        let slice_of = |store: &InMemoryGraphStore| {
+            let slicer = ContextSlicer::new_for_test(Arc::new(store.clone()), SlicePolicyV1::default());
+            async move { slicer.slice(id(2)).await.unwrap().slice_id().clone() }
+        };
+        let original = slice_of(&store).await;
+        let checkpoint = store.checkpoint();
+
+        // Branch: grow the graph
+        store.add_turn(make_turn(4, 0.5));
+        store.add_edge(Edge::new(id(2), id(4), EdgeType::Branch));
+        assert_eq!(store.num_turns(), 4);
+        assert_ne!(slice_of(&store).await, original);
+
+        store.restore(&checkpoint);
+        assert_eq!(store.num_turns(), 3);
+        assert_eq!(store.num_edges(), 2);
+        assert_eq!(slice_of(&store).await, original);
+    }
+}
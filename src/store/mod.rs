@@ -1,12 +1,65 @@
 //! Graph storage backends.
 
 pub mod memory;
+pub mod sharded;
 
 #[cfg(feature = "postgres")]
 pub mod postgres;
 
+#[cfg(feature = "arrow")]
+pub mod parquet;
+
 use async_trait::async_trait;
-use crate::types::{TurnId, TurnSnapshot, Edge};
+use serde::{Deserialize, Serialize};
+use crate::types::{TurnId, TurnSnapshot, Edge, Phase};
+
+/// Order of parent/child IDs returned by the `*_ordered` store queries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NeighborOrder {
+    /// By TurnId, as `get_parents` / `get_children` return them.
+    #[default]
+    ById,
+    /// By salience descending, then TurnId.
+    BySalienceDesc,
+}
+
+impl NeighborOrder {
+    pub(crate) fn is_by_id(&self) -> bool {
+        matches!(self, Self::ById)
+    }
+}
+
+/// Reorder neighbor IDs by salience desc then TurnId.
+///
+/// Turns the store cannot return sort last.
+pub(crate) async fn sort_by_salience_desc<S: GraphStore + ?Sized>(
+    store: &S,
+    mut ids: Vec<TurnId>,
+) -> Result<Vec<TurnId>, S::Error> {
+    let salience: std::collections::HashMap<TurnId, f32> = store
+        .get_turns(&ids)
+        .await?
+        .into_iter()
+        .map(|t| (t.id, t.salience))
+        .collect();
+    sort_ids_by_salience(&mut ids, &salience);
+    Ok(ids)
+}
+
+/// Sort IDs by salience desc then TurnId; IDs missing from `salience` sort last.
+pub(crate) fn sort_ids_by_salience(
+    ids: &mut [TurnId],
+    salience: &std::collections::HashMap<TurnId, f32>,
+) {
+    ids.sort_by(|a, b| {
+        let (sa, sb) = (
+            salience.get(a).copied().unwrap_or(f32::NEG_INFINITY),
+            salience.get(b).copied().unwrap_or(f32::NEG_INFINITY),
+        );
+        sb.total_cmp(&sa).then(a.cmp(b))
+    });
+}
 
 /// Trait for graph storage backends.
 ///
@@ -20,6 +73,14 @@ pub trait GraphStore: Send + Sync {
     /// Fetch a turn by ID.
     async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error>;
 
+    /// Check whether a turn exists without needing its snapshot.
+    ///
+    /// The default fetches the turn; backends with a cheaper lookup should
+    /// override it.
+    async fn exists(&self, id: &TurnId) -> Result<bool, Self::Error> {
+        Ok(self.get_turn(id).await?.is_some())
+    }
+
     /// Fetch multiple turns by ID.
     async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error>;
 
@@ -29,15 +90,135 @@ pub trait GraphStore: Send + Sync {
     /// Fetch child turn IDs (ordered by TurnId for determinism).
     async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error>;
 
+    /// Fetch parent turn IDs in the given order.
+    ///
+    /// `ById` is `get_parents`. The default `BySalienceDesc` looks the
+    /// parents up with `get_turns`; backends that can sort in the query
+    /// should override it.
+    async fn get_parents_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+        let parents = self.get_parents(id).await?;
+        match order {
+            NeighborOrder::ById => Ok(parents),
+            NeighborOrder::BySalienceDesc => sort_by_salience_desc(self, parents).await,
+        }
+    }
+
+    /// Fetch child turn IDs in the given order (see `get_parents_ordered`).
+    async fn get_children_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+        let children = self.get_children(id).await?;
+        match order {
+            NeighborOrder::ById => Ok(children),
+            NeighborOrder::BySalienceDesc => sort_by_salience_desc(self, children).await,
+        }
+    }
+
     /// Fetch sibling turn IDs (same parent, ordered by salience desc then TurnId).
     async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error>;
 
     /// Fetch edges between a set of turns.
     async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error>;
+
+    /// Fetch every edge with `id` as parent or child (sorted).
+    ///
+    /// The default combines `get_parents`, `get_children` and `get_edges`;
+    /// backends that can answer in one query should override it.
+    async fn get_incident_edges(&self, id: &TurnId) -> Result<Vec<Edge>, Self::Error> {
+        let mut neighborhood = vec![*id];
+        neighborhood.extend(self.get_parents(id).await?);
+        neighborhood.extend(self.get_children(id).await?);
+        let mut edges = self.get_edges(&neighborhood).await?;
+        edges.retain(|e| e.parent == *id || e.child == *id);
+        edges.sort();
+        edges.dedup();
+        Ok(edges)
+    }
+
+    /// Fetch up to `limit` turn IDs in a phase (ordered by salience desc then TurnId).
+    ///
+    /// Used to build phase-stratified anchor sets. The other queries cannot
+    /// enumerate turns, so the default returns no turns; backends that can
+    /// scan by phase should override it.
+    async fn turns_by_phase(&self, _phase: Phase, _limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    /// Fetch the latest `limit` turn IDs of a session (ordered by created_at desc then TurnId desc).
+    ///
+    /// A timeline query, independent of graph structure. Used to pick
+    /// "recent context" anchors. Like `turns_by_phase`, the default returns
+    /// no turns; backends that can scan a session should override it.
+    async fn recent_turns(&self, _session_id: &str, _limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        Ok(Vec::new())
+    }
 }
 
-pub use memory::InMemoryGraphStore;
+pub use memory::{InMemoryGraphStore, StoreCheckpoint};
+pub use sharded::ShardedGraphStore;
 
 #[cfg(feature = "postgres")]
 pub use postgres::PostgresGraphStore;
 
+#[cfg(feature = "arrow")]
+pub use self::parquet::{ParquetGraphStore, ParquetStoreError};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Role;
+    use uuid::Uuid;
+
+    /// Store implementing only the required methods.
+    struct MinimalStore(InMemoryGraphStore);
+
+    #[async_trait]
+    impl GraphStore for MinimalStore {
+        type Error = memory::InMemoryError;
+
+        async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error> {
+            self.0.get_turn(id).await
+        }
+
+        async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error> {
+            self.0.get_turns(ids).await
+        }
+
+        async fn get_parents(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+            self.0.get_parents(id).await
+        }
+
+        async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+            self.0.get_children(id).await
+        }
+
+        async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+            self.0.get_siblings(id, limit).await
+        }
+
+        async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error> {
+            self.0.get_edges(turn_ids).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enumeration_defaults_return_nothing() {
+        let mut inner = InMemoryGraphStore::new();
+        inner.add_turn(TurnSnapshot::new(
+            TurnId::new(Uuid::from_u128(1)),
+            "session_1".to_string(),
+            Role::User,
+            Phase::Synthesis,
+            0.5,
+            0,
+            0,
+            0.5,
+            0.5,
+            1.0,
+            1000,
+        ));
+        let store = MinimalStore(inner);
+
+        assert!(store.exists(&TurnId::new(Uuid::from_u128(1))).await.unwrap());
+        assert!(store.turns_by_phase(Phase::Synthesis, 10).await.unwrap().is_empty());
+        assert!(store.recent_turns("session_1", 10).await.unwrap().is_empty());
+    }
+}
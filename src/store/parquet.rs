@@ -0,0 +1,509 @@
+//! Parquet-backed graph store for offline analysis.
+//!
+//! Loads a static graph export (one Parquet file of turns, one of edges)
+//! into memory and serves it through `GraphStore`, so the slicer and the
+//! Atlas can run against exports without a database. The files are decoded
+//! in full when the store is opened; they are not memory-mapped, so the
+//! whole graph must fit in memory.
+//!
+//! ## File Layout
+//!
+//! Turns file:
+//!
+//! | Column | Type | Nullable |
+//! |--------|------|----------|
+//! | `id` | Utf8 (UUID) | no |
+//! | `session_id` | Utf8 | no |
+//! | `role` | Utf8 | no |
+//! | `phase` | Utf8 | yes |
+//! | `salience` | Float32 | no |
+//! | `trajectory_depth` | UInt32 | no |
+//! | `trajectory_sibling_order` | UInt32 | no |
+//! | `trajectory_homogeneity` | Float32 | no |
+//! | `trajectory_temporal` | Float32 | no |
+//! | `trajectory_complexity` | Float32 | no |
+//! | `created_at` | Int64 (unix seconds) | no |
+//! | `content_hash` | Utf8 | yes |
+//!
+//! A null or unrecognized `phase` loads as `Phase::Exploration` with
+//! `phase_unknown` set, and turns flagged `phase_unknown` are written with
+//! a null `phase`, so the flag survives a round trip.
+//!
+//! Edges file: `parent` (Utf8 UUID), `child` (Utf8 UUID), `edge_type` (Utf8).
+//!
+//! Ordering guarantees are those of `InMemoryGraphStore` (sorted by TurnId),
+//! independent of row order in the files.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float32Type, Int64Type, UInt32Type};
+use arrow_array::{
+    Array, ArrayRef, Float32Array, Int64Array, RecordBatch, StringArray, UInt32Array,
+};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef};
+use async_trait::async_trait;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+
+use crate::types::{Edge, EdgeType, Phase, Role, TurnId, TurnSnapshot};
+use super::memory::{InMemoryError, InMemoryGraphStore};
+use super::{GraphStore, NeighborOrder};
+
+/// Error type for loading or writing Parquet exports.
+#[derive(Debug, thiserror::Error)]
+pub enum ParquetStoreError {
+    /// Filesystem error.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Parquet decoding/encoding error.
+    #[error("Parquet error: {0}")]
+    Parquet(#[from] ParquetError),
+    /// Arrow error.
+    #[error("Arrow error: {0}")]
+    Arrow(#[from] ArrowError),
+    /// Column missing or of the wrong type.
+    #[error("Invalid column '{column}': {reason}")]
+    InvalidColumn {
+        /// Column name.
+        column: String,
+        /// What was wrong.
+        reason: String,
+    },
+    /// Value could not be parsed.
+    #[error("Invalid value in column '{column}': {value}")]
+    InvalidValue {
+        /// Column name.
+        column: String,
+        /// Offending value.
+        value: String,
+    },
+}
+
+/// Graph store backed by Parquet exports.
+///
+/// Files are decoded once at open time; lookups are served from memory.
+#[derive(Debug, Clone)]
+pub struct ParquetGraphStore {
+    inner: InMemoryGraphStore,
+}
+
+impl ParquetGraphStore {
+    /// Load a store from turn and edge Parquet files.
+    pub fn open(
+        turns_path: impl AsRef<Path>,
+        edges_path: impl AsRef<Path>,
+    ) -> Result<Self, ParquetStoreError> {
+        let mut inner = InMemoryGraphStore::new();
+
+        for batch in read_batches(turns_path.as_ref())? {
+            for turn in parse_turns(&batch)? {
+                inner.add_turn(turn);
+            }
+        }
+
+        let mut edges = Vec::new();
+        for batch in read_batches(edges_path.as_ref())? {
+            edges.extend(parse_edges(&batch)?);
+        }
+        // Edge order in the file must not affect get_edges output
+        edges.sort();
+        for edge in edges {
+            inner.add_edge(edge);
+        }
+
+        Ok(Self { inner })
+    }
+
+    /// Write turns and edges to Parquet files in the layout `open` expects.
+    pub fn write(
+        turns: &[TurnSnapshot],
+        edges: &[Edge],
+        turns_path: impl AsRef<Path>,
+        edges_path: impl AsRef<Path>,
+    ) -> Result<(), ParquetStoreError> {
+        write_batch(turns_path.as_ref(), turns_batch(turns)?)?;
+        write_batch(edges_path.as_ref(), edges_batch(edges)?)?;
+        Ok(())
+    }
+
+    /// Get the number of loaded turns.
+    pub fn num_turns(&self) -> usize {
+        self.inner.num_turns()
+    }
+
+    /// Get the number of loaded edges.
+    pub fn num_edges(&self) -> usize {
+        self.inner.num_edges()
+    }
+}
+
+#[async_trait]
+impl GraphStore for ParquetGraphStore {
+    type Error = InMemoryError;
+
+    async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error> {
+        self.inner.get_turn(id).await
+    }
+
+    async fn exists(&self, id: &TurnId) -> Result<bool, Self::Error> {
+        self.inner.exists(id).await
+    }
+
+    async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error> {
+        self.inner.get_turns(ids).await
+    }
+
+    async fn get_parents(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+        self.inner.get_parents(id).await
+    }
+
+    async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+        self.inner.get_children(id).await
+    }
+
+    async fn get_parents_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+        self.inner.get_parents_ordered(id, order).await
+    }
+
+    async fn get_children_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+        self.inner.get_children_ordered(id, order).await
+    }
+
+    async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        self.inner.get_siblings(id, limit).await
+    }
+
+    async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error> {
+        self.inner.get_edges(turn_ids).await
+    }
+
+    async fn get_incident_edges(&self, id: &TurnId) -> Result<Vec<Edge>, Self::Error> {
+        self.inner.get_incident_edges(id).await
+    }
+
+    async fn turns_by_phase(&self, phase: Phase, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        self.inner.turns_by_phase(phase, limit).await
+    }
+
+    async fn recent_turns(&self, session_id: &str, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        self.inner.recent_turns(session_id, limit).await
+    }
+}
+
+fn turns_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("session_id", DataType::Utf8, false),
+        Field::new("role", DataType::Utf8, false),
+        Field::new("phase", DataType::Utf8, true),
+        Field::new("salience", DataType::Float32, false),
+        Field::new("trajectory_depth", DataType::UInt32, false),
+        Field::new("trajectory_sibling_order", DataType::UInt32, false),
+        Field::new("trajectory_homogeneity", DataType::Float32, false),
+        Field::new("trajectory_temporal", DataType::Float32, false),
+        Field::new("trajectory_complexity", DataType::Float32, false),
+        Field::new("created_at", DataType::Int64, false),
+        Field::new("content_hash", DataType::Utf8, true),
+    ]))
+}
+
+fn edges_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("parent", DataType::Utf8, false),
+        Field::new("child", DataType::Utf8, false),
+        Field::new("edge_type", DataType::Utf8, false),
+    ]))
+}
+
+fn read_batches(path: &Path) -> Result<Vec<RecordBatch>, ParquetStoreError> {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path)?)?.build()?;
+    Ok(reader.collect::<Result<Vec<_>, _>>()?)
+}
+
+fn write_batch(path: &Path, batch: RecordBatch) -> Result<(), ParquetStoreError> {
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+fn column<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a ArrayRef, ParquetStoreError> {
+    batch.column_by_name(name).ok_or_else(|| ParquetStoreError::InvalidColumn {
+        column: name.to_string(),
+        reason: "missing".to_string(),
+    })
+}
+
+fn wrong_type(name: &str, expected: &str) -> ParquetStoreError {
+    ParquetStoreError::InvalidColumn {
+        column: name.to_string(),
+        reason: format!("expected {}", expected),
+    }
+}
+
+fn strings<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a StringArray, ParquetStoreError> {
+    column(batch, name)?.as_string_opt::<i32>().ok_or_else(|| wrong_type(name, "Utf8"))
+}
+
+fn f32s<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a Float32Array, ParquetStoreError> {
+    column(batch, name)?.as_primitive_opt::<Float32Type>().ok_or_else(|| wrong_type(name, "Float32"))
+}
+
+fn u32s<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a UInt32Array, ParquetStoreError> {
+    column(batch, name)?.as_primitive_opt::<UInt32Type>().ok_or_else(|| wrong_type(name, "UInt32"))
+}
+
+fn i64s<'a>(batch: &'a RecordBatch, name: &str) -> Result<&'a Int64Array, ParquetStoreError> {
+    column(batch, name)?.as_primitive_opt::<Int64Type>().ok_or_else(|| wrong_type(name, "Int64"))
+}
+
+fn parse_turn_id(column: &str, value: &str) -> Result<TurnId, ParquetStoreError> {
+    TurnId::from_str(value).map_err(|_| ParquetStoreError::InvalidValue {
+        column: column.to_string(),
+        value: value.to_string(),
+    })
+}
+
+fn parse_turns(batch: &RecordBatch) -> Result<Vec<TurnSnapshot>, ParquetStoreError> {
+    let ids = strings(batch, "id")?;
+    let sessions = strings(batch, "session_id")?;
+    let roles = strings(batch, "role")?;
+    let phases = strings(batch, "phase")?;
+    let salience = f32s(batch, "salience")?;
+    let depth = u32s(batch, "trajectory_depth")?;
+    let sibling_order = u32s(batch, "trajectory_sibling_order")?;
+    let homogeneity = f32s(batch, "trajectory_homogeneity")?;
+    let temporal = f32s(batch, "trajectory_temporal")?;
+    let complexity = f32s(batch, "trajectory_complexity")?;
+    let created_at = i64s(batch, "created_at")?;
+    let content_hashes = strings(batch, "content_hash")?;
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let role = Role::from_str(roles.value(row)).ok_or_else(|| ParquetStoreError::InvalidValue {
+                column: "role".to_string(),
+                value: roles.value(row).to_string(),
+            })?;
+            let phase = (!phases.is_null(row)).then(|| phases.value(row));
+            let content_hash = if content_hashes.is_null(row) {
+                None
+            } else {
+                Some(content_hashes.value(row).to_string())
+            };
+
+            Ok(TurnSnapshot::new(
+                parse_turn_id("id", ids.value(row))?,
+                sessions.value(row).to_string(),
+                role,
+                Phase::default(),
+                salience.value(row),
+                depth.value(row),
+                sibling_order.value(row),
+                homogeneity.value(row),
+                temporal.value(row),
+                complexity.value(row),
+                created_at.value(row),
+            )
+            .with_parsed_phase(phase)
+            .with_content_hash(content_hash))
+        })
+        .collect()
+}
+
+fn parse_edges(batch: &RecordBatch) -> Result<Vec<Edge>, ParquetStoreError> {
+    let parents = strings(batch, "parent")?;
+    let children = strings(batch, "child")?;
+    let edge_types = strings(batch, "edge_type")?;
+
+    (0..batch.num_rows())
+        .map(|row| {
+            let edge_type = EdgeType::from_str(edge_types.value(row)).ok_or_else(|| {
+                ParquetStoreError::InvalidValue {
+                    column: "edge_type".to_string(),
+                    value: edge_types.value(row).to_string(),
+                }
+            })?;
+
+            Ok(Edge::new(
+                parse_turn_id("parent", parents.value(row))?,
+                parse_turn_id("child", children.value(row))?,
+                edge_type,
+            ))
+        })
+        .collect()
+}
+
+fn turns_batch(turns: &[TurnSnapshot]) -> Result<RecordBatch, ArrowError> {
+    let utf8 = |f: &dyn Fn(&TurnSnapshot) -> String| -> ArrayRef {
+        Arc::new(StringArray::from_iter_values(turns.iter().map(f)))
+    };
+
+    RecordBatch::try_new(turns_schema(), vec![
+        utf8(&|t| t.id.to_string()),
+        utf8(&|t| t.session_id.clone()),
+        utf8(&|t| t.role.to_string()),
+        Arc::new(
+            turns
+                .iter()
+                .map(|t| (!t.phase_unknown).then(|| t.phase.to_string()))
+                .collect::<StringArray>(),
+        ),
+        Arc::new(Float32Array::from_iter_values(turns.iter().map(|t| t.salience))),
+        Arc::new(UInt32Array::from_iter_values(turns.iter().map(|t| t.trajectory_depth))),
+        Arc::new(UInt32Array::from_iter_values(turns.iter().map(|t| t.trajectory_sibling_order))),
+        Arc::new(Float32Array::from_iter_values(turns.iter().map(|t| t.trajectory_homogeneity))),
+        Arc::new(Float32Array::from_iter_values(turns.iter().map(|t| t.trajectory_temporal))),
+        Arc::new(Float32Array::from_iter_values(turns.iter().map(|t| t.trajectory_complexity))),
+        Arc::new(Int64Array::from_iter_values(turns.iter().map(|t| t.created_at))),
+        Arc::new(turns.iter().map(|t| t.content_hash.clone()).collect::<StringArray>()),
+    ])
+}
+
+fn edges_batch(edges: &[Edge]) -> Result<RecordBatch, ArrowError> {
+    RecordBatch::try_new(edges_schema(), vec![
+        Arc::new(StringArray::from_iter_values(edges.iter().map(|e| e.parent.to_string()))),
+        Arc::new(StringArray::from_iter_values(edges.iter().map(|e| e.child.to_string()))),
+        Arc::new(StringArray::from_iter_values(edges.iter().map(|e| e.edge_type.to_string()))),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::SlicePolicyV1;
+    use crate::slicer::ContextSlicer;
+    use uuid::Uuid;
+
+    fn make_turn(id: u128, phase: Phase, content_hash: Option<&str>) -> TurnSnapshot {
+        TurnSnapshot::new(
+            TurnId::new(Uuid::from_u128(id)),
+            "session_1".to_string(),
+            if id % 2 == 0 { Role::Assistant } else { Role::User },
+            phase,
+            0.5,
+            id as u32,
+            0,
+            0.5,
+            0.5,
+            1.0,
+            1000 + id as i64,
+        )
+        .with_content_hash(content_hash.map(|h| h.to_string()))
+    }
+
+    fn fixture_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("graph_kernel_{}_{}", name, Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[tokio::test]
+    async fn test_parquet_roundtrip_and_slice() {
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        // Rows deliberately out of order; 3 has no content hash
+        let turns = vec![
+            make_turn(4, Phase::Synthesis, Some("hash4")),
+            make_turn(1, Phase::Exploration, Some("hash1")),
+            make_turn(3, Phase::Debugging, None),
+            make_turn(2, Phase::Planning, Some("hash2")),
+        ];
+        let edges = vec![
+            Edge::new(id(3), id(4), EdgeType::Reply),
+            Edge::new(id(1), id(2), EdgeType::Reply),
+            Edge::new(id(2), id(3), EdgeType::Branch),
+        ];
+
+        let dir = fixture_dir("parquet_store");
+        let turns_path = dir.join("turns.parquet");
+        let edges_path = dir.join("edges.parquet");
+        ParquetGraphStore::write(&turns, &edges, &turns_path, &edges_path).unwrap();
+
+        let store = ParquetGraphStore::open(&turns_path, &edges_path).unwrap();
+        assert_eq!(store.num_turns(), 4);
+        assert_eq!(store.num_edges(), 3);
+
+        let turn3 = store.get_turn(&id(3)).await.unwrap().unwrap();
+        assert_eq!(turn3.content_hash, None);
+        assert_eq!(turn3.phase, Phase::Debugging);
+        let turn4 = store.get_turn(&id(4)).await.unwrap().unwrap();
+        assert_eq!(turn4.content_hash.as_deref(), Some("hash4"));
+        assert_eq!(turn4.role, Role::Assistant);
+
+        // Slicing the Parquet store matches slicing the same graph in memory
+        let mut memory = InMemoryGraphStore::new();
+        for turn in &turns {
+            memory.add_turn(turn.clone());
+        }
+        for edge in &edges {
+            memory.add_edge(edge.clone());
+        }
+
+        let policy = SlicePolicyV1::default();
+        let secret = b"parquet_test_secret".to_vec();
+        let from_parquet = ContextSlicer::new(Arc::new(store), policy.clone(), secret.clone())
+            .slice(id(2))
+            .await
+            .unwrap();
+        let from_memory = ContextSlicer::new(Arc::new(memory), policy, secret)
+            .slice(id(2))
+            .await
+            .unwrap();
+
+        assert_eq!(from_parquet.slice().num_turns(), 4);
+        assert_eq!(from_parquet.slice().slice_id, from_memory.slice().slice_id);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_parquet_rejects_invalid_role() {
+        let turn = make_turn(1, Phase::Planning, None);
+        let dir = fixture_dir("parquet_invalid");
+        let turns_path = dir.join("turns.parquet");
+        let edges_path = dir.join("edges.parquet");
+        ParquetGraphStore::write(std::slice::from_ref(&turn), &[], &turns_path, &edges_path).unwrap();
+
+        // Overwrite the turns file with an unknown role
+        let mut columns = turns_batch(&[turn]).unwrap().columns().to_vec();
+        columns[2] = Arc::new(StringArray::from(vec!["narrator"]));
+        write_batch(&turns_path, RecordBatch::try_new(turns_schema(), columns).unwrap()).unwrap();
+
+        let err = ParquetGraphStore::open(&turns_path, &edges_path).unwrap_err();
+        assert!(matches!(err, ParquetStoreError::InvalidValue { ref column, .. } if column == "role"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_parquet_unknown_phase() {
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let unknown = make_turn(1, Phase::Planning, None).with_parsed_phase(None);
+        let known = make_turn(2, Phase::Planning, None);
+        let dir = fixture_dir("parquet_unknown_phase");
+        let turns_path = dir.join("turns.parquet");
+        let edges_path = dir.join("edges.parquet");
+        ParquetGraphStore::write(&[unknown, known.clone()], &[], &turns_path, &edges_path).unwrap();
+
+        // The flag round-trips through a null phase
+        let store = ParquetGraphStore::open(&turns_path, &edges_path).unwrap();
+        assert!(store.get_turn(&id(1)).await.unwrap().unwrap().phase_unknown);
+        assert!(!store.get_turn(&id(2)).await.unwrap().unwrap().phase_unknown);
+
+        // Phases this build does not recognize load instead of failing
+        let mut columns = turns_batch(&[known]).unwrap().columns().to_vec();
+        columns[3] = Arc::new(StringArray::from(vec!["brainstorming"]));
+        write_batch(&turns_path, RecordBatch::try_new(turns_schema(), columns).unwrap()).unwrap();
+
+        let store = ParquetGraphStore::open(&turns_path, &edges_path).unwrap();
+        let turn = store.get_turn(&id(2)).await.unwrap().unwrap();
+        assert!(turn.phase_unknown);
+        assert_eq!(turn.phase, Phase::default());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -7,17 +7,21 @@
 //! - `DB_MAX_CONNECTIONS`: Maximum pool size (default: 10)
 //! - `DB_MIN_CONNECTIONS`: Minimum idle connections (default: 2)
 //! - `DB_CONNECT_TIMEOUT_SECS`: Connection timeout (default: 10)
+//! - `DB_STATEMENT_TIMEOUT_SECS`: Per-statement timeout; 0 disables (default: unset, the server setting applies)
 //! - `DB_IDLE_TIMEOUT_SECS`: Idle connection timeout (default: 300)
 //! - `DB_MAX_LIFETIME_SECS`: Max connection lifetime (default: 1800)
+//! - `DB_MAX_RETRIES`: Retries for transient read errors (default: 2)
+//! - `DB_RETRY_BASE_DELAY_MS`: Initial retry backoff, doubled per retry (default: 50)
 
 use async_trait::async_trait;
 use sqlx::postgres::{PgPool, PgPoolOptions};
-use sqlx::Row;
+use sqlx::{Executor, Row};
+use std::future::Future;
 use std::time::Duration;
 use uuid::Uuid;
 
 use crate::types::{TurnId, TurnSnapshot, Edge, EdgeType, Role, Phase};
-use super::GraphStore;
+use super::{GraphStore, NeighborOrder};
 
 /// Configuration for PostgreSQL connection pool.
 ///
@@ -36,10 +40,18 @@ pub struct PostgresConfig {
     pub min_connections: u32,
     /// Connection acquire timeout in seconds (default: 10).
     pub connect_timeout_secs: u64,
+    /// Per-statement timeout in seconds, set as `statement_timeout` on every
+    /// pooled connection (default: none, the server setting applies).
+    pub statement_timeout_secs: Option<u64>,
     /// Idle connection timeout in seconds (default: 300 = 5 min).
     pub idle_timeout_secs: u64,
     /// Maximum connection lifetime in seconds (default: 1800 = 30 min).
     pub max_lifetime_secs: u64,
+    /// Retries for transient read errors; 0 disables retrying (default: 2).
+    pub max_retries: u32,
+    /// Backoff before the first retry in milliseconds, doubled on each
+    /// subsequent retry (default: 50).
+    pub retry_base_delay_ms: u64,
 }
 
 impl PostgresConfig {
@@ -60,6 +72,9 @@ impl PostgresConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(10),
+            statement_timeout_secs: std::env::var("DB_STATEMENT_TIMEOUT_SECS")
+                .ok()
+                .and_then(|s| s.parse().ok()),
             idle_timeout_secs: std::env::var("DB_IDLE_TIMEOUT_SECS")
                 .ok()
                 .and_then(|s| s.parse().ok())
@@ -68,8 +83,22 @@ impl PostgresConfig {
                 .ok()
                 .and_then(|s| s.parse().ok())
                 .unwrap_or(1800),
+            max_retries: std::env::var("DB_MAX_RETRIES")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(2),
+            retry_base_delay_ms: std::env::var("DB_RETRY_BASE_DELAY_MS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(50),
         }
     }
+
+    /// The `SET statement_timeout` issued on each new connection, if any.
+    pub fn statement_timeout_sql(&self) -> Option<String> {
+        self.statement_timeout_secs
+            .map(|secs| format!("SET statement_timeout = '{}s'", secs))
+    }
 }
 
 impl Default for PostgresConfig {
@@ -78,12 +107,97 @@ impl Default for PostgresConfig {
     }
 }
 
+/// Check whether a database error is worth retrying.
+///
+/// Transient errors are connection-level failures (I/O, pool timeouts,
+/// crashed workers) and the PostgreSQL SQLSTATEs for connection exceptions
+/// (class `08`), serialization failures and deadlocks (`40001`, `40P01`),
+/// too many connections (`53300`), server shutdown (`57P01`-`57P03`) and
+/// statement timeouts (see [`is_statement_timeout`]). Everything else,
+/// including `RowNotFound` and decode errors, is permanent.
+pub fn is_transient(err: &sqlx::Error) -> bool {
+    if is_statement_timeout(err) {
+        return true;
+    }
+    match err {
+        sqlx::Error::Io(_) | sqlx::Error::PoolTimedOut | sqlx::Error::WorkerCrashed => true,
+        sqlx::Error::Database(db) => db.code().is_some_and(|code| {
+            code.starts_with("08")
+                || matches!(
+                    code.as_ref(),
+                    "40001" | "40P01" | "53300" | "57P01" | "57P02" | "57P03"
+                )
+        }),
+        _ => false,
+    }
+}
+
+/// Check whether a query was cancelled by `statement_timeout`.
+///
+/// PostgreSQL reports this as SQLSTATE `57014` (`query_canceled`), which
+/// also covers explicit cancellation; the kernel never cancels its own
+/// queries, so here it means the configured statement timeout fired.
+pub fn is_statement_timeout(err: &sqlx::Error) -> bool {
+    matches!(err, sqlx::Error::Database(db) if db.code().as_deref() == Some("57014"))
+}
+
+/// Retry schedule for read queries.
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+}
+
+impl RetryPolicy {
+    fn from_config(config: &PostgresConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            base_delay: Duration::from_millis(config.retry_base_delay_ms),
+        }
+    }
+
+    /// Backoff before retry number `attempt` (0-based).
+    fn delay(&self, attempt: u32) -> Duration {
+        self.base_delay.saturating_mul(1u32 << attempt.min(16))
+    }
+
+    /// Run a query, retrying transient errors with exponential backoff.
+    ///
+    /// The closure is called once per attempt so each retry builds a fresh
+    /// query. Permanent errors are returned immediately.
+    async fn run<T, F, Fut>(&self, mut query: F) -> Result<T, sqlx::Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match query().await {
+                Err(e) if attempt < self.max_retries && is_transient(&e) => {
+                    let delay = self.delay(attempt);
+                    tracing::warn!(
+                        error = %e,
+                        attempt = attempt + 1,
+                        max_retries = self.max_retries,
+                        delay_ms = delay.as_millis() as u64,
+                        "Transient database error, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
 /// PostgreSQL graph store.
 ///
 /// Queries the Orbit database for turn and edge data.
 /// Uses connection pooling with production-tuned settings.
 pub struct PostgresGraphStore {
     pool: PgPool,
+    retry: RetryPolicy,
 }
 
 impl PostgresGraphStore {
@@ -93,11 +207,14 @@ impl PostgresGraphStore {
             max_connections = config.max_connections,
             min_connections = config.min_connections,
             connect_timeout_secs = config.connect_timeout_secs,
+            statement_timeout_secs = ?config.statement_timeout_secs,
             idle_timeout_secs = config.idle_timeout_secs,
             max_lifetime_secs = config.max_lifetime_secs,
+            max_retries = config.max_retries,
             "Initializing PostgreSQL connection pool"
         );
 
+        let statement_timeout_sql = config.statement_timeout_sql();
         let pool = PgPoolOptions::new()
             .max_connections(config.max_connections)
             .min_connections(config.min_connections)
@@ -105,10 +222,41 @@ impl PostgresGraphStore {
             .idle_timeout(Duration::from_secs(config.idle_timeout_secs))
             .max_lifetime(Duration::from_secs(config.max_lifetime_secs))
             .test_before_acquire(true)
+            .after_connect(move |conn, _meta| {
+                let statement_timeout_sql = statement_timeout_sql.clone();
+                Box::pin(async move {
+                    if let Some(sql) = statement_timeout_sql {
+                        conn.execute(sql.as_str()).await?;
+                    }
+                    Ok(())
+                })
+            })
             .connect(&config.database_url)
             .await?;
 
-        Ok(Self { pool })
+        Ok(Self {
+            pool,
+            retry: RetryPolicy::from_config(&config),
+        })
+    }
+
+    /// Create a store backed by a lazily-connecting pool.
+    ///
+    /// No connection is attempted until the first query, so routes that
+    /// never touch the database can be exercised without PostgreSQL.
+    #[cfg(all(test, feature = "service"))]
+    pub(crate) fn connect_lazy_for_test() -> Self {
+        let pool = PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/graph_kernel_test")
+            .expect("valid database URL");
+
+        Self {
+            pool,
+            retry: RetryPolicy {
+                max_retries: 0,
+                base_delay: Duration::ZERO,
+            },
+        }
     }
 
     /// Create a store from environment variables.
@@ -154,19 +302,23 @@ impl PostgresGraphStore {
         &self,
         id: &TurnId,
     ) -> Result<Option<(TurnSnapshot, String)>, PostgresError> {
-        let row = sqlx::query(
-            r#"
-            SELECT id, conversation_id, role, phase, salience_score,
-                   trajectory_depth, trajectory_sibling_order, trajectory_homogeneity,
-                   trajectory_temporal, trajectory_complexity, created_at, content_hash,
-                   content_text
-            FROM memory_turns
-            WHERE id = $1
-            "#
-        )
-        .bind(id.as_uuid())
-        .fetch_optional(&self.pool)
-        .await?;
+        let row = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT id, conversation_id, role, phase, salience_score,
+                           trajectory_depth, trajectory_sibling_order, trajectory_homogeneity,
+                           trajectory_temporal, trajectory_complexity, created_at, content_hash,
+                           content_text
+                    FROM memory_turns
+                    WHERE id = $1
+                    "#
+                )
+                .bind(id.as_uuid())
+                .fetch_optional(&self.pool)
+            })
+            .await?;
 
         match row {
             Some(ref r) => {
@@ -216,7 +368,7 @@ impl PostgresGraphStore {
             TurnId::new(id),
             conversation_id.map(|u| u.to_string()).unwrap_or_default(),
             role_str.and_then(|s| Role::from_str(&s)).unwrap_or_default(),
-            phase_str.and_then(|s| Phase::from_str(&s)).unwrap_or_default(),
+            Phase::default(),
             salience.unwrap_or(0.5) as f32,
             depth.unwrap_or(0) as u32,
             sibling_order.unwrap_or(0) as u32,
@@ -224,7 +376,9 @@ impl PostgresGraphStore {
             temporal.unwrap_or(0.5) as f32,
             complexity.unwrap_or(1) as f32,
             created_at.timestamp(),
-        ).with_content_hash(content_hash))
+        )
+        .with_parsed_phase(phase_str.as_deref())
+        .with_content_hash(content_hash))
     }
 }
 
@@ -244,29 +398,58 @@ pub struct PoolStats {
 pub enum PostgresError {
     /// Database error.
     #[error("Database error: {0}")]
-    Database(#[from] sqlx::Error),
+    Database(sqlx::Error),
+    /// A query still hit `statement_timeout` after all retries.
+    ///
+    /// Retryable: the query may succeed under less load or a longer timeout.
+    #[error("Statement timeout: {0}")]
+    StatementTimeout(sqlx::Error),
     /// Content hash verification failed.
     #[error("Content hash verification failed: {0}")]
     ContentHashMismatch(#[from] crate::types::ContentHashError),
 }
 
+impl From<sqlx::Error> for PostgresError {
+    fn from(err: sqlx::Error) -> Self {
+        if is_statement_timeout(&err) {
+            Self::StatementTimeout(err)
+        } else {
+            Self::Database(err)
+        }
+    }
+}
+
+impl PostgresError {
+    /// Whether the failed call is worth retrying later.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Database(err) | Self::StatementTimeout(err) => is_transient(err),
+            Self::ContentHashMismatch(_) => false,
+        }
+    }
+}
+
 #[async_trait]
 impl GraphStore for PostgresGraphStore {
     type Error = PostgresError;
 
     async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error> {
-        let row = sqlx::query(
-            r#"
-            SELECT id, conversation_id, role, phase, salience_score,
-                   trajectory_depth, trajectory_sibling_order, trajectory_homogeneity,
-                   trajectory_temporal, trajectory_complexity, created_at, content_hash
-            FROM memory_turns
-            WHERE id = $1
-            "#
-        )
-        .bind(id.as_uuid())
-        .fetch_optional(&self.pool)
-        .await?;
+        let row = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT id, conversation_id, role, phase, salience_score,
+                           trajectory_depth, trajectory_sibling_order, trajectory_homogeneity,
+                           trajectory_temporal, trajectory_complexity, created_at, content_hash
+                    FROM memory_turns
+                    WHERE id = $1
+                    "#
+                )
+                .bind(id.as_uuid())
+                .fetch_optional(&self.pool)
+            })
+            .await?;
 
         match row {
             Some(ref r) => Ok(Some(Self::parse_turn_row(r)?)),
@@ -274,21 +457,38 @@ impl GraphStore for PostgresGraphStore {
         }
     }
 
+    async fn exists(&self, id: &TurnId) -> Result<bool, Self::Error> {
+        let row = self
+            .retry
+            .run(|| {
+                sqlx::query("SELECT 1 FROM memory_turns WHERE id = $1 LIMIT 1")
+                    .bind(id.as_uuid())
+                    .fetch_optional(&self.pool)
+            })
+            .await?;
+
+        Ok(row.is_some())
+    }
+
     async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error> {
         let uuids: Vec<Uuid> = ids.iter().map(|id| id.as_uuid()).collect();
-        let rows = sqlx::query(
-            r#"
-            SELECT id, conversation_id, role, phase, salience_score,
-                   trajectory_depth, trajectory_sibling_order, trajectory_homogeneity,
-                   trajectory_temporal, trajectory_complexity, created_at, content_hash
-            FROM memory_turns
-            WHERE id = ANY($1)
-            ORDER BY id
-            "#
-        )
-        .bind(&uuids)
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT id, conversation_id, role, phase, salience_score,
+                           trajectory_depth, trajectory_sibling_order, trajectory_homogeneity,
+                           trajectory_temporal, trajectory_complexity, created_at, content_hash
+                    FROM memory_turns
+                    WHERE id = ANY($1)
+                    ORDER BY id
+                    "#
+                )
+                .bind(&uuids)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         rows.iter()
             .map(Self::parse_turn_row)
@@ -297,17 +497,21 @@ impl GraphStore for PostgresGraphStore {
     }
 
     async fn get_parents(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
-        let rows = sqlx::query(
-            r#"
-            SELECT parent_turn_id
-            FROM memory_turn_edges
-            WHERE child_turn_id = $1
-            ORDER BY parent_turn_id
-            "#
-        )
-        .bind(id.as_uuid())
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT parent_turn_id
+                    FROM memory_turn_edges
+                    WHERE child_turn_id = $1
+                    ORDER BY parent_turn_id
+                    "#
+                )
+                .bind(id.as_uuid())
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(rows.iter()
             .map(|row| TurnId::new(row.get("parent_turn_id")))
@@ -315,17 +519,77 @@ impl GraphStore for PostgresGraphStore {
     }
 
     async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
-        let rows = sqlx::query(
-            r#"
-            SELECT child_turn_id
-            FROM memory_turn_edges
-            WHERE parent_turn_id = $1
-            ORDER BY child_turn_id
-            "#
-        )
-        .bind(id.as_uuid())
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT child_turn_id
+                    FROM memory_turn_edges
+                    WHERE parent_turn_id = $1
+                    ORDER BY child_turn_id
+                    "#
+                )
+                .bind(id.as_uuid())
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(rows.iter()
+            .map(|row| TurnId::new(row.get("child_turn_id")))
+            .collect())
+    }
+
+    async fn get_parents_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+        if order == NeighborOrder::ById {
+            return self.get_parents(id).await;
+        }
+
+        // Rank as the in-memory sort would: NULL salience reads as 0.5 (see
+        // `parse_turn_row`), compared at f32 precision, and missing turns last
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT e.parent_turn_id
+                    FROM memory_turn_edges e
+                    LEFT JOIN memory_turns mt ON mt.id = e.parent_turn_id
+                    WHERE e.child_turn_id = $1
+                    ORDER BY mt.id IS NULL, COALESCE(mt.salience_score, 0.5)::real DESC, e.parent_turn_id
+                    "#
+                )
+                .bind(id.as_uuid())
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(rows.iter()
+            .map(|row| TurnId::new(row.get("parent_turn_id")))
+            .collect())
+    }
+
+    async fn get_children_ordered(&self, id: &TurnId, order: NeighborOrder) -> Result<Vec<TurnId>, Self::Error> {
+        if order == NeighborOrder::ById {
+            return self.get_children(id).await;
+        }
+
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT e.child_turn_id
+                    FROM memory_turn_edges e
+                    LEFT JOIN memory_turns mt ON mt.id = e.child_turn_id
+                    WHERE e.parent_turn_id = $1
+                    ORDER BY mt.id IS NULL, COALESCE(mt.salience_score, 0.5)::real DESC, e.child_turn_id
+                    "#
+                )
+                .bind(id.as_uuid())
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(rows.iter()
             .map(|row| TurnId::new(row.get("child_turn_id")))
@@ -333,23 +597,27 @@ impl GraphStore for PostgresGraphStore {
     }
 
     async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
-        let rows = sqlx::query(
-            r#"
-            SELECT mt.id, mt.salience_score
-            FROM memory_turns mt
-            JOIN memory_turn_edges e ON e.child_turn_id = mt.id
-            WHERE e.parent_turn_id IN (
-                SELECT parent_turn_id FROM memory_turn_edges WHERE child_turn_id = $1
-            )
-            AND mt.id != $1
-            ORDER BY mt.salience_score DESC, mt.id
-            LIMIT $2
-            "#
-        )
-        .bind(id.as_uuid())
-        .bind(limit as i32)
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT mt.id, mt.salience_score
+                    FROM memory_turns mt
+                    JOIN memory_turn_edges e ON e.child_turn_id = mt.id
+                    WHERE e.parent_turn_id IN (
+                        SELECT parent_turn_id FROM memory_turn_edges WHERE child_turn_id = $1
+                    )
+                    AND mt.id != $1
+                    ORDER BY mt.salience_score DESC, mt.id
+                    LIMIT $2
+                    "#
+                )
+                .bind(id.as_uuid())
+                .bind(limit as i32)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(rows.iter()
             .map(|row| TurnId::new(row.get("id")))
@@ -358,17 +626,21 @@ impl GraphStore for PostgresGraphStore {
 
     async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error> {
         let uuids: Vec<Uuid> = turn_ids.iter().map(|id| id.as_uuid()).collect();
-        let rows = sqlx::query(
-            r#"
-            SELECT parent_turn_id, child_turn_id, edge_type
-            FROM memory_turn_edges
-            WHERE parent_turn_id = ANY($1) AND child_turn_id = ANY($1)
-            ORDER BY parent_turn_id, child_turn_id
-            "#
-        )
-        .bind(&uuids)
-        .fetch_all(&self.pool)
-        .await?;
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT parent_turn_id, child_turn_id, edge_type
+                    FROM memory_turn_edges
+                    WHERE parent_turn_id = ANY($1) AND child_turn_id = ANY($1)
+                    ORDER BY parent_turn_id, child_turn_id
+                    "#
+                )
+                .bind(&uuids)
+                .fetch_all(&self.pool)
+            })
+            .await?;
 
         Ok(rows.iter()
             .map(|row| {
@@ -386,5 +658,211 @@ impl GraphStore for PostgresGraphStore {
             })
             .collect())
     }
+
+    async fn get_incident_edges(&self, id: &TurnId) -> Result<Vec<Edge>, Self::Error> {
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT parent_turn_id, child_turn_id, edge_type
+                    FROM memory_turn_edges
+                    WHERE parent_turn_id = $1 OR child_turn_id = $1
+                    ORDER BY parent_turn_id, child_turn_id
+                    "#
+                )
+                .bind(id.as_uuid())
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(rows.iter()
+            .map(|row| {
+                let parent: Uuid = row.get("parent_turn_id");
+                let child: Uuid = row.get("child_turn_id");
+                let edge_type_str: Option<String> = row.get("edge_type");
+
+                Edge::new(
+                    TurnId::new(parent),
+                    TurnId::new(child),
+                    edge_type_str
+                        .and_then(|s| EdgeType::from_str(&s))
+                        .unwrap_or_default(),
+                )
+            })
+            .collect())
+    }
+
+    async fn turns_by_phase(&self, phase: Phase, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT id
+                    FROM memory_turns
+                    WHERE LOWER(phase) = $1
+                    ORDER BY salience_score DESC, id
+                    LIMIT $2
+                    "#
+                )
+                .bind(phase.to_string())
+                .bind(limit as i32)
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(rows.iter()
+            .map(|row| TurnId::new(row.get("id")))
+            .collect())
+    }
+
+    async fn recent_turns(&self, session_id: &str, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        // Sessions are conversation IDs; anything else matches no turns
+        let conversation_id = match Uuid::parse_str(session_id) {
+            Ok(id) => id,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let rows = self
+            .retry
+            .run(|| {
+                sqlx::query(
+                    r#"
+                    SELECT id
+                    FROM memory_turns
+                    WHERE conversation_id = $1
+                    ORDER BY created_at DESC, id DESC
+                    LIMIT $2
+                    "#
+                )
+                .bind(conversation_id)
+                .bind(limit as i32)
+                .fetch_all(&self.pool)
+            })
+            .await?;
+
+        Ok(rows.iter()
+            .map(|row| TurnId::new(row.get("id")))
+            .collect())
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay: Duration::from_millis(1),
+        }
+    }
+
+    fn connection_reset() -> sqlx::Error {
+        sqlx::Error::Io(std::io::ErrorKind::ConnectionReset.into())
+    }
+
+    #[tokio::test]
+    async fn test_retry_recovers_from_transient_error() {
+        let attempts = AtomicU32::new(0);
+        let result = policy(2)
+            .run(|| async {
+                if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(connection_reset())
+                } else {
+                    Ok(42)
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_after_max_retries() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = policy(3)
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(sqlx::Error::PoolTimedOut)
+            })
+            .await;
+
+        assert!(matches!(result, Err(sqlx::Error::PoolTimedOut)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_permanent_error_is_not_retried() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = policy(3)
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(sqlx::Error::RowNotFound)
+            })
+            .await;
+
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_backoff_doubles() {
+        let policy = RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(50),
+        };
+        assert_eq!(policy.delay(0), Duration::from_millis(50));
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_statement_timeout_config() {
+        let config = PostgresConfig {
+            statement_timeout_secs: None,
+            ..PostgresConfig::from_env()
+        };
+        assert_eq!(config.statement_timeout_sql(), None);
+
+        let config = PostgresConfig {
+            statement_timeout_secs: Some(5),
+            ..config
+        };
+        assert_eq!(
+            config.statement_timeout_sql().as_deref(),
+            Some("SET statement_timeout = '5s'")
+        );
+    }
+
+    /// Requires PostgreSQL: `DATABASE_URL=... cargo test --features postgres -- --ignored`
+    #[tokio::test]
+    #[ignore]
+    async fn test_statement_timeout_cuts_off_slow_query() {
+        let store = PostgresGraphStore::new(PostgresConfig {
+            min_connections: 0,
+            statement_timeout_secs: Some(1),
+            ..PostgresConfig::from_env()
+        })
+        .await
+        .expect("DATABASE_URL must point at a reachable PostgreSQL");
+
+        let started = std::time::Instant::now();
+        let err = sqlx::query("SELECT pg_sleep(5)")
+            .execute(store.pool())
+            .await
+            .unwrap_err();
+
+        assert!(is_statement_timeout(&err), "unexpected error: {err}");
+        assert!(is_transient(&err));
+        assert!(started.elapsed() < Duration::from_secs(4));
+
+        // Surfaced through the store as its own retryable variant
+        let err = PostgresError::from(err);
+        assert!(matches!(err, PostgresError::StatementTimeout(_)), "unexpected error: {err}");
+        assert!(err.is_transient());
+    }
+}
@@ -0,0 +1,269 @@
+//! Graph store that shards turns across several backends.
+//!
+//! Each turn lives on the shard chosen by a user-supplied `shard_fn`. An
+//! edge may be stored on either endpoint's shard (or both), so adjacency
+//! and edge queries fan out to every shard and merge the results. Merged
+//! results are re-sorted with the same keys the single-store contract
+//! uses, so a sharded graph slices identically to the same graph held in
+//! one store.
+
+use async_trait::async_trait;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Arc;
+
+use super::GraphStore;
+use crate::types::{Edge, Phase, TurnId, TurnSnapshot};
+
+type ShardFn = dyn Fn(&TurnId) -> usize + Send + Sync;
+
+/// Graph store routing turns to one of several backends.
+///
+/// Point lookups (`get_turn`, `get_turns`) go to the owning shard only.
+/// Everything else fans out to all shards sequentially and merges.
+pub struct ShardedGraphStore<S: GraphStore> {
+    shards: Vec<S>,
+    shard_fn: Arc<ShardFn>,
+}
+
+impl<S: GraphStore> ShardedGraphStore<S> {
+    /// Create a sharded store.
+    ///
+    /// `shard_fn` may return any index; it is reduced modulo the number of
+    /// shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is empty.
+    pub fn new(
+        shards: Vec<S>,
+        shard_fn: impl Fn(&TurnId) -> usize + Send + Sync + 'static,
+    ) -> Self {
+        assert!(!shards.is_empty(), "ShardedGraphStore needs at least one shard");
+        Self {
+            shards,
+            shard_fn: Arc::new(shard_fn),
+        }
+    }
+
+    /// Index of the shard owning `id`.
+    pub fn shard_for(&self, id: &TurnId) -> usize {
+        (self.shard_fn)(id) % self.shards.len()
+    }
+
+    /// The backing shards, in index order.
+    pub fn shards(&self) -> &[S] {
+        &self.shards
+    }
+
+    /// Union of per-shard ID lists, sorted by TurnId.
+    async fn union_ids<'a, F, Fut>(&'a self, query: F) -> Result<BTreeSet<TurnId>, S::Error>
+    where
+        F: Fn(&'a S) -> Fut,
+        Fut: std::future::Future<Output = Result<Vec<TurnId>, S::Error>>,
+    {
+        let mut ids = BTreeSet::new();
+        for shard in &self.shards {
+            ids.extend(query(shard).await?);
+        }
+        Ok(ids)
+    }
+}
+
+impl<S: GraphStore> std::fmt::Debug for ShardedGraphStore<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShardedGraphStore")
+            .field("shards", &self.shards.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sort by salience descending (total order, as `InMemoryGraphStore`), then TurnId.
+fn sort_by_salience(turns: &mut [TurnSnapshot]) {
+    turns.sort_by(|a, b| {
+        b.salience.total_cmp(&a.salience).then_with(|| a.id.cmp(&b.id))
+    });
+}
+
+#[async_trait]
+impl<S: GraphStore> GraphStore for ShardedGraphStore<S> {
+    type Error = S::Error;
+
+    async fn get_turn(&self, id: &TurnId) -> Result<Option<TurnSnapshot>, Self::Error> {
+        self.shards[self.shard_for(id)].get_turn(id).await
+    }
+
+    async fn get_turns(&self, ids: &[TurnId]) -> Result<Vec<TurnSnapshot>, Self::Error> {
+        let mut by_shard: Vec<Vec<TurnId>> = vec![Vec::new(); self.shards.len()];
+        for id in ids {
+            by_shard[self.shard_for(id)].push(*id);
+        }
+
+        let mut found: HashMap<TurnId, TurnSnapshot> = HashMap::new();
+        for (shard, shard_ids) in self.shards.iter().zip(&by_shard) {
+            if shard_ids.is_empty() {
+                continue;
+            }
+            for turn in shard.get_turns(shard_ids).await? {
+                found.insert(turn.id, turn);
+            }
+        }
+
+        // Preserve the caller's order, as a single store would
+        Ok(ids.iter().filter_map(|id| found.get(id).cloned()).collect())
+    }
+
+    async fn get_parents(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+        Ok(self.union_ids(|s| s.get_parents(id)).await?.into_iter().collect())
+    }
+
+    async fn get_children(&self, id: &TurnId) -> Result<Vec<TurnId>, Self::Error> {
+        Ok(self.union_ids(|s| s.get_children(id)).await?.into_iter().collect())
+    }
+
+    async fn get_siblings(&self, id: &TurnId, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        // Per-shard siblings miss edges stored elsewhere, so rebuild from
+        // the merged parent and child sets
+        let mut siblings: BTreeSet<TurnId> = BTreeSet::new();
+        for parent in self.get_parents(id).await? {
+            siblings.extend(self.get_children(&parent).await?);
+        }
+        siblings.remove(id);
+
+        let ids: Vec<TurnId> = siblings.into_iter().collect();
+        let mut turns = self.get_turns(&ids).await?;
+        sort_by_salience(&mut turns);
+
+        Ok(turns.into_iter().take(limit).map(|t| t.id).collect())
+    }
+
+    async fn get_edges(&self, turn_ids: &[TurnId]) -> Result<Vec<Edge>, Self::Error> {
+        let mut edges = Vec::new();
+        for shard in &self.shards {
+            edges.extend(shard.get_edges(turn_ids).await?);
+        }
+
+        // An edge stored on both endpoint shards is reported once
+        edges.sort();
+        edges.dedup();
+        Ok(edges)
+    }
+
+    async fn turns_by_phase(&self, phase: Phase, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        // Each shard's top `limit` covers the global top `limit`
+        let ids: Vec<TurnId> = self
+            .union_ids(|s| s.turns_by_phase(phase, limit))
+            .await?
+            .into_iter()
+            .collect();
+        let mut turns = self.get_turns(&ids).await?;
+        sort_by_salience(&mut turns);
+
+        Ok(turns.into_iter().take(limit).map(|t| t.id).collect())
+    }
+
+    async fn recent_turns(&self, session_id: &str, limit: usize) -> Result<Vec<TurnId>, Self::Error> {
+        let ids: Vec<TurnId> = self
+            .union_ids(|s| s.recent_turns(session_id, limit))
+            .await?
+            .into_iter()
+            .collect();
+        let mut turns = self.get_turns(&ids).await?;
+        turns.sort_by(|a, b| {
+            b.created_at
+                .cmp(&a.created_at)
+                .then_with(|| b.id.cmp(&a.id))
+        });
+
+        Ok(turns.into_iter().take(limit).map(|t| t.id).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::SlicePolicyV1;
+    use crate::slicer::ContextSlicer;
+    use crate::store::InMemoryGraphStore;
+    use crate::types::{EdgeType, Role};
+    use uuid::Uuid;
+
+    fn make_turn(id: u128, salience: f32) -> TurnSnapshot {
+        TurnSnapshot::new(
+            TurnId::new(Uuid::from_u128(id)),
+            "session_1".to_string(),
+            Role::User,
+            Phase::Consolidation,
+            salience,
+            1,
+            0,
+            0.5,
+            0.5,
+            1.0,
+            1000 + id as i64,
+        )
+    }
+
+    fn parity(id: &TurnId) -> usize {
+        (id.as_uuid().as_u128() % 2) as usize
+    }
+
+    #[tokio::test]
+    async fn test_sharded_slice_matches_single_store() {
+        // Tree: 1 -> {2, 3, 4}, 2 -> {5, 6}; every edge crosses or stays
+        // within a shard depending on parity
+        let turns: Vec<TurnSnapshot> = (1..=6).map(|n| make_turn(n, 0.1 * n as f32)).collect();
+        let edges: Vec<Edge> = [(1, 2), (1, 3), (1, 4), (2, 5), (2, 6)]
+            .iter()
+            .map(|&(p, c)| {
+                Edge::new(
+                    TurnId::new(Uuid::from_u128(p)),
+                    TurnId::new(Uuid::from_u128(c)),
+                    EdgeType::Reply,
+                )
+            })
+            .collect();
+
+        let mut single = InMemoryGraphStore::new();
+        let mut shards = vec![InMemoryGraphStore::new(), InMemoryGraphStore::new()];
+        for turn in &turns {
+            single.add_turn(turn.clone());
+            shards[parity(&turn.id)].add_turn(turn.clone());
+        }
+        for edge in &edges {
+            single.add_edge(edge.clone());
+            // Cross-shard edges live on the child's shard only
+            shards[parity(&edge.child)].add_edge(edge.clone());
+        }
+        let sharded = ShardedGraphStore::new(shards, parity);
+
+        let anchor = TurnId::new(Uuid::from_u128(2));
+        assert_eq!(
+            sharded.get_siblings(&anchor, 10).await.unwrap(),
+            single.get_siblings(&anchor, 10).await.unwrap()
+        );
+        let all: Vec<TurnId> = turns.iter().map(|t| t.id).collect();
+        assert_eq!(
+            sharded.get_edges(&all).await.unwrap(),
+            single.get_edges(&all).await.unwrap()
+        );
+        assert_eq!(
+            sharded.recent_turns("session_1", 4).await.unwrap(),
+            single.recent_turns("session_1", 4).await.unwrap()
+        );
+
+        let policy = SlicePolicyV1::default();
+        let expected = ContextSlicer::new_for_test(Arc::new(single), policy.clone())
+            .slice(anchor)
+            .await
+            .unwrap();
+        let slicer = ContextSlicer::new_for_test(Arc::new(sharded), policy);
+        let first = slicer.slice(anchor).await.unwrap();
+        let second = slicer.slice(anchor).await.unwrap();
+
+        // The slice spans both shards and matches the unsharded graph
+        let shards_hit: BTreeSet<usize> = first.turn_ids().iter().map(parity).collect();
+        assert_eq!(shards_hit.len(), 2);
+        assert_eq!(first.slice_id(), expected.slice_id());
+        assert_eq!(first.slice_id(), second.slice_id());
+    }
+}
@@ -0,0 +1,267 @@
+//! Deterministic graph generation for property tests, plus a serialization
+//! round-trip check for slices.
+//!
+//! Available under `cfg(test)` and the `test-support` feature so downstream
+//! crates can fuzz against the same graphs.
+//!
+//! ## Construction
+//!
+//! Every random choice is drawn from `xxh64(counter, seed)` with an
+//! incrementing counter, so a seed reproduces the exact graph on any
+//! platform without an external RNG.
+//!
+//! - Turn IDs are built from two consecutive draws
+//! - Turn `i > 0` gets a `Reply` edge from a uniformly chosen earlier turn,
+//!   keeping the graph connected
+//! - Extra `Branch`/`Reference` edges from earlier turns bring the mean
+//!   in-degree up to `avg_fanout`; edges only point forward, so the result
+//!   is a DAG
+
+use uuid::Uuid;
+use xxhash_rust::xxh64::xxh64;
+
+use crate::store::InMemoryGraphStore;
+use crate::types::{Edge, EdgeType, Phase, Role, SliceExport, SliceFingerprint, TurnId, TurnSnapshot};
+
+/// Seeded stream of deterministic pseudo-random values.
+struct HashStream {
+    seed: u64,
+    counter: u64,
+}
+
+impl HashStream {
+    fn new(seed: u64) -> Self {
+        Self { seed, counter: 0 }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = xxh64(&self.counter.to_le_bytes(), self.seed);
+        self.counter += 1;
+        value
+    }
+
+    /// Uniform value in `0..bound` (`bound` must be non-zero).
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    /// Uniform value in `[0, 1)`.
+    fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Generate a deterministic DAG of `num_turns` turns.
+///
+/// `avg_fanout` is the target mean number of parents per non-root turn
+/// (values below 1.0 are treated as 1.0). The same arguments always yield
+/// the same turns and edges.
+pub fn gen_graph(seed: u64, num_turns: usize, avg_fanout: f32) -> InMemoryGraphStore {
+    let mut rng = HashStream::new(seed);
+    let mut store = InMemoryGraphStore::new();
+
+    const ROLES: [Role; 4] = [Role::User, Role::Assistant, Role::System, Role::Tool];
+    const PHASES: [Phase; 5] = [
+        Phase::Exploration,
+        Phase::Debugging,
+        Phase::Planning,
+        Phase::Consolidation,
+        Phase::Synthesis,
+    ];
+
+    let mut ids = Vec::with_capacity(num_turns);
+    let mut depths: Vec<u32> = Vec::with_capacity(num_turns);
+
+    for i in 0..num_turns {
+        let id = TurnId::new(Uuid::from_u128(
+            ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128,
+        ));
+
+        // Primary parent keeps the graph connected
+        let (depth, primary_parent) = if i == 0 {
+            (0, None)
+        } else {
+            let parent = rng.below(i as u64) as usize;
+            (depths[parent] + 1, Some(parent))
+        };
+
+        let turn = TurnSnapshot::new(
+            id,
+            format!("session_{}", seed),
+            ROLES[rng.below(ROLES.len() as u64) as usize],
+            PHASES[rng.below(PHASES.len() as u64) as usize],
+            rng.unit() as f32,
+            depth,
+            0,
+            rng.unit() as f32,
+            i as f32 / num_turns as f32,
+            rng.unit() as f32,
+            1_700_000_000 + i as i64,
+        );
+        store.add_turn(turn);
+
+        if let Some(parent) = primary_parent {
+            store.add_edge(Edge::new(ids[parent], id, EdgeType::Reply));
+
+            // Extra parents: floor(fanout - 1) plus one more with the fractional probability
+            let extra = (avg_fanout.max(1.0) - 1.0) as f64;
+            let mut count = extra.floor() as usize;
+            if rng.unit() < extra.fract() {
+                count += 1;
+            }
+
+            let mut linked = vec![parent];
+            for _ in 0..count.min(i - 1) {
+                let candidate = rng.below(i as u64) as usize;
+                if linked.contains(&candidate) {
+                    continue;
+                }
+                linked.push(candidate);
+                let edge_type = if rng.below(2) == 0 { EdgeType::Branch } else { EdgeType::Reference };
+                store.add_edge(Edge::new(ids[candidate], id, edge_type));
+            }
+        }
+
+        ids.push(id);
+        depths.push(depth);
+    }
+
+    store
+}
+
+/// Why a slice failed `assert_roundtrip_stable`.
+#[derive(Debug, thiserror::Error)]
+pub enum RoundtripError {
+    /// The slice could not be serialized.
+    #[error("Failed to serialize slice {slice_id}: {source}")]
+    Serialize {
+        /// The slice being checked.
+        slice_id: SliceFingerprint,
+        /// Underlying serde error.
+        source: serde_json::Error,
+    },
+    /// The serialized slice could not be read back.
+    #[error("Failed to deserialize slice {slice_id}: {source}")]
+    Deserialize {
+        /// The slice being checked.
+        slice_id: SliceFingerprint,
+        /// Underlying serde error.
+        source: serde_json::Error,
+    },
+    /// The round-tripped contents fingerprint to a different slice_id.
+    #[error("slice_id {expected} recomputed as {recomputed} after a JSON round trip")]
+    SliceIdMismatch {
+        /// The slice_id carried by the input slice.
+        expected: SliceFingerprint,
+        /// The slice_id recomputed from the round-tripped contents.
+        recomputed: SliceFingerprint,
+    },
+}
+
+/// Check that a slice survives a JSON round trip with its identity intact.
+///
+/// Serializes `slice`, deserializes it, restores canonical turn and edge
+/// order, and recomputes the fingerprint under the round-tripped
+/// `canonical_version`. Fails unless it equals the input's `slice_id`, so a
+/// slice whose contents were altered after issuance also fails.
+pub fn assert_roundtrip_stable(slice: &SliceExport) -> Result<(), RoundtripError> {
+    let json = serde_json::to_string(slice).map_err(|source| RoundtripError::Serialize {
+        slice_id: slice.slice_id.clone(),
+        source,
+    })?;
+    let mut parsed: SliceExport =
+        serde_json::from_str(&json).map_err(|source| RoundtripError::Deserialize {
+            slice_id: slice.slice_id.clone(),
+            source,
+        })?;
+
+    parsed.turns.sort();
+    parsed.edges.sort();
+    parsed.edges.dedup();
+    let recomputed = SliceExport::compute_fingerprint(
+        &parsed.anchor_turn_id,
+        &parsed.turns,
+        &parsed.edges,
+        &parsed.policy_id,
+        &parsed.policy_params_hash,
+        parsed.canonical_version,
+    );
+
+    if recomputed != slice.slice_id {
+        return Err(RoundtripError::SliceIdMismatch {
+            expected: slice.slice_id.clone(),
+            recomputed,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::SlicePolicyV1;
+    use crate::slicer::ContextSlicer;
+    use proptest::prelude::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_gen_graph_reproducible() {
+        let a = gen_graph(42, 50, 2.0);
+        let b = gen_graph(42, 50, 2.0);
+        let c = gen_graph(43, 50, 2.0);
+
+        let ids = |s: &InMemoryGraphStore| s.all_turns().iter().map(|t| t.id).collect::<Vec<_>>();
+        assert_eq!(ids(&a), ids(&b));
+        assert_eq!(a.all_edges(), b.all_edges());
+        assert_ne!(ids(&a), ids(&c));
+
+        assert_eq!(a.num_turns(), 50);
+        assert!(a.num_edges() >= 49);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn prop_slice_determinism(seed in any::<u64>(), num_turns in 1usize..60, fanout in 1.0f32..3.0) {
+            // Two independently generated copies of the same graph
+            let store1 = Arc::new(gen_graph(seed, num_turns, fanout));
+            let store2 = Arc::new(gen_graph(seed, num_turns, fanout));
+            let anchor = store1.all_turns()[(seed % num_turns as u64) as usize].id;
+
+            let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+            let (first, second) = runtime.block_on(async {
+                let slicer1 = ContextSlicer::new_for_test(store1, SlicePolicyV1::default());
+                let slicer2 = ContextSlicer::new_for_test(store2, SlicePolicyV1::default());
+                (slicer1.slice(anchor).await.unwrap(), slicer2.slice(anchor).await.unwrap())
+            });
+
+            prop_assert_eq!(first.slice_id(), second.slice_id());
+            prop_assert!(first.is_turn_admissible(&anchor));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_roundtrip_stable_detects_corruption() {
+        let store = Arc::new(gen_graph(7, 30, 2.0));
+        let anchor = store.all_turns()[5].id;
+        let slice = ContextSlicer::new_for_test(store, SlicePolicyV1::default())
+            .slice(anchor)
+            .await
+            .unwrap()
+            .slice()
+            .clone();
+
+        assert!(assert_roundtrip_stable(&slice).is_ok());
+
+        // Dropping a turn after issuance changes the selection
+        let mut corrupted = slice.clone();
+        corrupted.turns.retain(|t| t.id == anchor);
+        let err = assert_roundtrip_stable(&corrupted).unwrap_err();
+        assert!(matches!(
+            &err,
+            RoundtripError::SliceIdMismatch { expected, .. } if *expected == slice.slice_id
+        ));
+        assert!(err.to_string().contains(slice.slice_id.as_str()));
+    }
+}
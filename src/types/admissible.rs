@@ -27,8 +27,12 @@
 //! unverified evidence. The type system enforces kernel authorization.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use super::edge::Edge;
 use super::slice::{SliceExport, SliceFingerprint, GraphSnapshotHash, AdmissibilityToken};
 use super::turn::TurnId;
+use super::boundary::BoundaryViolation;
+use crate::store::GraphStore;
 
 /// Error type for admissibility verification.
 #[derive(Debug, thiserror::Error)]
@@ -46,6 +50,69 @@ pub enum VerificationError {
     IncompleteProvenance(String),
 }
 
+/// Why a bundle failed a freshness check.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FreshnessError {
+    /// The slice was taken from a different graph state than expected.
+    #[error("Graph snapshot mismatch: expected {expected}, slice has {actual}")]
+    SnapshotMismatch {
+        /// Snapshot hash the caller expected.
+        expected: GraphSnapshotHash,
+        /// Snapshot hash recorded in the slice.
+        actual: GraphSnapshotHash,
+    },
+    /// The bundle is older than the allowed age.
+    #[error("Evidence is stale: {age_ms}ms old, maximum is {max_age_ms}ms")]
+    Stale {
+        /// Age of the evidence at the time of the check.
+        age_ms: i64,
+        /// Maximum allowed age.
+        max_age_ms: i64,
+    },
+}
+
+/// Graph state and verification time of a bundle, for recency checks.
+///
+/// Produced by [`AdmissibleEvidenceBundle::freshness_proof`]; ties the
+/// slice's content-immutability proof to when it was verified.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FreshnessProof {
+    /// Graph state the slice was taken from.
+    pub graph_snapshot_hash: GraphSnapshotHash,
+    /// When the bundle was verified (Unix ms).
+    pub verified_at_unix_ms: i64,
+}
+
+/// Characters assumed per turn when no content is available for it.
+///
+/// Roughly one medium-length conversational message.
+pub const DEFAULT_TURN_CHARS: usize = 400;
+
+/// Estimated context footprint of a bundle.
+///
+/// Produced by [`AdmissibleEvidenceBundle::estimate_context_size`] so LLM
+/// callers can budget a slice before injecting it as context.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ContextSizeEstimate {
+    /// Total characters across all turns.
+    pub total_chars: usize,
+    /// Estimated token count (`total_chars / chars_per_token`, rounded up).
+    pub estimated_tokens: usize,
+    /// Number of turns counted.
+    pub turn_count: usize,
+}
+
+/// One entry of an OpenAI-style chat messages array.
+///
+/// Produced by [`AdmissibleEvidenceBundle::to_chat_messages`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    /// Chat role (`user`, `assistant`, `system` or `tool`).
+    pub role: String,
+    /// Message text.
+    pub content: String,
+}
+
 /// Admissible evidence bundle - cryptographically verified slice.
 ///
 /// This type represents a `SliceExport` that has passed HMAC token verification.
@@ -128,15 +195,7 @@ impl AdmissibleEvidenceBundle {
         }
 
         // Verify HMAC token
-        let is_valid = slice.admissibility_token.verify_hmac(
-            hmac_secret,
-            &slice.slice_id,
-            &slice.anchor_turn_id,
-            &slice.policy_id,
-            &slice.policy_params_hash,
-            &slice.graph_snapshot_hash,
-            &slice.schema_version,
-        );
+        let is_valid = slice.verify_token(hmac_secret);
 
         if !is_valid {
             return Err(VerificationError::TokenMismatch);
@@ -206,21 +265,197 @@ impl AdmissibleEvidenceBundle {
         self.slice.filter_admissible(turn_ids)
     }
 
+    /// Split turn IDs into admissible and rejected, preserving input order.
+    ///
+    /// Unlike `filter_admissible`, the out-of-slice IDs are returned so
+    /// callers can report **INV-GK-001** violations instead of hiding them.
+    pub fn partition_admissible(&self, turn_ids: &[TurnId]) -> (Vec<TurnId>, Vec<TurnId>) {
+        turn_ids.iter().partition(|id| self.is_turn_admissible(id))
+    }
+
+    /// Build a violation report for the rejected IDs, if any.
+    ///
+    /// Returns `None` when every ID is admissible. Call `log()` on the
+    /// result to emit it as a security event.
+    pub fn boundary_violation(
+        &self,
+        turn_ids: &[TurnId],
+        context: Option<String>,
+    ) -> Option<BoundaryViolation> {
+        let (_, rejected) = self.partition_admissible(turn_ids);
+        if rejected.is_empty() {
+            return None;
+        }
+
+        Some(BoundaryViolation {
+            slice_fingerprint: self.slice.slice_id.as_str().to_string(),
+            unauthorized_ids: rejected,
+            timestamp: chrono::Utc::now(),
+            context,
+        })
+    }
+
+    /// Fetch the edges that cross the slice boundary.
+    ///
+    /// Returns edges with exactly one endpoint in the slice, sorted
+    /// canonically. The slice itself only carries internal edges; this is
+    /// for context-completeness analysis and does not change the bundle.
+    pub async fn boundary_edges<S: GraphStore + ?Sized>(&self, store: &S) -> Result<Vec<Edge>, S::Error> {
+        let mut outside = BTreeSet::new();
+        for turn in &self.slice.turns {
+            let parents = store.get_parents(&turn.id).await?;
+            let children = store.get_children(&turn.id).await?;
+            outside.extend(parents.into_iter().chain(children).filter(|id| !self.is_turn_admissible(id)));
+        }
+        if outside.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<TurnId> = self.turn_ids().into_iter().chain(outside).collect();
+        let mut edges: Vec<Edge> = store
+            .get_edges(&ids)
+            .await?
+            .into_iter()
+            .filter(|e| self.is_turn_admissible(&e.parent) != self.is_turn_admissible(&e.child))
+            .collect();
+        edges.sort();
+        Ok(edges)
+    }
+
     /// Get the timestamp when this bundle was verified.
     pub fn verified_at_unix_ms(&self) -> i64 {
         self.verified_at_unix_ms
     }
 
+    /// Get the timestamp when the kernel issued the slice, if stamped.
+    pub fn issued_at_unix_ms(&self) -> Option<i64> {
+        self.slice.issued_at_unix_ms
+    }
+
+    /// Check whether this evidence is older than `max_age` at `now_unix_ms`.
+    ///
+    /// Age is measured from the slice's issue time, falling back to the
+    /// verification time for slices issued without one. Promotion pipelines
+    /// should reject stale bundles.
+    pub fn is_stale(&self, max_age: std::time::Duration, now_unix_ms: i64) -> bool {
+        let reference = self.slice.issued_at_unix_ms.unwrap_or(self.verified_at_unix_ms);
+        let max_age_ms = i64::try_from(max_age.as_millis()).unwrap_or(i64::MAX);
+        now_unix_ms.saturating_sub(reference) > max_age_ms
+    }
+
+    /// Render the slice as an OpenAI-style chat messages array.
+    ///
+    /// Turns are ordered by `created_at`, then TurnId, and take their text
+    /// from `content`. Turns with no entry in `content` are skipped; the
+    /// second value is how many were skipped.
+    pub fn to_chat_messages(&self, content: &HashMap<TurnId, String>) -> (Vec<ChatMessage>, usize) {
+        let mut turns: Vec<_> = self.slice.turns.iter().collect();
+        turns.sort_by_key(|t| (t.created_at, t.id));
+
+        let mut skipped = 0;
+        let mut messages = Vec::with_capacity(turns.len());
+        for turn in turns {
+            match content.get(&turn.id) {
+                Some(text) => messages.push(ChatMessage {
+                    role: turn.role.chat_role().to_string(),
+                    content: text.clone(),
+                }),
+                None => skipped += 1,
+            }
+        }
+        (messages, skipped)
+    }
+
+    /// Get the graph snapshot and verification time of this bundle.
+    pub fn freshness_proof(&self) -> FreshnessProof {
+        FreshnessProof {
+            graph_snapshot_hash: self.slice.graph_snapshot_hash.clone(),
+            verified_at_unix_ms: self.verified_at_unix_ms,
+        }
+    }
+
+    /// Check that this evidence comes from `expected_snapshot` and is no
+    /// older than `max_age` at `now_unix_ms`.
+    ///
+    /// The snapshot is checked first. Age is measured as in `is_stale`.
+    /// Returns the freshness proof on success.
+    pub fn verify_freshness(
+        &self,
+        expected_snapshot: &GraphSnapshotHash,
+        max_age: std::time::Duration,
+        now_unix_ms: i64,
+    ) -> Result<FreshnessProof, FreshnessError> {
+        if &self.slice.graph_snapshot_hash != expected_snapshot {
+            return Err(FreshnessError::SnapshotMismatch {
+                expected: expected_snapshot.clone(),
+                actual: self.slice.graph_snapshot_hash.clone(),
+            });
+        }
+        if self.is_stale(max_age, now_unix_ms) {
+            let reference = self.slice.issued_at_unix_ms.unwrap_or(self.verified_at_unix_ms);
+            return Err(FreshnessError::Stale {
+                age_ms: now_unix_ms.saturating_sub(reference),
+                max_age_ms: i64::try_from(max_age.as_millis()).unwrap_or(i64::MAX),
+            });
+        }
+        Ok(self.freshness_proof())
+    }
+
     /// Get the number of turns in the bundle.
     pub fn num_turns(&self) -> usize {
         self.slice.num_turns()
     }
 
+    /// Number of turns whose stored phase was missing or unrecognized.
+    pub fn unknown_phase_count(&self) -> usize {
+        self.slice.turns.iter().filter(|t| t.phase_unknown).count()
+    }
+
     /// Get the schema version.
     pub fn schema_version(&self) -> &str {
         &self.slice.schema_version
     }
 
+    /// Estimate the context footprint of this bundle without content.
+    ///
+    /// Every turn is assumed to be [`DEFAULT_TURN_CHARS`] long.
+    /// See [`Self::estimate_context_size_with_content`] for exact counts.
+    pub fn estimate_context_size(&self, chars_per_token: f32) -> ContextSizeEstimate {
+        self.estimate_context_size_with_content(chars_per_token, &HashMap::new())
+    }
+
+    /// Estimate the context footprint of this bundle from turn content.
+    ///
+    /// Characters are counted as Unicode scalar values. Turns missing from
+    /// `content` fall back to [`DEFAULT_TURN_CHARS`]. A non-positive
+    /// `chars_per_token` is treated as 1.0.
+    pub fn estimate_context_size_with_content(
+        &self,
+        chars_per_token: f32,
+        content: &HashMap<TurnId, String>,
+    ) -> ContextSizeEstimate {
+        let total_chars: usize = self
+            .slice
+            .turns
+            .iter()
+            .map(|t| {
+                content
+                    .get(&t.id)
+                    .map(|text| text.chars().count())
+                    .unwrap_or(DEFAULT_TURN_CHARS)
+            })
+            .sum();
+
+        let chars_per_token = if chars_per_token > 0.0 { chars_per_token } else { 1.0 };
+        let estimated_tokens = (total_chars as f64 / chars_per_token as f64).ceil() as usize;
+
+        ContextSizeEstimate {
+            total_chars,
+            estimated_tokens,
+            turn_count: self.slice.turns.len(),
+        }
+    }
+
     /// Extract provenance metadata for audit/replay.
     ///
     /// Returns a tuple of:
@@ -249,7 +484,7 @@ impl AdmissibleEvidenceBundle {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{TurnSnapshot, Role, Phase};
+    use crate::types::{EdgeType, TurnSnapshot, Role, Phase};
     use uuid::Uuid;
 
     fn make_turn(id: u128) -> TurnSnapshot {
@@ -389,6 +624,36 @@ mod tests {
         assert!(filtered.contains(&TurnId::new(Uuid::from_u128(2))));
     }
 
+    #[test]
+    fn test_partition_admissible_reports_rejected() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let slice = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            vec![make_turn(1), make_turn(2)],
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret).unwrap();
+
+        let candidates = vec![id(999), id(2), id(500), id(1)];
+        let (admissible, rejected) = bundle.partition_admissible(&candidates);
+        assert_eq!(admissible, vec![id(2), id(1)]);
+        assert_eq!(rejected, vec![id(999), id(500)]);
+
+        let violation = bundle
+            .boundary_violation(&candidates, Some("retrieval".to_string()))
+            .expect("rejected IDs produce a violation");
+        assert_eq!(violation.slice_fingerprint, bundle.slice_id().as_str());
+        assert_eq!(violation.unauthorized_ids, rejected);
+        assert_eq!(violation.context.as_deref(), Some("retrieval"));
+
+        assert!(bundle.boundary_violation(&[id(1), id(2)], None).is_none());
+    }
+
     #[test]
     fn test_provenance_extraction() {
         let secret = b"test_kernel_secret_32_bytes_min!";
@@ -438,4 +703,235 @@ mod tests {
         let verified_at = bundle.verified_at_unix_ms();
         assert!(verified_at >= before && verified_at <= after);
     }
+
+    #[test]
+    fn test_stale_and_fresh_bundles() {
+        use std::time::Duration;
+
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let anchor = TurnId::new(Uuid::from_u128(1));
+        let slice = SliceExport::new_with_secret(
+            secret,
+            anchor,
+            vec![make_turn(1)],
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+        let unstamped_id = slice.slice_id.clone();
+
+        let issued_at = 1_700_000_000_000;
+        let stamped = slice.with_issued_at(secret, Some(issued_at));
+        assert_eq!(stamped.slice_id, unstamped_id);
+
+        let bundle = AdmissibleEvidenceBundle::from_verified(stamped.clone(), secret).unwrap();
+        assert_eq!(bundle.issued_at_unix_ms(), Some(issued_at));
+
+        let hour = Duration::from_secs(3600);
+        assert!(!bundle.is_stale(hour, issued_at + 1_000));
+        assert!(!bundle.is_stale(hour, issued_at + 3_600_000));
+        assert!(bundle.is_stale(hour, issued_at + 3_600_001));
+
+        // The issue time is bound into the token
+        let mut backdated = stamped;
+        backdated.issued_at_unix_ms = Some(issued_at + 86_400_000);
+        assert!(matches!(
+            AdmissibleEvidenceBundle::from_verified(backdated, secret),
+            Err(VerificationError::TokenMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_unstamped_bundle_ages_from_verification() {
+        use std::time::Duration;
+
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let slice = SliceExport::new_with_secret(
+            secret,
+            TurnId::new(Uuid::from_u128(1)),
+            vec![make_turn(1)],
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret).unwrap();
+        assert_eq!(bundle.issued_at_unix_ms(), None);
+
+        let verified_at = bundle.verified_at_unix_ms();
+        assert!(!bundle.is_stale(Duration::from_secs(60), verified_at));
+        assert!(bundle.is_stale(Duration::from_secs(60), verified_at + 60_001));
+    }
+
+    #[test]
+    fn test_to_chat_messages() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let turn = |id: u128, role: Role, created_at: i64| {
+            TurnSnapshot::new(
+                TurnId::new(Uuid::from_u128(id)),
+                "session_test".to_string(),
+                role,
+                Phase::Synthesis,
+                0.8,
+                1,
+                0,
+                0.5,
+                0.5,
+                1.0,
+                created_at,
+            )
+        };
+        // TurnId order differs from chronological order; 3 and 4 tie on time
+        let turns = vec![
+            turn(1, Role::Assistant, 2000),
+            turn(2, Role::System, 1000),
+            turn(3, Role::Tool, 3000),
+            turn(4, Role::User, 3000),
+            turn(5, Role::User, 1500),
+        ];
+        let slice = SliceExport::new_with_secret(
+            secret,
+            TurnId::new(Uuid::from_u128(1)),
+            turns,
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret).unwrap();
+
+        let content: HashMap<TurnId, String> = [1, 2, 3, 4]
+            .into_iter()
+            .map(|n| (TurnId::new(Uuid::from_u128(n)), format!("turn {}", n)))
+            .collect();
+        let (messages, skipped) = bundle.to_chat_messages(&content);
+
+        assert_eq!(skipped, 1);
+        let rendered: Vec<(&str, &str)> = messages
+            .iter()
+            .map(|m| (m.role.as_str(), m.content.as_str()))
+            .collect();
+        assert_eq!(
+            rendered,
+            vec![
+                ("system", "turn 2"),
+                ("assistant", "turn 1"),
+                ("tool", "turn 3"),
+                ("user", "turn 4"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_verify_freshness() {
+        use std::time::Duration;
+
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let snapshot = GraphSnapshotHash::new("test_snapshot".to_string());
+        let slice = SliceExport::new_with_secret(
+            secret,
+            TurnId::new(Uuid::from_u128(1)),
+            vec![make_turn(1)],
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            snapshot.clone(),
+        );
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret).unwrap();
+        let verified_at = bundle.verified_at_unix_ms();
+        let minute = Duration::from_secs(60);
+
+        // Matching and fresh
+        let proof = bundle.verify_freshness(&snapshot, minute, verified_at + 1_000).unwrap();
+        assert_eq!(proof, bundle.freshness_proof());
+        assert_eq!(proof.graph_snapshot_hash, snapshot);
+        assert_eq!(proof.verified_at_unix_ms, verified_at);
+
+        // Matching but stale
+        assert_eq!(
+            bundle.verify_freshness(&snapshot, minute, verified_at + 60_001),
+            Err(FreshnessError::Stale { age_ms: 60_001, max_age_ms: 60_000 })
+        );
+
+        // Snapshot mismatch, even when fresh
+        let current = GraphSnapshotHash::new("newer_snapshot".to_string());
+        assert!(matches!(
+            bundle.verify_freshness(&current, minute, verified_at),
+            Err(FreshnessError::SnapshotMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_estimate_context_size() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let anchor = TurnId::new(Uuid::from_u128(1));
+        let turns = vec![make_turn(1), make_turn(2), make_turn(3)];
+        let snapshot = GraphSnapshotHash::new("test_snapshot".to_string());
+
+        let slice = SliceExport::new_with_secret(
+            secret,
+            anchor,
+            turns,
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            snapshot,
+        );
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret).unwrap();
+
+        let mut content = HashMap::new();
+        content.insert(TurnId::new(Uuid::from_u128(1)), "hello world".to_string()); // 11
+        content.insert(TurnId::new(Uuid::from_u128(2)), "héllo".to_string()); // 5 chars, 6 bytes
+
+        let estimate = bundle.estimate_context_size_with_content(4.0, &content);
+        assert_eq!(estimate.turn_count, 3);
+        assert_eq!(estimate.total_chars, 11 + 5 + DEFAULT_TURN_CHARS);
+        assert_eq!(estimate.estimated_tokens, (11 + 5 + DEFAULT_TURN_CHARS).div_ceil(4));
+
+        // Without content every turn uses the default
+        let estimate = bundle.estimate_context_size(4.0);
+        assert_eq!(estimate.total_chars, 3 * DEFAULT_TURN_CHARS);
+        assert_eq!(estimate.estimated_tokens, 3 * DEFAULT_TURN_CHARS / 4);
+    }
+
+    #[tokio::test]
+    async fn test_boundary_edges_linear_graph() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        // 1 -> 2 -> 3 -> 4 -> 5, slice covers 2..=4
+        let mut store = crate::store::InMemoryGraphStore::new();
+        for n in 1..=5 {
+            store.add_turn(make_turn(n));
+        }
+        for n in 1..5 {
+            store.add_edge(Edge::new(id(n), id(n + 1), EdgeType::Reply));
+        }
+
+        let slice = SliceExport::new_with_secret(
+            secret,
+            id(3),
+            vec![make_turn(2), make_turn(3), make_turn(4)],
+            vec![
+                Edge::new(id(2), id(3), EdgeType::Reply),
+                Edge::new(id(3), id(4), EdgeType::Reply),
+            ],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret).unwrap();
+
+        let boundary = bundle.boundary_edges(&store).await.unwrap();
+        assert_eq!(
+            boundary,
+            vec![
+                Edge::new(id(1), id(2), EdgeType::Reply),
+                Edge::new(id(4), id(5), EdgeType::Reply),
+            ]
+        );
+        assert_eq!(bundle.slice().edges.len(), 2);
+    }
 }
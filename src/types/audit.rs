@@ -0,0 +1,66 @@
+//! Append-only audit trail for issued slices.
+//!
+//! `ContextSlicer` reports every bundle it issues to an `AuditSink` after
+//! the bundle has been created and verified, and records the incidents it
+//! raises (such as policy version mismatches). Sinks only observe: they run
+//! after the slice is fixed, so they cannot affect `slice_id` or the token.
+
+use std::sync::Mutex;
+
+use super::admissible::AdmissibleEvidenceBundle;
+use super::incident::Incident;
+
+/// Receiver for slice issuance events and incidents.
+///
+/// Implementations should be append-only (database table, WORM storage,
+/// log shipper). Recording is infallible from the slicer's point of view;
+/// sinks that can fail should handle or report errors themselves.
+pub trait AuditSink: Send + Sync {
+    /// Record that a slice was issued. The default does nothing.
+    fn record_slice_issued(&self, _bundle: &AdmissibleEvidenceBundle) {}
+
+    /// Record an incident the slicer raised. The default does nothing.
+    fn record_incident(&self, _incident: &Incident) {}
+}
+
+/// Audit sink that discards all events (the slicer default).
+#[derive(Debug, Default)]
+pub struct NoOpAuditSink;
+
+impl AuditSink for NoOpAuditSink {}
+
+/// In-memory audit sink for testing.
+#[derive(Debug, Default)]
+pub struct InMemoryAuditSink {
+    /// Issued slice IDs in the order they were recorded.
+    pub slice_ids: Mutex<Vec<String>>,
+    /// Incidents in the order they were recorded.
+    pub incidents: Mutex<Vec<Incident>>,
+}
+
+impl InMemoryAuditSink {
+    /// Create an empty sink.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Snapshot of recorded slice IDs, oldest first.
+    pub fn entries(&self) -> Vec<String> {
+        self.slice_ids.lock().unwrap().clone()
+    }
+
+    /// Snapshot of recorded incidents, oldest first.
+    pub fn incidents(&self) -> Vec<Incident> {
+        self.incidents.lock().unwrap().clone()
+    }
+}
+
+impl AuditSink for InMemoryAuditSink {
+    fn record_slice_issued(&self, bundle: &AdmissibleEvidenceBundle) {
+        self.slice_ids.lock().unwrap().push(bundle.slice_id().to_string());
+    }
+
+    fn record_incident(&self, incident: &Incident) {
+        self.incidents.lock().unwrap().push(incident.clone());
+    }
+}
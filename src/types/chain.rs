@@ -0,0 +1,147 @@
+//! Hash chain over an ordered sequence of slices.
+//!
+//! Each link commits to the previous link's hash and the appended slice's
+//! `slice_id`, so the head hash commits to the whole sequence in order.
+//! Inserting, removing, or reordering any slice changes every later hash,
+//! which makes a stored chain a tamper-evident log of issued slices.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::slice::{SliceExport, SliceFingerprint};
+
+/// Chain hash of an empty chain: 64 zero hex digits.
+pub const GENESIS_CHAIN_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// One entry in a `SliceChain`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainLink {
+    /// The appended slice.
+    pub slice_id: SliceFingerprint,
+    /// `SHA-256(prev_chain_hash ++ slice_id)`, hex encoded.
+    pub chain_hash: String,
+}
+
+/// Append-only, order-sensitive hash chain of slice IDs.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SliceChain {
+    links: Vec<ChainLink>,
+}
+
+impl SliceChain {
+    /// Create an empty chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a slice and return the new head hash.
+    pub fn append(&mut self, slice: &SliceExport) -> &str {
+        let chain_hash = Self::link_hash(self.head(), &slice.slice_id);
+        self.links.push(ChainLink {
+            slice_id: slice.slice_id.clone(),
+            chain_hash,
+        });
+        self.head()
+    }
+
+    /// Hash of the latest link, or `GENESIS_CHAIN_HASH` if empty.
+    pub fn head(&self) -> &str {
+        self.links
+            .last()
+            .map_or(GENESIS_CHAIN_HASH, |link| link.chain_hash.as_str())
+    }
+
+    /// Links in append order.
+    pub fn links(&self) -> &[ChainLink] {
+        &self.links
+    }
+
+    /// Number of slices in the chain.
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    /// Check if the chain has no slices.
+    pub fn is_empty(&self) -> bool {
+        self.links.is_empty()
+    }
+
+    /// Recompute every link and check it matches the stored hash.
+    ///
+    /// Detects edits to a deserialized chain's slice IDs or hashes.
+    pub fn verify(&self) -> bool {
+        let mut prev = GENESIS_CHAIN_HASH;
+        for link in &self.links {
+            if Self::link_hash(prev, &link.slice_id) != link.chain_hash {
+                return false;
+            }
+            prev = &link.chain_hash;
+        }
+        true
+    }
+
+    /// `SHA-256(prev ++ slice_id)`. `prev` is always 64 hex digits, so the
+    /// concatenation is unambiguous.
+    fn link_hash(prev: &str, slice_id: &SliceFingerprint) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(prev.as_bytes());
+        hasher.update(slice_id.as_str().as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Phase, Role, TurnId, TurnSnapshot};
+    use uuid::Uuid;
+
+    fn make_slice(n: u128) -> SliceExport {
+        let anchor = TurnId::new(Uuid::from_u128(n));
+        SliceExport::new_for_test(
+            anchor,
+            vec![TurnSnapshot::new(
+                anchor,
+                "session_1".to_string(),
+                Role::User,
+                Phase::Synthesis,
+                0.5,
+                1,
+                0,
+                0.5,
+                0.5,
+                1.0,
+                1000,
+            )],
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_chain_is_order_sensitive() {
+        let (a, b) = (make_slice(1), make_slice(2));
+
+        let mut forward = SliceChain::new();
+        assert_eq!(forward.head(), GENESIS_CHAIN_HASH);
+        forward.append(&a);
+        forward.append(&b);
+
+        let mut replay = SliceChain::new();
+        replay.append(&a);
+        replay.append(&b);
+        assert_eq!(replay.head(), forward.head());
+
+        let mut reversed = SliceChain::new();
+        reversed.append(&b);
+        reversed.append(&a);
+        assert_ne!(reversed.head(), forward.head());
+
+        assert!(forward.verify());
+        let mut tampered = forward.clone();
+        tampered.links[0].slice_id = b.slice_id.clone();
+        assert!(!tampered.verify());
+    }
+}
@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 use super::turn::TurnId;
 
 /// Type of edge in the conversation DAG.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum EdgeType {
     /// Direct reply/continuation.
     Reply,
@@ -13,25 +13,34 @@ pub enum EdgeType {
     /// Reference to earlier turn.
     Reference,
     /// Default/unspecified.
+    #[default]
     Default,
+    /// Symmetric association between turns; orientation is not meaningful.
+    Related,
 }
 
 impl EdgeType {
     /// Parse edge type from string.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "reply" => Some(Self::Reply),
             "branch" => Some(Self::Branch),
             "reference" => Some(Self::Reference),
             "default" | "" => Some(Self::Default),
+            "related" => Some(Self::Related),
             _ => None,
         }
     }
 }
 
-impl Default for EdgeType {
-    fn default() -> Self {
-        Self::Default
+impl EdgeType {
+    /// Whether the edge's orientation carries meaning.
+    ///
+    /// `Related` is a symmetric relation that stores may record either
+    /// way round; every other type points from parent to child.
+    pub fn is_directed(&self) -> bool {
+        !matches!(self, Self::Related)
     }
 }
 
@@ -42,6 +51,7 @@ impl std::fmt::Display for EdgeType {
             Self::Branch => write!(f, "branch"),
             Self::Reference => write!(f, "reference"),
             Self::Default => write!(f, "default"),
+            Self::Related => write!(f, "related"),
         }
     }
 }
@@ -74,6 +84,19 @@ impl Edge {
     pub fn reply(parent: TurnId, child: TurnId) -> Self {
         Self::new(parent, child, EdgeType::Reply)
     }
+
+    /// Normalize the orientation of undirected edges.
+    ///
+    /// Undirected edges (see `EdgeType::is_directed`) are rewritten so the
+    /// smaller TurnId is the parent, making `(a, b)` and `(b, a)` equal.
+    /// Directed edges are returned unchanged.
+    pub fn canonicalize(self) -> Self {
+        if self.edge_type.is_directed() || self.parent <= self.child {
+            self
+        } else {
+            Self::new(self.child, self.parent, self.edge_type)
+        }
+    }
 }
 
 // Canonical ordering: parent, then child, then edge_type
@@ -128,5 +151,27 @@ mod tests {
         // EdgeType ordering matters when parent and child are equal
         assert!(e1 != e2);
     }
+
+    #[test]
+    fn test_canonicalize_undirected_only() {
+        let id1 = TurnId::new(Uuid::from_u128(1));
+        let id2 = TurnId::new(Uuid::from_u128(2));
+
+        // A related edge recorded either way round collapses to one edge
+        let forward = Edge::new(id1, id2, EdgeType::Related).canonicalize();
+        let backward = Edge::new(id2, id1, EdgeType::Related).canonicalize();
+        assert_eq!(forward, backward);
+        assert_eq!(backward.parent, id1);
+
+        // A reference points at an earlier turn and keeps its orientation
+        let reference = Edge::new(id2, id1, EdgeType::Reference).canonicalize();
+        assert_eq!(reference.parent, id2);
+
+        // A reply keeps its orientation
+        let reply = Edge::reply(id2, id1).canonicalize();
+        assert_eq!(reply.parent, id2);
+        assert_eq!(reply.child, id1);
+        assert_ne!(reply, Edge::reply(id1, id2).canonicalize());
+    }
 }
 
@@ -23,7 +23,7 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use super::turn::TurnId;
 
@@ -117,6 +117,17 @@ pub enum IncidentType {
         /// New params hash.
         new_hash: String,
     },
+    /// Slicing under different policy semantics than a prior slice (INV-GK-007).
+    PolicyVersionMismatch {
+        /// Policy ID being sliced under.
+        policy_id: String,
+        /// Semantics version the prior slice was produced with.
+        prior_version: String,
+        /// Semantics version of the current build.
+        current_version: String,
+        /// Whether the versions are semver-compatible.
+        compatible: bool,
+    },
     /// Generic security incident.
     Other {
         /// Description of the incident.
@@ -134,6 +145,8 @@ impl IncidentType {
             Self::TokenVerificationFailure { .. } => Severity::Critical,
             Self::SqlBoundaryBypass { .. } => Severity::Critical,
             Self::PolicyMutation { .. } => Severity::High,
+            Self::PolicyVersionMismatch { compatible: true, .. } => Severity::Low,
+            Self::PolicyVersionMismatch { compatible: false, .. } => Severity::High,
             Self::Other { .. } => Severity::Medium,
         }
     }
@@ -147,6 +160,7 @@ impl IncidentType {
             Self::TokenVerificationFailure { .. } => "INV-GK-005",
             Self::SqlBoundaryBypass { .. } => "INV-GK-008",
             Self::PolicyMutation { .. } => "INV-GK-007",
+            Self::PolicyVersionMismatch { .. } => "INV-GK-007",
             Self::Other { .. } => "UNKNOWN",
         }
     }
@@ -160,6 +174,7 @@ impl IncidentType {
             Self::TokenVerificationFailure { .. } => "graph_kernel_token_verification_failures_total",
             Self::SqlBoundaryBypass { .. } => "graph_kernel_sql_boundary_bypass_total",
             Self::PolicyMutation { .. } => "graph_kernel_policy_mutations_total",
+            Self::PolicyVersionMismatch { .. } => "graph_kernel_policy_version_mismatches_total",
             Self::Other { .. } => "graph_kernel_other_incidents_total",
         }
     }
@@ -231,6 +246,47 @@ impl Incident {
             self.incident_type.invariant()
         );
     }
+
+    /// Render this incident as a single flat JSON line for SIEM ingestion.
+    ///
+    /// Keys are sorted. Incident type fields are prefixed with `type.` and
+    /// context entries with `context.`, so nothing is nested. The line has
+    /// no trailing newline.
+    pub fn to_json_line(&self) -> String {
+        use serde_json::Value;
+
+        let mut fields: BTreeMap<String, Value> = BTreeMap::new();
+        fields.insert("id".to_string(), Value::from(self.id.clone()));
+        fields.insert("timestamp".to_string(), Value::from(self.timestamp.to_rfc3339()));
+        fields.insert("invariant".to_string(), Value::from(self.incident_type.invariant()));
+        fields.insert("severity".to_string(), Value::from(self.severity.to_string()));
+        fields.insert("metric".to_string(), Value::from(self.incident_type.metric_name()));
+        fields.insert("source".to_string(), Value::from(self.source.clone()));
+        fields.insert("acknowledged".to_string(), Value::from(self.acknowledged));
+        if let Some(at) = &self.acknowledged_at {
+            fields.insert("acknowledged_at".to_string(), Value::from(at.to_rfc3339()));
+        }
+        if let Some(by) = &self.acknowledged_by {
+            fields.insert("acknowledged_by".to_string(), Value::from(by.clone()));
+        }
+
+        // The type is internally tagged, so it serializes to an object with a "type" key
+        if let Ok(Value::Object(type_fields)) = serde_json::to_value(&self.incident_type) {
+            for (key, value) in type_fields {
+                if key == "type" {
+                    fields.insert(key, value);
+                } else {
+                    fields.insert(format!("type.{}", key), value);
+                }
+            }
+        }
+
+        for (key, value) in &self.context {
+            fields.insert(format!("context.{}", key), Value::from(value.clone()));
+        }
+
+        serde_json::to_string(&fields).expect("incident fields serialize")
+    }
 }
 
 /// A quarantined token that failed verification.
@@ -300,14 +356,6 @@ pub trait IncidentMetrics: Send + Sync {
 
     /// Record an incident.
     fn record_incident(&self, incident: &Incident) {
-        let labels = [
-            ("severity", incident.severity.to_string().as_str()),
-            ("invariant", incident.incident_type.invariant()),
-            ("source", incident.source.as_str()),
-        ];
-
-        // This won't compile as-is due to lifetime issues with to_string()
-        // In real implementation, use static strings or owned labels
         self.increment(incident.incident_type.metric_name(), &[
             ("severity", match incident.severity {
                 Severity::Low => "low",
@@ -539,4 +587,34 @@ mod tests {
         };
         assert_eq!(token.metric_name(), "graph_kernel_token_verification_failures_total");
     }
+
+    #[test]
+    fn test_incident_json_line() {
+        let incident = Incident::new(
+            IncidentType::SliceBoundaryViolation {
+                slice_fingerprint: "fp".to_string(),
+                unauthorized_count: 3,
+            },
+            "promotion_api",
+        )
+        .with_context("turn", "abc");
+
+        let line = incident.to_json_line();
+        assert!(!line.contains('\n'));
+
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(value["invariant"], "INV-GK-001");
+        assert_eq!(value["severity"], "CRITICAL");
+        assert_eq!(value["type"], "slice_boundary_violation");
+        assert_eq!(value["type.unauthorized_count"], 3);
+        assert_eq!(value["source"], "promotion_api");
+        assert_eq!(value["context.turn"], "abc");
+
+        // Keys are emitted in sorted order
+        let keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+        assert!(line.starts_with("{\"acknowledged\":false,"));
+    }
 }
@@ -9,14 +9,18 @@ pub mod sufficiency;
 pub mod boundary;
 pub mod provenance;
 pub mod incident;
+pub mod audit;
+pub mod chain;
 
 pub use turn::{TurnId, TurnSnapshot, Role, Phase, ContentHashError};
 pub use edge::{Edge, EdgeType};
-pub use slice::{SliceExport, SliceFingerprint, GraphSnapshotHash, AdmissibilityToken};
-pub use admissible::{AdmissibleEvidenceBundle, VerificationError};
-pub use verification::{TokenVerifier, VerificationMode, VerificationResult, CacheConfig, CacheStats};
+pub use slice::{SliceExport, SelfDescribingSlice, SliceFingerprint, GraphSnapshotHash, AdmissibilityToken, AdmissionReason, DEDUP_SELECTION_PREFIX, EXTERNAL_SELECTION_PREFIX, PRUNED_SELECTION_PREFIX};
+pub use admissible::{AdmissibleEvidenceBundle, VerificationError, ContextSizeEstimate, ChatMessage, FreshnessProof, FreshnessError};
+pub use verification::{TokenVerifier, VerificationMode, VerificationResult, FailureKind, CacheConfig, CacheStats, SecretResolver};
+#[cfg(feature = "remote-verify")]
+pub use verification::RemoteEndpointError;
 pub use sufficiency::{
-    DiversityMetrics, SalienceStats, SufficiencyPolicy, SufficiencyCheck,
+    DiversityAccumulator, DiversityMetrics, SalienceStats, SufficiencyPolicy, SufficiencyCheck,
     SufficiencyViolation, EvidenceBundle, EvidenceBundleError,
 };
 pub use boundary::{
@@ -31,4 +35,5 @@ pub use incident::{
     IncidentMetrics, NoOpMetrics, TestMetrics,
     QUARANTINE_TABLE_SCHEMA, INCIDENT_TABLE_SCHEMA,
 };
-
+pub use audit::{AuditSink, NoOpAuditSink, InMemoryAuditSink};
+pub use chain::{SliceChain, ChainLink, GENESIS_CHAIN_HASH};
@@ -27,6 +27,7 @@ use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 
 use super::slice::GraphSnapshotHash;
+use super::turn::TurnId;
 
 /// Reference to an embedding model with full version info.
 ///
@@ -202,6 +203,9 @@ pub struct ReplayProvenance {
     pub graph_snapshot: GraphSnapshotHash,
     /// The resulting slice fingerprint.
     pub slice_fingerprint: String,
+    /// Anchor the slice was built around (required to replay it).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor_turn_id: Option<TurnId>,
     /// Query vector hash (for query reproduction).
     pub query_vector_hash: Option<String>,
     /// Additional metadata.
@@ -271,6 +275,7 @@ pub struct ProvenanceBuilder {
     retrieval_params: Option<RetrievalParams>,
     graph_snapshot: Option<GraphSnapshotHash>,
     slice_fingerprint: Option<String>,
+    anchor_turn_id: Option<TurnId>,
     query_vector_hash: Option<String>,
     metadata: std::collections::HashMap<String, String>,
 }
@@ -311,6 +316,12 @@ impl ProvenanceBuilder {
         self
     }
 
+    /// Set the anchor turn the slice was built around.
+    pub fn anchor_turn_id(mut self, anchor: TurnId) -> Self {
+        self.anchor_turn_id = Some(anchor);
+        self
+    }
+
     /// Set the query vector hash.
     pub fn query_vector_hash(mut self, hash: impl Into<String>) -> Self {
         self.query_vector_hash = Some(hash.into());
@@ -343,6 +354,7 @@ impl ProvenanceBuilder {
             retrieval_params,
             graph_snapshot,
             slice_fingerprint,
+            anchor_turn_id: self.anchor_turn_id,
             query_vector_hash: self.query_vector_hash,
             metadata: self.metadata,
         })
@@ -8,10 +8,13 @@
 //! 4. **Replay**: Requires `(slice_id, graph_snapshot_hash, query_embedding_hash)` match
 
 use serde::{Deserialize, Serialize};
-use super::turn::{TurnId, TurnSnapshot};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use super::turn::{Phase, TurnId, TurnSnapshot};
 use super::edge::Edge;
 use crate::canonical::canonical_hash_hex;
-use crate::GRAPH_KERNEL_SCHEMA_VERSION;
+use crate::policy::{PolicyVersion, SlicePolicyV1};
+use crate::slicer::selection_snapshot_hash;
+use crate::{GRAPH_KERNEL_SCHEMA_VERSION, CANONICAL_SLICE_VERSION, LEGACY_CANONICAL_SLICE_VERSION};
 
 /// Fingerprint of a slice for provenance tracking.
 ///
@@ -135,6 +138,9 @@ impl AdmissibilityToken {
     const TOKEN_VERSION: &'static str = "admissibility_token_v2_hmac";
 
     /// Build the canonical string for HMAC computation.
+    ///
+    /// The issue time is appended only when present, so tokens for slices
+    /// without one are unchanged.
     fn canonical_string(
         slice_id: &SliceFingerprint,
         anchor_turn_id: &TurnId,
@@ -142,8 +148,9 @@ impl AdmissibilityToken {
         policy_params_hash: &str,
         graph_snapshot_hash: &GraphSnapshotHash,
         schema_version: &str,
+        issued_at_unix_ms: Option<i64>,
     ) -> String {
-        format!(
+        let mut canonical = format!(
             "{}|{}|{}|{}|{}|{}|{}",
             slice_id.as_str(),
             anchor_turn_id.as_uuid(),
@@ -152,7 +159,11 @@ impl AdmissibilityToken {
             graph_snapshot_hash.as_str(),
             schema_version,
             Self::TOKEN_VERSION,
-        )
+        );
+        if let Some(issued_at) = issued_at_unix_ms {
+            canonical.push_str(&format!("|issued_at={}", issued_at));
+        }
+        canonical
     }
 
     /// Issue a cryptographically signed admissibility token (kernel-only operation).
@@ -171,6 +182,32 @@ impl AdmissibilityToken {
         policy_params_hash: &str,
         graph_snapshot_hash: &GraphSnapshotHash,
         schema_version: &str,
+    ) -> Self {
+        Self::issue_hmac_at(
+            secret,
+            slice_id,
+            anchor_turn_id,
+            policy_id,
+            policy_params_hash,
+            graph_snapshot_hash,
+            schema_version,
+            None,
+        )
+    }
+
+    /// Issue a token that also binds the slice's issue time.
+    ///
+    /// With `issued_at_unix_ms = None` this is identical to `issue_hmac`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue_hmac_at(
+        secret: &[u8],
+        slice_id: &SliceFingerprint,
+        anchor_turn_id: &TurnId,
+        policy_id: &str,
+        policy_params_hash: &str,
+        graph_snapshot_hash: &GraphSnapshotHash,
+        schema_version: &str,
+        issued_at_unix_ms: Option<i64>,
     ) -> Self {
         use hmac::{Hmac, Mac};
         use sha2::Sha256;
@@ -182,6 +219,7 @@ impl AdmissibilityToken {
             policy_params_hash,
             graph_snapshot_hash,
             schema_version,
+            issued_at_unix_ms,
         );
 
         let mut mac = Hmac::<Sha256>::new_from_slice(secret)
@@ -200,6 +238,7 @@ impl AdmissibilityToken {
     /// # Arguments
     /// * `secret` - The kernel's HMAC secret (shared with verifier)
     /// * Other parameters must match exactly what was used to issue the token
+    #[allow(clippy::too_many_arguments)]
     pub fn verify_hmac(
         &self,
         secret: &[u8],
@@ -209,6 +248,33 @@ impl AdmissibilityToken {
         policy_params_hash: &str,
         graph_snapshot_hash: &GraphSnapshotHash,
         schema_version: &str,
+    ) -> bool {
+        self.verify_hmac_at(
+            secret,
+            slice_id,
+            anchor_turn_id,
+            policy_id,
+            policy_params_hash,
+            graph_snapshot_hash,
+            schema_version,
+            None,
+        )
+    }
+
+    /// Verify a token issued with `issue_hmac_at`.
+    ///
+    /// `issued_at_unix_ms` must match the value the token was issued with.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_hmac_at(
+        &self,
+        secret: &[u8],
+        slice_id: &SliceFingerprint,
+        anchor_turn_id: &TurnId,
+        policy_id: &str,
+        policy_params_hash: &str,
+        graph_snapshot_hash: &GraphSnapshotHash,
+        schema_version: &str,
+        issued_at_unix_ms: Option<i64>,
     ) -> bool {
         use hmac::{Hmac, Mac};
         use sha2::Sha256;
@@ -220,6 +286,7 @@ impl AdmissibilityToken {
             policy_params_hash,
             graph_snapshot_hash,
             schema_version,
+            issued_at_unix_ms,
         );
 
         let mut mac = Hmac::<Sha256>::new_from_slice(secret)
@@ -287,6 +354,67 @@ impl std::fmt::Display for AdmissibilityToken {
     }
 }
 
+/// Prefix on the `policy_id` of slices signed for a client-built selection.
+///
+/// The kernel signs such selections without running expansion, so they must
+/// not pass for slicer output under the plain policy ID.
+pub const EXTERNAL_SELECTION_PREFIX: &str = "external:";
+
+/// Prefix on the `policy_id` of slices derived by `SliceExport::dedup_by_content`.
+///
+/// The prefix is followed by the source `slice_id`, a `:`, and the source
+/// `policy_id`, so the derivation is covered by `slice_id` and the token.
+pub const DEDUP_SELECTION_PREFIX: &str = "dedup:";
+
+/// Prefix on the `policy_id` of slices derived by `SliceExport::prune_to`.
+///
+/// Laid out like `DEDUP_SELECTION_PREFIX`.
+pub const PRUNED_SELECTION_PREFIX: &str = "pruned:";
+
+/// Derivation markers that may lead a `policy_id`, outermost first.
+const DERIVED_SELECTION_PREFIXES: &[&str] = &[DEDUP_SELECTION_PREFIX, PRUNED_SELECTION_PREFIX];
+
+/// `policy_id` of a selection derived from `source` under `prefix`.
+fn derived_policy_id(prefix: &str, source: &SliceFingerprint, policy_id: &str) -> String {
+    format!("{}{}:{}", prefix, source.as_str(), policy_id)
+}
+
+/// Source slice recorded under `prefix` in a derived `policy_id`.
+///
+/// Walks the derivation markers outermost first and returns the first one
+/// with `prefix`.
+fn derived_from(policy_id: &str, prefix: &str) -> Option<SliceFingerprint> {
+    let mut rest = policy_id;
+    loop {
+        let (marker, tail) = DERIVED_SELECTION_PREFIXES
+            .iter()
+            .find_map(|p| rest.strip_prefix(p).map(|tail| (*p, tail)))?;
+        let (source, tail) = tail.split_once(':')?;
+        if marker == prefix {
+            return Some(SliceFingerprint::new(source.to_string()));
+        }
+        rest = tail;
+    }
+}
+
+/// How a turn entered a slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdmissionReason {
+    /// The slice's anchor turn.
+    Anchor,
+    /// Reached as a parent of an admitted turn.
+    ParentHop,
+    /// Reached as a child of an admitted turn.
+    ChildHop,
+    /// Reached as a sibling of an admitted turn.
+    Sibling,
+    /// Added on the root-to-anchor path (`include_root_path`).
+    RootPath,
+    /// Added by `ContextSlicer::with_pinned_turns`.
+    Pinned,
+}
+
 /// Exported slice of the conversation DAG.
 ///
 /// Contains all information needed to:
@@ -324,13 +452,63 @@ pub struct SliceExport {
     pub graph_snapshot_hash: GraphSnapshotHash,
     /// Unforgeable admissibility claim from Graph Kernel.
     pub admissibility_token: AdmissibilityToken,
+    /// Slice this one was derived from by `dedup_by_content`, if any.
+    ///
+    /// Mirrors the `DEDUP_SELECTION_PREFIX` marker in `policy_id`; a slice
+    /// whose field disagrees with the marker fails verification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduped_from: Option<SliceFingerprint>,
+    /// Slice this one was derived from by `prune_to`, if any.
+    ///
+    /// Mirrors the `PRUNED_SELECTION_PREFIX` marker in `policy_id`, like
+    /// `deduped_from`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pruned_from: Option<SliceFingerprint>,
+    /// Why each turn was admitted, when sliced with
+    /// `ContextSlicer::with_turn_provenance`.
+    ///
+    /// Metadata only: excluded from `slice_id` and the token, so it can be
+    /// edited without failing verification. Treat it as advisory.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unverified_turn_provenance: Option<BTreeMap<TurnId, AdmissionReason>>,
+    /// Policy semantics version the slicer ran under, if stamped.
+    ///
+    /// Metadata only: excluded from `slice_id` and the token, so a holder
+    /// can change it without failing verification.
+    /// `ContextSlicer::scan_drift` compares it with the current semantics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy_semantics_version: Option<PolicyVersion>,
+    /// When the kernel issued this slice (Unix ms), if stamped.
+    ///
+    /// Metadata only: excluded from `slice_id`, but bound into the token
+    /// so it cannot be altered without invalidating it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issued_at_unix_ms: Option<i64>,
+    /// Canonical layout version hashed into `slice_id`.
+    ///
+    /// Slices serialized before this field existed deserialize as
+    /// `LEGACY_CANONICAL_SLICE_VERSION`.
+    #[serde(default = "legacy_canonical_version")]
+    pub canonical_version: u32,
+}
+
+fn legacy_canonical_version() -> u32 {
+    LEGACY_CANONICAL_SLICE_VERSION
 }
 
 impl SliceExport {
+    /// `anchor_eccentricity` of a slice with turns unreachable from the anchor.
+    pub const DISCONNECTED_ECCENTRICITY: u32 = u32::MAX;
+
     /// Create a new slice export with HMAC-signed admissibility token.
     ///
     /// This is the production method. Requires the kernel's HMAC secret.
     ///
+    /// Turns and edges are sorted, and duplicate edges (same parent, child,
+    /// and edge type) are dropped. Rebuilding a slice from an edge list that
+    /// contained duplicates therefore yields a different `slice_id` than
+    /// kernels that kept them.
+    ///
     /// # Arguments
     /// * `hmac_secret` - The kernel's secret key for signing tokens
     /// * Other parameters define the slice content
@@ -343,9 +521,11 @@ impl SliceExport {
         policy_params_hash: String,
         graph_snapshot_hash: GraphSnapshotHash,
     ) -> Self {
-        // Sort for determinism
+        // Sort for determinism; a store returning an edge twice must not
+        // change num_edges or the fingerprint
         turns.sort();
         edges.sort();
+        edges.dedup();
 
         let schema_version = GRAPH_KERNEL_SCHEMA_VERSION.to_string();
 
@@ -356,6 +536,7 @@ impl SliceExport {
             &edges,
             &policy_id,
             &policy_params_hash,
+            CANONICAL_SLICE_VERSION,
         );
 
         // Issue HMAC-signed admissibility token
@@ -379,6 +560,12 @@ impl SliceExport {
             slice_id,
             graph_snapshot_hash,
             admissibility_token,
+            deduped_from: None,
+            pruned_from: None,
+            unverified_turn_provenance: None,
+            policy_semantics_version: None,
+            issued_at_unix_ms: None,
+            canonical_version: CANONICAL_SLICE_VERSION,
         }
     }
 
@@ -387,7 +574,7 @@ impl SliceExport {
     /// # Arguments
     /// * `hmac_secret` - The kernel's secret key for verification
     pub fn verify_token(&self, hmac_secret: &[u8]) -> bool {
-        self.admissibility_token.verify_hmac(
+        self.derivation_matches() && self.admissibility_token.verify_hmac_at(
             hmac_secret,
             &self.slice_id,
             &self.anchor_turn_id,
@@ -395,9 +582,35 @@ impl SliceExport {
             &self.policy_params_hash,
             &self.graph_snapshot_hash,
             &self.schema_version,
+            self.issued_at_unix_ms,
         )
     }
 
+    /// Whether `deduped_from` and `pruned_from` agree with the markers
+    /// signed into `policy_id`.
+    pub(crate) fn derivation_matches(&self) -> bool {
+        self.deduped_from == derived_from(&self.policy_id, DEDUP_SELECTION_PREFIX)
+            && self.pruned_from == derived_from(&self.policy_id, PRUNED_SELECTION_PREFIX)
+    }
+
+    /// Stamp the issue time and re-issue the token to bind it.
+    ///
+    /// `slice_id` is unchanged.
+    pub fn with_issued_at(mut self, hmac_secret: &[u8], issued_at_unix_ms: Option<i64>) -> Self {
+        self.issued_at_unix_ms = issued_at_unix_ms;
+        self.admissibility_token = AdmissibilityToken::issue_hmac_at(
+            hmac_secret,
+            &self.slice_id,
+            &self.anchor_turn_id,
+            &self.policy_id,
+            &self.policy_params_hash,
+            &self.graph_snapshot_hash,
+            &self.schema_version,
+            issued_at_unix_ms,
+        );
+        self
+    }
+
     /// Create a slice export for testing (uses legacy non-HMAC token).
     #[cfg(test)]
     #[allow(deprecated)]
@@ -410,6 +623,7 @@ impl SliceExport {
     ) -> Self {
         turns.sort();
         edges.sort();
+        edges.dedup();
 
         let schema_version = GRAPH_KERNEL_SCHEMA_VERSION.to_string();
         let graph_snapshot_hash = GraphSnapshotHash::new("test_snapshot".to_string());
@@ -420,6 +634,7 @@ impl SliceExport {
             &edges,
             &policy_id,
             &policy_params_hash,
+            CANONICAL_SLICE_VERSION,
         );
 
         let admissibility_token = AdmissibilityToken::issue_legacy(
@@ -441,31 +656,88 @@ impl SliceExport {
             slice_id,
             graph_snapshot_hash,
             admissibility_token,
+            deduped_from: None,
+            pruned_from: None,
+            unverified_turn_provenance: None,
+            policy_semantics_version: None,
+            issued_at_unix_ms: None,
+            canonical_version: CANONICAL_SLICE_VERSION,
         }
     }
 
-    /// Compute the slice fingerprint.
-    fn compute_fingerprint(
+    /// Compute the slice fingerprint under a canonical layout version.
+    ///
+    /// The legacy layout hashes the tuple without a version element; every
+    /// later layout appends its version, so slice_ids are namespaced by it.
+    pub(crate) fn compute_fingerprint(
         anchor: &TurnId,
         turns: &[TurnSnapshot],
         edges: &[Edge],
         policy_id: &str,
         policy_params_hash: &str,
+        canonical_version: u32,
     ) -> SliceFingerprint {
         // Extract just the turn IDs for hashing (not full snapshots)
         let turn_ids: Vec<_> = turns.iter().map(|t| t.id).collect();
 
         // Create canonical representation
-        let canonical = (
-            anchor,
-            &turn_ids,
-            edges,
-            policy_id,
-            policy_params_hash,
-            GRAPH_KERNEL_SCHEMA_VERSION,
+        let hash = if canonical_version == LEGACY_CANONICAL_SLICE_VERSION {
+            canonical_hash_hex(&(
+                anchor,
+                &turn_ids,
+                edges,
+                policy_id,
+                policy_params_hash,
+                GRAPH_KERNEL_SCHEMA_VERSION,
+            ))
+        } else {
+            canonical_hash_hex(&(
+                anchor,
+                &turn_ids,
+                edges,
+                policy_id,
+                policy_params_hash,
+                GRAPH_KERNEL_SCHEMA_VERSION,
+                canonical_version,
+            ))
+        };
+
+        SliceFingerprint::new(hash)
+    }
+
+    /// Re-issue this slice under a specific canonical layout version.
+    ///
+    /// Recomputes `slice_id` and the token. Use
+    /// `LEGACY_CANONICAL_SLICE_VERSION` to reproduce slice_ids issued before
+    /// the layout was versioned.
+    pub fn with_canonical_version(mut self, hmac_secret: &[u8], canonical_version: u32) -> Self {
+        self.canonical_version = canonical_version;
+        self.slice_id = Self::compute_fingerprint(
+            &self.anchor_turn_id,
+            &self.turns,
+            &self.edges,
+            &self.policy_id,
+            &self.policy_params_hash,
+            canonical_version,
         );
+        let issued_at = self.issued_at_unix_ms;
+        self.with_issued_at(hmac_secret, issued_at)
+    }
 
-        SliceFingerprint::new(canonical_hash_hex(&canonical))
+    /// Whether `slice_id` matches a fresh fingerprint of this slice's contents.
+    ///
+    /// Uses the slice's own `canonical_version`, so a slice produced under a
+    /// different layout is detected rather than silently mismatching.
+    pub fn fingerprint_matches(&self) -> bool {
+        self.canonical_version <= CANONICAL_SLICE_VERSION
+            && Self::compute_fingerprint(
+                &self.anchor_turn_id,
+                &self.turns,
+                &self.edges,
+                &self.policy_id,
+                &self.policy_params_hash,
+                self.canonical_version,
+            ) == self.slice_id
     }
 
     /// Get the number of turns in the slice.
@@ -488,20 +760,336 @@ impl SliceExport {
         self.turns.iter().find(|t| t.id == self.anchor_turn_id)
     }
 
+    /// Maximum hop distance from the anchor to any other turn in the slice.
+    ///
+    /// Breadth-first over the slice's own edges, ignoring direction. A
+    /// slice containing a turn the anchor cannot reach this way (e.g. one
+    /// added only as a sibling) returns `DISCONNECTED_ECCENTRICITY`.
+    pub fn anchor_eccentricity(&self) -> u32 {
+        let mut adjacency: HashMap<TurnId, Vec<TurnId>> = HashMap::new();
+        for edge in &self.edges {
+            adjacency.entry(edge.parent).or_default().push(edge.child);
+            adjacency.entry(edge.child).or_default().push(edge.parent);
+        }
+
+        let mut distances: HashMap<TurnId, u32> = HashMap::from([(self.anchor_turn_id, 0)]);
+        let mut queue = VecDeque::from([self.anchor_turn_id]);
+        while let Some(id) = queue.pop_front() {
+            let next = distances[&id] + 1;
+            for neighbor in adjacency.get(&id).into_iter().flatten() {
+                if !distances.contains_key(neighbor) {
+                    distances.insert(*neighbor, next);
+                    queue.push_back(*neighbor);
+                }
+            }
+        }
+
+        self.turns
+            .iter()
+            .map(|t| distances.get(&t.id).copied().unwrap_or(Self::DISCONNECTED_ECCENTRICITY))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Check that every turn carries a `content_hash`.
+    ///
+    /// Content-drift detection is meaningless for turns without a hash, so
+    /// consumers relying on it should reject slices that fail this check.
+    /// Returns the IDs of unhashed turns, in TurnId order.
+    pub fn require_content_hashes(&self) -> Result<(), Vec<TurnId>> {
+        let missing: Vec<TurnId> = self
+            .turns
+            .iter()
+            .filter(|t| !t.has_content_hash())
+            .map(|t| t.id)
+            .collect();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(missing)
+        }
+    }
+
     /// Verify the admissibility token is valid for this slice.
     ///
     /// Returns true if the token was issued by the kernel for these exact parameters.
-    /// Requires the HMAC secret that was used to issue the token.
+    /// Requires the HMAC secret that was used to issue the token. Same check
+    /// as `verify_token`, including a stamped `issued_at_unix_ms`.
     pub fn verify_admissibility(&self, hmac_secret: &[u8]) -> bool {
-        self.admissibility_token.verify_hmac(
+        self.verify_token(hmac_secret)
+    }
+
+    /// Collapse turns that share a `content_hash` into one representative.
+    ///
+    /// The lowest TurnId of each content hash is kept; edges (and the anchor)
+    /// pointing at a collapsed turn are rewritten to its representative, and
+    /// resulting self-loops and duplicate edges are dropped. Turns without a
+    /// content hash are never collapsed.
+    ///
+    /// The selection changes, so the result is signed under a derived
+    /// `policy_id` (see `DEDUP_SELECTION_PREFIX`) with `deduped_from` set to
+    /// this slice's `slice_id`, and its snapshot hash is recomputed over the
+    /// surviving turns. It never passes for slicer output.
+    pub fn dedup_by_content(&self, hmac_secret: &[u8]) -> SliceExport {
+        let mut ordered: Vec<&TurnSnapshot> = self.turns.iter().collect();
+        ordered.sort_by_key(|t| t.id);
+
+        let mut representatives: BTreeMap<&str, TurnId> = BTreeMap::new();
+        let mut collapsed: BTreeMap<TurnId, TurnId> = BTreeMap::new();
+        let mut turns = Vec::with_capacity(ordered.len());
+
+        for turn in ordered {
+            match turn.content_hash.as_deref() {
+                Some(hash) => match representatives.get(hash) {
+                    Some(representative) => {
+                        collapsed.insert(turn.id, *representative);
+                    }
+                    None => {
+                        representatives.insert(hash, turn.id);
+                        turns.push(turn.clone());
+                    }
+                },
+                None => turns.push(turn.clone()),
+            }
+        }
+
+        let resolve = |id: &TurnId| *collapsed.get(id).unwrap_or(id);
+
+        let mut edges: Vec<Edge> = self
+            .edges
+            .iter()
+            .map(|e| Edge::new(resolve(&e.parent), resolve(&e.child), e.edge_type))
+            .filter(|e| e.parent != e.child)
+            .collect();
+        edges.sort();
+        edges.dedup();
+
+        let graph_snapshot_hash = selection_snapshot_hash(&turns, edges.len());
+        let mut slice = Self::new_with_secret(
             hmac_secret,
-            &self.slice_id,
+            resolve(&self.anchor_turn_id),
+            turns,
+            edges,
+            derived_policy_id(DEDUP_SELECTION_PREFIX, &self.slice_id, &self.policy_id),
+            self.policy_params_hash.clone(),
+            graph_snapshot_hash,
+        );
+        slice.deduped_from = Some(self.slice_id.clone());
+        slice.pruned_from = self.pruned_from.clone();
+        slice.policy_semantics_version = self.policy_semantics_version;
+        slice.unverified_turn_provenance = self.unverified_turn_provenance.as_ref().map(|reasons| {
+            reasons
+                .iter()
+                .filter(|(id, _)| slice.contains_turn(id))
+                .map(|(id, reason)| (*id, *reason))
+                .collect()
+        });
+        slice.with_issued_at(hmac_secret, self.issued_at_unix_ms)
+    }
+
+    /// Shrink the slice to at most `max_turns` turns, keeping phase coverage.
+    ///
+    /// The anchor is always kept. Remaining turns are ranked within their
+    /// phase by salience (descending, then TurnId), and taken round-robin
+    /// across phases: every represented phase gets its best turn before any
+    /// phase gets a second. Within a round, higher salience wins. The
+    /// anchor fills its phase's first round. Edges are kept only when both
+    /// endpoints survive.
+    ///
+    /// A slice already within budget is returned unchanged. Otherwise the
+    /// result is signed under a derived `policy_id` (see
+    /// `PRUNED_SELECTION_PREFIX`) with `pruned_from` set to this slice's
+    /// `slice_id`, and its snapshot hash is recomputed over the kept turns.
+    ///
+    /// The anchor survives any budget, so `prune_to(0)` (like `prune_to(1)`)
+    /// yields the anchor alone.
+    pub fn prune_to(&self, max_turns: usize, hmac_secret: &[u8]) -> SliceExport {
+        if self.turns.len() <= max_turns {
+            return self.clone();
+        }
+
+        let anchor_phase = self.anchor_turn().map(|t| t.phase);
+        let mut by_phase: HashMap<Phase, Vec<&TurnSnapshot>> = HashMap::new();
+        for turn in self.turns.iter().filter(|t| t.id != self.anchor_turn_id) {
+            by_phase.entry(turn.phase).or_default().push(turn);
+        }
+
+        // (round, turn) pairs; the anchor already occupies round 0 of its phase
+        let mut candidates: Vec<(usize, &TurnSnapshot)> = Vec::with_capacity(self.turns.len());
+        for (phase, mut members) in by_phase {
+            members.sort_by(|a, b| b.salience.total_cmp(&a.salience).then_with(|| a.id.cmp(&b.id)));
+            let offset = usize::from(Some(phase) == anchor_phase);
+            candidates.extend(members.into_iter().enumerate().map(|(i, t)| (i + offset, t)));
+        }
+        candidates.sort_by(|(ra, a), (rb, b)| {
+            ra.cmp(rb)
+                .then_with(|| b.salience.total_cmp(&a.salience))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        let budget = max_turns.saturating_sub(usize::from(anchor_phase.is_some()));
+        let mut keep: BTreeSet<TurnId> = candidates.iter().take(budget).map(|(_, t)| t.id).collect();
+        if anchor_phase.is_some() {
+            keep.insert(self.anchor_turn_id);
+        }
+
+        let turns: Vec<TurnSnapshot> = self
+            .turns
+            .iter()
+            .filter(|t| keep.contains(&t.id))
+            .cloned()
+            .collect();
+        let edges: Vec<Edge> = self
+            .edges
+            .iter()
+            .filter(|e| keep.contains(&e.parent) && keep.contains(&e.child))
+            .cloned()
+            .collect();
+
+        let graph_snapshot_hash = selection_snapshot_hash(&turns, edges.len());
+        let mut slice = Self::new_with_secret(
+            hmac_secret,
+            self.anchor_turn_id,
+            turns,
+            edges,
+            derived_policy_id(PRUNED_SELECTION_PREFIX, &self.slice_id, &self.policy_id),
+            self.policy_params_hash.clone(),
+            graph_snapshot_hash,
+        );
+        slice.deduped_from = self.deduped_from.clone();
+        slice.pruned_from = Some(self.slice_id.clone());
+        slice.policy_semantics_version = self.policy_semantics_version;
+        slice.unverified_turn_provenance = self.unverified_turn_provenance.as_ref().map(|reasons| {
+            reasons
+                .iter()
+                .filter(|(id, _)| keep.contains(id))
+                .map(|(id, reason)| (*id, *reason))
+                .collect()
+        });
+        slice.with_issued_at(hmac_secret, self.issued_at_unix_ms)
+    }
+
+    /// Repair a slice deserialized from untrusted input.
+    ///
+    /// Re-sorts turns and edges into canonical order, recomputes `slice_id`
+    /// under the slice's `canonical_version`, and re-issues the token with
+    /// `hmac_secret`. Logs a warning when the
+    /// incoming `slice_id` differs from the recomputed one.
+    ///
+    /// This is repair, not verification: the result is canonical and signed
+    /// regardless of whether the input token was valid.
+    pub fn recanonicalize_and_resign(self, hmac_secret: &[u8]) -> SliceExport {
+        let incoming_slice_id = self.slice_id.clone();
+        let deduped_from = self.deduped_from.clone();
+        let pruned_from = self.pruned_from.clone();
+        let unverified_turn_provenance = self.unverified_turn_provenance.clone();
+        let policy_semantics_version = self.policy_semantics_version;
+        let issued_at_unix_ms = self.issued_at_unix_ms;
+        let canonical_version = self.canonical_version;
+
+        let mut slice = Self::new_with_secret(
+            hmac_secret,
+            self.anchor_turn_id,
+            self.turns,
+            self.edges,
+            self.policy_id,
+            self.policy_params_hash,
+            self.graph_snapshot_hash,
+        );
+        slice.deduped_from = deduped_from;
+        slice.pruned_from = pruned_from;
+        slice.unverified_turn_provenance = unverified_turn_provenance;
+        slice.policy_semantics_version = policy_semantics_version;
+        slice.issued_at_unix_ms = issued_at_unix_ms;
+        let slice = slice.with_canonical_version(hmac_secret, canonical_version);
+
+        if slice.slice_id != incoming_slice_id {
+            tracing::warn!(
+                incoming_slice_id = %incoming_slice_id,
+                recomputed_slice_id = %slice.slice_id,
+                anchor_turn_id = %slice.anchor_turn_id,
+                "Recanonicalized slice has a different slice_id than it was received with"
+            );
+        }
+
+        slice
+    }
+
+    /// Check whether the selection was built by a client rather than the slicer.
+    pub fn is_external_selection(&self) -> bool {
+        self.policy_id.starts_with(EXTERNAL_SELECTION_PREFIX)
+    }
+
+    /// Check whether two slices made the same selection.
+    ///
+    /// Compares anchor, turn IDs, edges, policy_id, and policy_params_hash.
+    /// Ignores `admissibility_token` and `graph_snapshot_hash`, so slices issued
+    /// by kernels with different HMAC secrets can be matched across environments.
+    pub fn selection_equals(&self, other: &Self) -> bool {
+        self.anchor_turn_id == other.anchor_turn_id
+            && self.policy_id == other.policy_id
+            && self.policy_params_hash == other.policy_params_hash
+            && self.edges == other.edges
+            && self.turns.len() == other.turns.len()
+            && self.turns.iter().zip(&other.turns).all(|(a, b)| a.id == b.id)
+    }
+
+    /// Canonical hash of the selected turn IDs alone.
+    ///
+    /// Ignores anchor, edges, and policy, so slices that selected the same
+    /// turns share this hash even when their `slice_id`s differ.
+    pub fn turn_set_hash(&self) -> String {
+        let mut turn_ids: Vec<TurnId> = self.turns.iter().map(|t| t.id).collect();
+        turn_ids.sort();
+        canonical_hash_hex(&turn_ids)
+    }
+
+    /// Canonical hash of the selection and policy alone.
+    ///
+    /// Covers anchor, turn IDs, edges, `policy_id`, and `policy_params_hash`,
+    /// but not the snapshot hash, token, schema, or canonical version, so
+    /// identical selections issued by different deployments share it. Unlike
+    /// `turn_set_hash`, edges and policy count. `slice_id` is unaffected.
+    pub fn selection_id(&self) -> String {
+        let turn_ids: Vec<TurnId> = self.turns.iter().map(|t| t.id).collect();
+        canonical_hash_hex(&(
             &self.anchor_turn_id,
+            &turn_ids,
+            &self.edges,
             &self.policy_id,
             &self.policy_params_hash,
-            &self.graph_snapshot_hash,
-            &self.schema_version,
-        )
+        ))
+    }
+
+    /// Check whether every turn of this slice is also in `other`.
+    ///
+    /// Merges the two canonically sorted turn lists in one pass. An empty
+    /// slice is contained in any slice.
+    pub fn is_contained_in(&self, other: &SliceExport) -> bool {
+        self.shared_turn_count(other) == self.turns.len()
+    }
+
+    /// Fraction of this slice's turns that are also in `other` (0.0-1.0).
+    ///
+    /// Returns 1.0 for an empty slice, consistent with `is_contained_in`.
+    pub fn containment_ratio(&self, other: &SliceExport) -> f32 {
+        if self.turns.is_empty() {
+            return 1.0;
+        }
+        self.shared_turn_count(other) as f32 / self.turns.len() as f32
+    }
+
+    /// Count turns present in both slices (turns must be sorted by ID).
+    fn shared_turn_count(&self, other: &SliceExport) -> usize {
+        let mut theirs = other.turns.iter().map(|t| t.id).peekable();
+        let mut shared = 0;
+        for id in self.turns.iter().map(|t| t.id) {
+            while theirs.next_if(|other_id| *other_id < id).is_some() {}
+            if theirs.next_if_eq(&id).is_some() {
+                shared += 1;
+            }
+        }
+        shared
     }
 
     /// Check if a turn is admissible in this slice.
@@ -520,10 +1108,41 @@ impl SliceExport {
     }
 }
 
+/// A slice bundled with the full policy that produced it.
+///
+/// `SliceExport` only names its policy (`policy_id` + `policy_params_hash`);
+/// this carries the definition too, so an auditor can re-run slicing
+/// without a policy registry. Check `is_consistent` before trusting the
+/// embedded policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SelfDescribingSlice {
+    /// The slice.
+    pub slice: SliceExport,
+    /// The policy the slice claims to have been produced with.
+    pub policy: SlicePolicyV1,
+}
+
+impl SelfDescribingSlice {
+    /// Bundle a slice with its policy.
+    pub fn new(slice: SliceExport, policy: SlicePolicyV1) -> Self {
+        Self { slice, policy }
+    }
+
+    /// Check that the embedded policy is the one the slice names.
+    ///
+    /// Compares `policy_id` and the recomputed `params_hash` against the
+    /// slice. Does not verify the slice's token or fingerprint.
+    pub fn is_consistent(&self) -> bool {
+        self.policy.policy_id() == self.slice.policy_id
+            && self.policy.params_hash() == self.slice.policy_params_hash
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::turn::{Role, Phase};
+    use crate::types::verification::{TokenVerifier, VerificationMode};
     use uuid::Uuid;
 
     fn make_turn(id: u128, salience: f32, phase: Phase) -> TurnSnapshot {
@@ -598,6 +1217,33 @@ mod tests {
         assert_ne!(slice1.slice_id, slice2.slice_id);
     }
 
+    #[test]
+    fn test_duplicate_edges_collapsed() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let anchor = TurnId::new(Uuid::from_u128(1));
+        let turns = vec![make_turn(1, 0.8, Phase::Synthesis), make_turn(2, 0.6, Phase::Planning)];
+        let edge = Edge::reply(anchor, TurnId::new(Uuid::from_u128(2)));
+        let build = |edges: Vec<Edge>| {
+            SliceExport::new_with_secret(
+                secret,
+                anchor,
+                turns.clone(),
+                edges,
+                "test_policy".to_string(),
+                "params_hash".to_string(),
+                GraphSnapshotHash::new("test_snapshot".to_string()),
+            )
+        };
+
+        let duplicated = build(vec![edge.clone(), edge.clone(), edge.clone()]);
+        let single = build(vec![edge.clone()]);
+
+        assert_eq!(duplicated.edges, vec![edge]);
+        assert_eq!(duplicated.num_edges(), 1);
+        assert_eq!(duplicated.slice_id, single.slice_id);
+        assert!(duplicated.verify_admissibility(secret));
+    }
+
     #[test]
     fn test_slice_turns_sorted() {
         let anchor = TurnId::new(Uuid::from_u128(1));
@@ -646,6 +1292,300 @@ mod tests {
         assert!(!slice.verify_admissibility(b"wrong_secret_definitely_wrong!"));
     }
 
+    #[test]
+    fn test_stamped_slice_verifies_on_both_paths() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let anchor = TurnId::new(Uuid::from_u128(1));
+        let slice = SliceExport::new_with_secret(
+            secret,
+            anchor,
+            vec![make_turn(1, 0.8, Phase::Synthesis)],
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        )
+        .with_issued_at(secret, Some(1_700_000_000_000));
+
+        assert!(slice.verify_token(secret));
+        assert!(slice.verify_admissibility(secret));
+
+        let mut backdated = slice.clone();
+        backdated.issued_at_unix_ms = Some(1);
+        assert!(!backdated.verify_token(secret));
+        assert!(!backdated.verify_admissibility(secret));
+    }
+
+    #[test]
+    fn test_selection_equals_ignores_token() {
+        let anchor = TurnId::new(Uuid::from_u128(1));
+        let turns = vec![
+            make_turn(1, 0.8, Phase::Synthesis),
+            make_turn(2, 0.6, Phase::Planning),
+        ];
+        let edges = vec![Edge::reply(anchor, TurnId::new(Uuid::from_u128(2)))];
+
+        let make = |secret: &[u8], snapshot: &str| {
+            SliceExport::new_with_secret(
+                secret,
+                anchor,
+                turns.clone(),
+                edges.clone(),
+                "test_policy".to_string(),
+                "params_hash".to_string(),
+                GraphSnapshotHash::new(snapshot.to_string()),
+            )
+        };
+
+        let staging = make(b"staging_secret_32_bytes_minimum!", "snapshot_a");
+        let production = make(b"production_secret_32_bytes_min!!", "snapshot_b");
+
+        assert!(staging.selection_equals(&production));
+        assert_ne!(staging.admissibility_token, production.admissibility_token);
+
+        let other = SliceExport::new_with_secret(
+            b"staging_secret_32_bytes_minimum!",
+            anchor,
+            vec![make_turn(1, 0.8, Phase::Synthesis)],
+            vec![],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("snapshot_a".to_string()),
+        );
+        assert!(!staging.selection_equals(&other));
+    }
+
+    #[test]
+    fn test_dedup_by_content_collapses_and_rewires() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let with_hash = |n: u128, hash: Option<&str>| {
+            TurnSnapshot::new_with_content_hash(
+                id(n),
+                "session_1".to_string(),
+                Role::User,
+                Phase::Synthesis,
+                0.5,
+                1,
+                0,
+                0.5,
+                0.5,
+                1.0,
+                1000,
+                hash.map(|h| h.to_string()),
+            )
+        };
+
+        // 2 and 3 are a quoted repost; 4 and 5 lack hashes and must survive
+        let turns = vec![
+            with_hash(1, Some("root")),
+            with_hash(2, Some("repost")),
+            with_hash(3, Some("repost")),
+            with_hash(4, None),
+            with_hash(5, None),
+        ];
+        let edges = vec![
+            Edge::reply(id(1), id(2)),
+            Edge::reply(id(1), id(3)),
+            Edge::reply(id(3), id(4)),
+            Edge::reply(id(4), id(5)),
+        ];
+
+        let slice = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            turns,
+            edges,
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+        let deduped = slice.dedup_by_content(secret);
+
+        assert_eq!(deduped.num_turns(), 4);
+        assert!(deduped.contains_turn(&id(2)));
+        assert!(!deduped.contains_turn(&id(3)));
+        assert!(deduped.contains_turn(&id(4)));
+        assert!(deduped.contains_turn(&id(5)));
+
+        // 1->3 collapses into 1->2; 3->4 is rewired to 2->4
+        assert_eq!(
+            deduped.edges,
+            vec![
+                Edge::reply(id(1), id(2)),
+                Edge::reply(id(2), id(4)),
+                Edge::reply(id(4), id(5)),
+            ]
+        );
+
+        assert_ne!(deduped.slice_id, slice.slice_id);
+        assert_eq!(deduped.deduped_from, Some(slice.slice_id.clone()));
+        assert!(deduped.verify_token(secret));
+        assert_eq!(slice.deduped_from, None);
+
+        // Signed under a derived policy_id and a snapshot of its own turns
+        assert_eq!(
+            deduped.policy_id,
+            format!("{}{}:test_policy", DEDUP_SELECTION_PREFIX, slice.slice_id)
+        );
+        assert!(deduped.fingerprint_matches());
+        assert_eq!(
+            deduped.graph_snapshot_hash,
+            selection_snapshot_hash(&deduped.turns, deduped.num_edges())
+        );
+    }
+
+    #[test]
+    fn test_dedup_provenance_is_bound_to_token() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let turns = vec![make_turn(1, 0.5, Phase::Synthesis), make_turn(2, 0.5, Phase::Synthesis)];
+        let slice = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            turns,
+            vec![Edge::reply(id(1), id(2))],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+        let deduped = slice.dedup_by_content(secret);
+        let verifier = TokenVerifier::new(VerificationMode::local_secret(secret.to_vec()));
+        assert!(deduped.verify_token(secret));
+        assert!(verifier.verify_slice(&deduped).is_valid);
+
+        // Dropping the marker alone no longer verifies
+        let mut stripped = deduped.clone();
+        stripped.deduped_from = None;
+        assert!(!stripped.verify_token(secret));
+        assert!(!verifier.verify_slice(&stripped).is_valid);
+
+        // Nor does restoring the source policy_id under the derived token
+        stripped.policy_id = slice.policy_id.clone();
+        assert!(!stripped.verify_token(secret));
+
+        // Or pointing the marker at another slice
+        let mut rewritten = deduped.clone();
+        rewritten.deduped_from = Some(SliceFingerprint::new("elsewhere".to_string()));
+        assert!(!rewritten.verify_token(secret));
+    }
+
+    #[test]
+    fn test_anchor_eccentricity_on_linear_slice() {
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let chain = |anchor: u128, extra: Option<u128>| {
+            let mut turns: Vec<TurnSnapshot> =
+                (1..=5).map(|n| make_turn(n, 0.5, Phase::Planning)).collect();
+            turns.extend(extra.map(|n| make_turn(n, 0.5, Phase::Planning)));
+            let edges = (1..5).map(|n| Edge::reply(id(n), id(n + 1))).collect();
+            SliceExport::new_for_test(id(anchor), turns, edges, "test".to_string(), "hash".to_string())
+        };
+
+        // 1 -> 2 -> 3 -> 4 -> 5: farthest endpoint is 5 from 2, 2 from the middle
+        assert_eq!(chain(2, None).anchor_eccentricity(), 3);
+        assert_eq!(chain(3, None).anchor_eccentricity(), 2);
+        assert_eq!(chain(5, None).anchor_eccentricity(), 4);
+
+        // A turn with no edge into the chain
+        assert_eq!(
+            chain(3, Some(9)).anchor_eccentricity(),
+            SliceExport::DISCONNECTED_ECCENTRICITY
+        );
+    }
+
+    #[test]
+    fn test_prune_to_keeps_one_turn_per_phase() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        // Synthesis dominates on salience; a naive top-k would drop the
+        // low-salience Debugging and Exploration turns
+        let turns = vec![
+            make_turn(1, 0.5, Phase::Synthesis),
+            make_turn(2, 0.95, Phase::Synthesis),
+            make_turn(3, 0.9, Phase::Synthesis),
+            make_turn(4, 0.85, Phase::Synthesis),
+            make_turn(5, 0.2, Phase::Debugging),
+            make_turn(6, 0.3, Phase::Debugging),
+            make_turn(7, 0.1, Phase::Exploration),
+        ];
+        let edges: Vec<Edge> = (2..=7).map(|n| Edge::reply(id(1), id(n))).collect();
+        let slice = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            turns,
+            edges,
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+
+        let pruned = slice.prune_to(4, secret);
+        let kept: Vec<TurnId> = pruned.turns.iter().map(|t| t.id).collect();
+        // Anchor, best Debugging, lone Exploration, then best Synthesis
+        assert_eq!(kept, vec![id(1), id(2), id(6), id(7)]);
+        assert_eq!(pruned.num_edges(), 3);
+        assert_eq!(pruned.pruned_from, Some(slice.slice_id.clone()));
+        assert_ne!(pruned.slice_id, slice.slice_id);
+        assert!(pruned.fingerprint_matches());
+        assert!(pruned.verify_token(secret));
+
+        // Reproducible, and a slice within budget is left alone
+        assert_eq!(slice.prune_to(4, secret).slice_id, pruned.slice_id);
+        assert_eq!(slice.prune_to(7, secret).slice_id, slice.slice_id);
+        assert_eq!(slice.prune_to(7, secret).pruned_from, None);
+
+        // Fewer slots than phases: anchor first, then highest-salience heads
+        let tight = slice.prune_to(2, secret);
+        let kept: Vec<TurnId> = tight.turns.iter().map(|t| t.id).collect();
+        assert_eq!(kept, vec![id(1), id(6)]);
+
+        // A zero budget still keeps the anchor
+        let empty = slice.prune_to(0, secret);
+        assert_eq!(empty.turns.iter().map(|t| t.id).collect::<Vec<_>>(), vec![id(1)]);
+    }
+
+    #[test]
+    fn test_prune_provenance_is_bound_to_token() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let turns: Vec<TurnSnapshot> = (1..=3).map(|n| make_turn(n, 0.5, Phase::Synthesis)).collect();
+        let edges = vec![Edge::reply(id(1), id(2)), Edge::reply(id(1), id(3))];
+        let slice = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            turns,
+            edges,
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+
+        let pruned = slice.prune_to(2, secret);
+        assert_eq!(
+            pruned.policy_id,
+            format!("{}{}:test_policy", PRUNED_SELECTION_PREFIX, slice.slice_id)
+        );
+        assert_eq!(
+            pruned.graph_snapshot_hash,
+            selection_snapshot_hash(&pruned.turns, pruned.num_edges())
+        );
+        assert!(pruned.verify_token(secret));
+
+        let mut stripped = pruned.clone();
+        stripped.pruned_from = None;
+        assert!(!stripped.verify_token(secret));
+
+        // Lineage survives chaining in either order
+        let chained = pruned.dedup_by_content(secret);
+        assert_eq!(chained.pruned_from, Some(slice.slice_id.clone()));
+        assert_eq!(chained.deduped_from, Some(pruned.slice_id.clone()));
+        assert!(chained.verify_token(secret));
+        let chained = slice.dedup_by_content(secret).prune_to(2, secret);
+        assert!(chained.deduped_from.is_some() && chained.pruned_from.is_some());
+        assert!(chained.verify_token(secret));
+    }
+
     #[test]
     fn test_hmac_token_is_unforgeable() {
         let secret = b"kernel_only_secret_very_secure!!";
@@ -774,5 +1714,190 @@ mod tests {
         assert_eq!(hash1, hash2);
         assert_ne!(hash1, hash3);
     }
-}
 
+    #[test]
+    fn test_recanonicalize_and_resign() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        let canonical = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            vec![make_turn(1, 0.5, Phase::Planning), make_turn(2, 0.5, Phase::Planning), make_turn(3, 0.5, Phase::Planning)],
+            vec![Edge::reply(id(1), id(2)), Edge::reply(id(2), id(3))],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("snapshot".to_string()),
+        );
+
+        // Simulate untrusted JSON: shuffled order and a stale slice_id
+        let mut tampered = canonical.clone();
+        tampered.turns.reverse();
+        tampered.edges.reverse();
+        tampered.slice_id = SliceFingerprint::new("0000000000000000".to_string());
+
+        let repaired = tampered.recanonicalize_and_resign(secret);
+        let turn_ids: Vec<_> = repaired.turns.iter().map(|t| t.id).collect();
+        assert_eq!(turn_ids, vec![id(1), id(2), id(3)]);
+        assert!(repaired.edges.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(repaired.slice_id, canonical.slice_id);
+        assert!(repaired.verify_token(secret));
+
+        // Repair is idempotent
+        let again = repaired.clone().recanonicalize_and_resign(secret);
+        assert_eq!(again.slice_id, repaired.slice_id);
+    }
+
+    #[test]
+    fn test_turn_set_hash_ignores_edges() {
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let turns = || vec![make_turn(1, 0.5, Phase::Planning), make_turn(2, 0.5, Phase::Planning)];
+
+        let linked = SliceExport::new_for_test(
+            id(1), turns(), vec![Edge::reply(id(1), id(2))], "p".to_string(), "h".to_string(),
+        );
+        let unlinked = SliceExport::new_for_test(id(1), turns(), vec![], "p".to_string(), "h".to_string());
+        let other = SliceExport::new_for_test(
+            id(1), vec![make_turn(1, 0.5, Phase::Planning)], vec![], "p".to_string(), "h".to_string(),
+        );
+
+        assert_eq!(linked.turn_set_hash(), unlinked.turn_set_hash());
+        assert_ne!(linked.slice_id, unlinked.slice_id);
+        assert_ne!(linked.turn_set_hash(), other.turn_set_hash());
+    }
+
+    #[test]
+    fn test_selection_id_ignores_snapshot_and_token() {
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let issue = |secret: &[u8], snapshot: &str, edges: Vec<Edge>| {
+            SliceExport::new_with_secret(
+                secret,
+                id(1),
+                vec![make_turn(1, 0.5, Phase::Planning), make_turn(2, 0.5, Phase::Planning)],
+                edges,
+                "slice_policy_v1".to_string(),
+                "params".to_string(),
+                GraphSnapshotHash::new(snapshot.to_string()),
+            )
+        };
+
+        let staging = issue(b"staging_secret_32_bytes_minimum!", "snap_a", vec![Edge::reply(id(1), id(2))]);
+        let production = issue(b"production_secret_32_bytes_min!!", "snap_b", vec![Edge::reply(id(1), id(2))]);
+        assert_ne!(staging.admissibility_token, production.admissibility_token);
+        assert_eq!(staging.selection_id(), production.selection_id());
+
+        // Edges are part of the selection
+        let unlinked = issue(b"staging_secret_32_bytes_minimum!", "snap_a", vec![]);
+        assert_eq!(unlinked.turn_set_hash(), staging.turn_set_hash());
+        assert_ne!(unlinked.selection_id(), staging.selection_id());
+    }
+
+    #[test]
+    fn test_slice_containment() {
+        let slice = |ids: &[u128]| {
+            let turns: Vec<_> = ids.iter().map(|&i| make_turn(i, 0.5, Phase::Planning)).collect();
+            SliceExport::new_for_test(turns[0].id, turns, vec![], "p".to_string(), "h".to_string())
+        };
+        let narrow = slice(&[2, 4]);
+        let broad = slice(&[1, 2, 3, 4, 5]);
+        let disjoint = slice(&[6, 7]);
+
+        // Proper subset
+        assert!(narrow.is_contained_in(&broad));
+        assert!(!broad.is_contained_in(&narrow));
+        assert_eq!(narrow.containment_ratio(&broad), 1.0);
+        assert!((broad.containment_ratio(&narrow) - 0.4).abs() < 1e-6);
+
+        // Equal
+        assert!(broad.is_contained_in(&broad.clone()));
+        assert_eq!(broad.containment_ratio(&broad), 1.0);
+
+        // Disjoint
+        assert!(!narrow.is_contained_in(&disjoint));
+        assert_eq!(narrow.containment_ratio(&disjoint), 0.0);
+    }
+
+    #[test]
+    fn test_canonical_version_in_fingerprint() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        let current = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            vec![make_turn(1, 0.5, Phase::Planning), make_turn(2, 0.5, Phase::Planning)],
+            vec![Edge::reply(id(1), id(2))],
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("snapshot".to_string()),
+        );
+        assert_eq!(current.canonical_version, CANONICAL_SLICE_VERSION);
+        assert!(current.fingerprint_matches());
+
+        let legacy = current.clone().with_canonical_version(secret, LEGACY_CANONICAL_SLICE_VERSION);
+        assert_ne!(legacy.slice_id, current.slice_id);
+        assert!(legacy.fingerprint_matches());
+        assert!(legacy.verify_token(secret));
+
+        // Exports written before the field existed read back as legacy
+        let mut json = serde_json::to_value(&legacy).unwrap();
+        json.as_object_mut().unwrap().remove("canonical_version");
+        let parsed: SliceExport = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed.canonical_version, LEGACY_CANONICAL_SLICE_VERSION);
+        assert!(parsed.fingerprint_matches());
+
+        // Relabelling the version without recomputing is detected
+        let mut relabelled = legacy;
+        relabelled.canonical_version = CANONICAL_SLICE_VERSION;
+        assert!(!relabelled.fingerprint_matches());
+    }
+
+    #[test]
+    fn test_self_describing_slice_consistency() {
+        let policy = SlicePolicyV1::default();
+        let slice = SliceExport::new_for_test(
+            TurnId::new(Uuid::from_u128(1)),
+            vec![make_turn(1, 0.5, Phase::Planning)],
+            vec![],
+            policy.policy_id().to_string(),
+            policy.params_hash(),
+        );
+
+        let bundle = SelfDescribingSlice::new(slice.clone(), policy.clone());
+        assert!(bundle.is_consistent());
+
+        // Survives a JSON round trip
+        let json = serde_json::to_string(&bundle).unwrap();
+        let parsed: SelfDescribingSlice = serde_json::from_str(&json).unwrap();
+        assert!(parsed.is_consistent());
+
+        // A policy with different parameters is rejected
+        let other = SlicePolicyV1::builder().max_nodes(policy.max_nodes + 1).build();
+        assert!(!SelfDescribingSlice::new(slice, other).is_consistent());
+    }
+
+    #[test]
+    fn test_require_content_hashes() {
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+        let unhashed = |n: u128| make_turn(n, 0.5, Phase::Synthesis);
+        let hashed = |n: u128| unhashed(n).with_content_hash(Some(format!("h{}", n)));
+
+        let slice = SliceExport::new_for_test(
+            id(1),
+            vec![hashed(1), unhashed(2), hashed(3), unhashed(4)],
+            vec![],
+            "slice_policy_v1".to_string(),
+            "test_hash".to_string(),
+        );
+        assert_eq!(slice.require_content_hashes(), Err(vec![id(2), id(4)]));
+
+        let complete = SliceExport::new_for_test(
+            id(1),
+            vec![hashed(1), hashed(3)],
+            vec![],
+            "slice_policy_v1".to_string(),
+            "test_hash".to_string(),
+        );
+        assert_eq!(complete.require_content_hashes(), Ok(()));
+    }
+}
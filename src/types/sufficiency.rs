@@ -17,6 +17,7 @@
 //! | **Salience Spread** | Distribution of salience scores | All low-salience is suspicious |
 //! | **Turn Count** | Minimum number of turns | Too few turns = insufficient context |
 //! | **Unique Sessions** | Distinct session IDs | Cross-session evidence is stronger |
+//! | **Edge Density** | Edges per turn | Degenerate dense subgraphs make poor context |
 //!
 //! ## Security Model
 //!
@@ -31,8 +32,11 @@
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
-use super::admissible::AdmissibleEvidenceBundle;
-use super::turn::{TurnId, Role, Phase};
+use super::admissible::{AdmissibleEvidenceBundle, VerificationError};
+use super::turn::{TurnId, TurnSnapshot, Role, Phase};
+use crate::canonical::canonical_hash_hex;
+use crate::policy::v1::quantize_float;
+use crate::SUFFICIENCY_POLICY_VERSION;
 
 /// Diversity metrics computed from a slice's turns.
 ///
@@ -62,7 +66,14 @@ pub struct DiversityMetrics {
     pub salience_stats: SalienceStats,
 
     /// Whether there's meaningful conversation exchange (user + assistant).
+    ///
+    /// This is the default exchange definition; `SufficiencyPolicy` checks
+    /// its own `exchange_roles` against `role_distribution` instead.
     pub has_exchange: bool,
+
+    /// Number of edges in the underlying slice.
+    #[serde(default)]
+    pub edge_count: usize,
 }
 
 /// Statistical summary of salience scores.
@@ -78,6 +89,31 @@ pub struct SalienceStats {
     pub std_dev: f32,
     /// Count of high-salience turns (>= 0.7).
     pub high_salience_count: usize,
+    /// Median salience (50th percentile).
+    #[serde(default)]
+    pub median: f32,
+    /// 25th percentile salience.
+    #[serde(default)]
+    pub p25: f32,
+    /// 75th percentile salience.
+    #[serde(default)]
+    pub p75: f32,
+}
+
+impl SalienceStats {
+    /// Stats of an empty slice: all zero.
+    fn empty() -> Self {
+        Self {
+            min: 0.0,
+            max: 0.0,
+            mean: 0.0,
+            std_dev: 0.0,
+            high_salience_count: 0,
+            median: 0.0,
+            p25: 0.0,
+            p75: 0.0,
+        }
+    }
 }
 
 impl DiversityMetrics {
@@ -119,18 +155,55 @@ impl DiversityMetrics {
             unique_sessions: unique_sessions.len(),
             salience_stats,
             has_exchange,
+            edge_count: slice.edges.len(),
         }
     }
 
+    /// Edges per turn in the slice (0.0 for an empty slice).
+    pub fn edge_turn_ratio(&self) -> f32 {
+        if self.turn_count == 0 {
+            0.0
+        } else {
+            self.edge_count as f32 / self.turn_count as f32
+        }
+    }
+
+    /// Shannon entropy (base 2) of the phase distribution.
+    ///
+    /// 0.0 when all turns share one phase; `log2(k)` when they are spread
+    /// evenly over `k` phases.
+    pub fn phase_entropy(&self) -> f32 {
+        Self::shannon_entropy(self.phase_distribution.values().copied())
+    }
+
+    /// Shannon entropy (base 2) of the role distribution.
+    pub fn role_entropy(&self) -> f32 {
+        Self::shannon_entropy(self.role_distribution.values().copied())
+    }
+
+    /// Entropy of a count distribution. Counts are sorted first so the
+    /// float summation order (and result) does not depend on map order.
+    fn shannon_entropy(counts: impl Iterator<Item = usize>) -> f32 {
+        let mut counts: Vec<usize> = counts.filter(|&c| c > 0).collect();
+        counts.sort_unstable();
+        let total: usize = counts.iter().sum();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let entropy: f64 = counts
+            .iter()
+            .map(|&c| {
+                let p = c as f64 / total as f64;
+                p * (1.0 / p).log2()
+            })
+            .sum();
+        entropy as f32
+    }
+
     fn compute_salience_stats(saliences: &[f32]) -> SalienceStats {
         if saliences.is_empty() {
-            return SalienceStats {
-                min: 0.0,
-                max: 0.0,
-                mean: 0.0,
-                std_dev: 0.0,
-                high_salience_count: 0,
-            };
+            return SalienceStats::empty();
         }
 
         let min = saliences.iter().cloned().fold(f32::INFINITY, f32::min);
@@ -145,12 +218,131 @@ impl DiversityMetrics {
 
         let high_salience_count = saliences.iter().filter(|&&s| s >= 0.7).count();
 
+        let mut sorted = saliences.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
         SalienceStats {
             min,
             max,
             mean,
             std_dev,
             high_salience_count,
+            median: Self::percentile(&sorted, 0.5),
+            p25: Self::percentile(&sorted, 0.25),
+            p75: Self::percentile(&sorted, 0.75),
+        }
+    }
+
+    /// Percentile of sorted, non-empty values with linear interpolation
+    /// between closest ranks.
+    fn percentile(sorted: &[f32], p: f32) -> f32 {
+        let rank = p * (sorted.len() - 1) as f32;
+        let lower = rank.floor() as usize;
+        let upper = rank.ceil() as usize;
+        let weight = rank - lower as f32;
+        sorted[lower] + (sorted[upper] - sorted[lower]) * weight
+    }
+}
+
+/// Incrementally maintained `DiversityMetrics`.
+///
+/// For tools that grow a slice one turn at a time. Distributions and
+/// sessions are counted as turns arrive, salience mean and variance use
+/// Welford's algorithm, and saliences are kept sorted for percentiles.
+/// `finalize` matches `DiversityMetrics::from_bundle` exactly except for
+/// salience mean and standard deviation, which agree to float rounding.
+#[derive(Debug, Clone, Default)]
+pub struct DiversityAccumulator {
+    role_distribution: HashMap<Role, usize>,
+    phase_distribution: HashMap<Phase, usize>,
+    sessions: HashSet<String>,
+    /// Saliences seen so far, ascending.
+    sorted_saliences: Vec<f32>,
+    /// Welford running mean.
+    mean: f64,
+    /// Welford sum of squared deviations from the mean.
+    m2: f64,
+    high_salience_count: usize,
+    edge_count: usize,
+}
+
+impl DiversityAccumulator {
+    /// Create an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a turn to the metrics.
+    pub fn add_turn(&mut self, turn: &TurnSnapshot) {
+        *self.role_distribution.entry(turn.role).or_insert(0) += 1;
+        *self.phase_distribution.entry(turn.phase).or_insert(0) += 1;
+        if !self.sessions.contains(&turn.session_id) {
+            self.sessions.insert(turn.session_id.clone());
+        }
+        self.add_salience(turn.salience);
+    }
+
+    /// Count `count` more edges in the slice.
+    pub fn add_edges(&mut self, count: usize) {
+        self.edge_count += count;
+    }
+
+    /// Number of turns added so far.
+    pub fn turn_count(&self) -> usize {
+        self.sorted_saliences.len()
+    }
+
+    /// Metrics for the turns and edges added so far.
+    pub fn finalize(&self) -> DiversityMetrics {
+        let has_user = self.role_distribution.contains_key(&Role::User);
+        let has_assistant = self.role_distribution.contains_key(&Role::Assistant);
+
+        DiversityMetrics {
+            turn_count: self.turn_count(),
+            unique_roles: self.role_distribution.len(),
+            role_distribution: self.role_distribution.clone(),
+            unique_phases: self.phase_distribution.len(),
+            phase_distribution: self.phase_distribution.clone(),
+            unique_sessions: self.sessions.len(),
+            salience_stats: self.salience_stats(),
+            has_exchange: has_user && has_assistant,
+            edge_count: self.edge_count,
+        }
+    }
+
+    fn add_salience(&mut self, salience: f32) {
+        let pos = self
+            .sorted_saliences
+            .partition_point(|s| s.total_cmp(&salience).is_le());
+        self.sorted_saliences.insert(pos, salience);
+
+        let n = self.sorted_saliences.len() as f64;
+        let delta = salience as f64 - self.mean;
+        self.mean += delta / n;
+        self.m2 += delta * (salience as f64 - self.mean);
+
+        if salience >= 0.7 {
+            self.high_salience_count += 1;
+        }
+    }
+
+    fn salience_stats(&self) -> SalienceStats {
+        let sorted = &self.sorted_saliences;
+        if sorted.is_empty() {
+            return SalienceStats::empty();
+        }
+
+        let variance = self.m2 / sorted.len() as f64;
+
+        SalienceStats {
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            mean: self.mean as f32,
+            std_dev: variance.sqrt() as f32,
+            high_salience_count: self.high_salience_count,
+            median: DiversityMetrics::percentile(sorted, 0.5),
+            p25: DiversityMetrics::percentile(sorted, 0.25),
+            p75: DiversityMetrics::percentile(sorted, 0.75),
         }
     }
 }
@@ -173,11 +365,55 @@ pub struct SufficiencyPolicy {
     /// Minimum number of high-salience turns required.
     pub min_high_salience: usize,
 
-    /// Require meaningful exchange (user + assistant).
+    /// Require meaningful exchange (see `exchange_roles`).
     pub require_exchange: bool,
 
+    /// Role pairs that count as an exchange when both roles are present.
+    ///
+    /// Defaults to `[(User, Assistant)]`. Add e.g. `(User, Tool)` to accept
+    /// tool-augmented evidence.
+    #[serde(default = "default_exchange_roles")]
+    pub exchange_roles: Vec<(Role, Role)>,
+
     /// Minimum mean salience score.
     pub min_mean_salience: f32,
+
+    /// Maximum edges per turn (None = unbounded).
+    #[serde(default)]
+    pub max_edge_turn_ratio: Option<f32>,
+
+    /// Phases that must each have at least one turn (empty = no requirement).
+    ///
+    /// Unlike `min_phases`, this names the phases, e.g. `{Synthesis}` for
+    /// high-stakes promotion.
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub required_phases: HashSet<Phase>,
+}
+
+/// Quantized sufficiency parameters for deterministic hashing.
+#[derive(Serialize)]
+struct QuantizedSufficiencyParams {
+    version: &'static str,
+    min_turns: usize,
+    min_roles: usize,
+    min_phases: usize,
+    min_high_salience: usize,
+    require_exchange: bool,
+    min_mean_salience: i64,
+    /// Omitted when unset so pre-existing policies keep their hash.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_edge_turn_ratio: Option<i64>,
+    /// Normalized pairs, omitted when equal to the default definition.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exchange_roles: Option<Vec<(String, String)>>,
+    /// Sorted phase names, omitted when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    required_phases: Vec<String>,
+}
+
+/// Default exchange definition: a user and an assistant turn.
+fn default_exchange_roles() -> Vec<(Role, Role)> {
+    vec![(Role::User, Role::Assistant)]
 }
 
 impl Default for SufficiencyPolicy {
@@ -192,6 +428,9 @@ impl Default for SufficiencyPolicy {
             min_high_salience: 1,   // At least one high-salience turn
             require_exchange: true, // Must be a conversation
             min_mean_salience: 0.3, // Average salience above threshold
+            max_edge_turn_ratio: None,
+            exchange_roles: default_exchange_roles(),
+            required_phases: HashSet::new(),
         }
     }
 }
@@ -206,6 +445,9 @@ impl SufficiencyPolicy {
             min_high_salience: 0,
             require_exchange: false,
             min_mean_salience: 0.0,
+            max_edge_turn_ratio: None,
+            exchange_roles: default_exchange_roles(),
+            required_phases: HashSet::new(),
         }
     }
 
@@ -218,17 +460,105 @@ impl SufficiencyPolicy {
             min_high_salience: 2,
             require_exchange: true,
             min_mean_salience: 0.5,
+            max_edge_turn_ratio: None,
+            exchange_roles: default_exchange_roles(),
+            required_phases: HashSet::new(),
         }
     }
 
+    /// Get the policy identifier.
+    pub fn policy_id(&self) -> &str {
+        SUFFICIENCY_POLICY_VERSION
+    }
+
+    /// Compute the canonical hash of policy parameters.
+    ///
+    /// Floats are quantized the same way as `SlicePolicyV1::params_hash`,
+    /// so equal thresholds always produce the same hash.
+    pub fn params_hash(&self) -> String {
+        let mut required_phases: Vec<String> =
+            self.required_phases.iter().map(|p| format!("{:?}", p)).collect();
+        required_phases.sort();
+
+        let quantized = QuantizedSufficiencyParams {
+            version: SUFFICIENCY_POLICY_VERSION,
+            min_turns: self.min_turns,
+            min_roles: self.min_roles,
+            min_phases: self.min_phases,
+            min_high_salience: self.min_high_salience,
+            require_exchange: self.require_exchange,
+            min_mean_salience: quantize_float(self.min_mean_salience),
+            max_edge_turn_ratio: self.max_edge_turn_ratio.map(quantize_float),
+            exchange_roles: self.normalized_exchange_roles(),
+            required_phases,
+        };
+        canonical_hash_hex(&quantized)
+    }
+
+    /// Exchange pairs in canonical form for hashing.
+    ///
+    /// Pairs are unordered and the list is a set, so each pair is sorted,
+    /// then the list is sorted and deduplicated. Returns `None` for the
+    /// default definition so existing policies keep their hash.
+    fn normalized_exchange_roles(&self) -> Option<Vec<(String, String)>> {
+        let mut pairs: Vec<(String, String)> = self
+            .exchange_roles
+            .iter()
+            .map(|(a, b)| {
+                let (a, b) = (format!("{:?}", a), format!("{:?}", b));
+                if a <= b { (a, b) } else { (b, a) }
+            })
+            .collect();
+        pairs.sort();
+        pairs.dedup();
+
+        let default = vec![("Assistant".to_string(), "User".to_string())];
+        (pairs != default).then_some(pairs)
+    }
+
+    /// Whether the metrics contain any configured exchange pair.
+    pub fn has_exchange(&self, metrics: &DiversityMetrics) -> bool {
+        self.exchange_roles.iter().any(|(a, b)| {
+            metrics.role_distribution.contains_key(a) && metrics.role_distribution.contains_key(b)
+        })
+    }
+
+    /// Required phases absent from a phase distribution, sorted by name.
+    fn missing_required_phases(&self, phase_distribution: &HashMap<Phase, usize>) -> Vec<Phase> {
+        let mut missing: Vec<Phase> = self
+            .required_phases
+            .iter()
+            .filter(|phase| phase_distribution.get(phase).map_or(true, |count| *count == 0))
+            .copied()
+            .collect();
+        missing.sort_by_key(|p| format!("{:?}", p));
+        missing
+    }
+
     /// Check if metrics satisfy this policy.
     pub fn is_satisfied(&self, metrics: &DiversityMetrics) -> bool {
         metrics.turn_count >= self.min_turns
             && metrics.unique_roles >= self.min_roles
             && metrics.unique_phases >= self.min_phases
             && metrics.salience_stats.high_salience_count >= self.min_high_salience
-            && (!self.require_exchange || metrics.has_exchange)
+            && (!self.require_exchange || self.has_exchange(metrics))
             && metrics.salience_stats.mean >= self.min_mean_salience
+            && self.max_edge_turn_ratio.map_or(true, |max| metrics.edge_turn_ratio() <= max)
+            && self.missing_required_phases(&metrics.phase_distribution).is_empty()
+    }
+
+    /// Describe a violation of this policy.
+    ///
+    /// Like the violation's `Display`, but `NoExchange` names this policy's
+    /// `exchange_roles`, which the violation itself does not carry.
+    pub fn describe_violation(&self, violation: &SufficiencyViolation) -> String {
+        match violation {
+            SufficiencyViolation::NoExchange => format!(
+                "No meaningful exchange: requires {} turns",
+                describe_exchange_roles(&self.exchange_roles)
+            ),
+            other => other.to_string(),
+        }
     }
 
     /// Get detailed violation report.
@@ -263,7 +593,7 @@ impl SufficiencyPolicy {
             });
         }
 
-        if self.require_exchange && !metrics.has_exchange {
+        if self.require_exchange && !self.has_exchange(metrics) {
             violations.push(SufficiencyViolation::NoExchange);
         }
 
@@ -274,6 +604,20 @@ impl SufficiencyPolicy {
             });
         }
 
+        if let Some(max) = self.max_edge_turn_ratio {
+            let ratio = metrics.edge_turn_ratio();
+            if ratio > max {
+                violations.push(SufficiencyViolation::EdgeDensityTooHigh {
+                    required: max,
+                    actual: ratio,
+                });
+            }
+        }
+
+        for phase in self.missing_required_phases(&metrics.phase_distribution) {
+            violations.push(SufficiencyViolation::MissingRequiredPhase { phase });
+        }
+
         SufficiencyCheck {
             is_sufficient: violations.is_empty(),
             violations,
@@ -293,6 +637,13 @@ pub struct SufficiencyCheck {
     pub metrics: DiversityMetrics,
 }
 
+impl SufficiencyCheck {
+    /// One remediation hint per violation, in violation order.
+    pub fn suggestions(&self) -> Vec<String> {
+        self.violations.iter().map(SufficiencyViolation::suggestion).collect()
+    }
+}
+
 /// Specific sufficiency violation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SufficiencyViolation {
@@ -333,6 +684,59 @@ pub enum SufficiencyViolation {
         /// Actual value.
         actual: f32,
     },
+    /// Too many edges per turn.
+    EdgeDensityTooHigh {
+        /// Maximum allowed edges per turn.
+        required: f32,
+        /// Actual edges per turn.
+        actual: f32,
+    },
+    /// A required phase has no turns.
+    MissingRequiredPhase {
+        /// The missing phase.
+        phase: Phase,
+    },
+}
+
+impl SufficiencyViolation {
+    /// Actionable hint for resolving this violation.
+    ///
+    /// Deterministic: the text depends only on the violation's fields.
+    pub fn suggestion(&self) -> String {
+        match self {
+            Self::InsufficientTurns { required, actual } => format!(
+                "Add {} more turn(s): raise max_nodes or widen max_radius",
+                required.saturating_sub(*actual)
+            ),
+            Self::InsufficientRoles { required, actual } => format!(
+                "Include turns from {} more role(s)",
+                required.saturating_sub(*actual)
+            ),
+            Self::InsufficientPhases { required, actual } => format!(
+                "Widen max_radius to capture {} more phase(s)",
+                required.saturating_sub(*actual)
+            ),
+            Self::InsufficientHighSalience { required, actual } => format!(
+                "Add {} more high-salience turn(s): raise salience_weight or pick a more salient anchor",
+                required.saturating_sub(*actual)
+            ),
+            Self::NoExchange => {
+                "Add turns completing one of the policy's exchange_roles pairs".to_string()
+            }
+            Self::LowMeanSalience { required, .. } => format!(
+                "Raise mean salience to at least {:.2}: raise salience_weight or lower max_nodes",
+                required
+            ),
+            Self::EdgeDensityTooHigh { required, .. } => format!(
+                "Reduce edge density to at most {:.2} edges per turn: restrict allowed_edge_types",
+                required
+            ),
+            Self::MissingRequiredPhase { phase } => format!(
+                "Include a {:?} turn: raise its phase weight or widen max_radius",
+                phase
+            ),
+        }
+    }
 }
 
 impl std::fmt::Display for SufficiencyViolation {
@@ -351,15 +755,33 @@ impl std::fmt::Display for SufficiencyViolation {
                 write!(f, "Insufficient high-salience turns: {} required, {} found", required, actual)
             }
             Self::NoExchange => {
-                write!(f, "No meaningful exchange: requires both user and assistant turns")
+                write!(f, "No meaningful exchange: no configured exchange role pair present")
             }
             Self::LowMeanSalience { required, actual } => {
                 write!(f, "Low mean salience: {:.2} required, {:.2} found", required, actual)
             }
+            Self::EdgeDensityTooHigh { required, actual } => {
+                write!(f, "Edge density too high: at most {:.2} edges per turn allowed, {:.2} found", required, actual)
+            }
+            Self::MissingRequiredPhase { phase } => {
+                write!(f, "Missing required phase: no {:?} turns", phase)
+            }
         }
     }
 }
 
+/// Render exchange role pairs as "user and assistant, or user and tool".
+fn describe_exchange_roles(pairs: &[(Role, Role)]) -> String {
+    if pairs.is_empty() {
+        return "exchange roles (none configured)".to_string();
+    }
+    pairs
+        .iter()
+        .map(|(a, b)| format!("{} and {}", a, b))
+        .collect::<Vec<_>>()
+        .join(", or ")
+}
+
 /// Evidence bundle combining admissibility and sufficiency.
 ///
 /// This is the highest-level evidence type, ensuring both:
@@ -400,6 +822,14 @@ pub enum EvidenceBundleError {
     /// Sufficiency policy not satisfied.
     #[error("Evidence does not satisfy sufficiency policy: {0}")]
     InsufficientEvidence(String),
+
+    /// Admissibility token no longer verifies.
+    #[error(transparent)]
+    Verification(#[from] VerificationError),
+
+    /// `slice_id` does not match the slice contents.
+    #[error("Slice fingerprint does not match its contents")]
+    FingerprintMismatch,
 }
 
 impl EvidenceBundle {
@@ -439,6 +869,42 @@ impl EvidenceBundle {
         })
     }
 
+    /// Re-verify a bundle, typically after deserializing it.
+    ///
+    /// Checks the HMAC token and the slice fingerprint, then re-runs
+    /// `policy` against metrics recomputed from the slice. The stored
+    /// metrics are not trusted.
+    pub fn reverify(
+        &self,
+        hmac_secret: &[u8],
+        policy: &SufficiencyPolicy,
+    ) -> Result<(), EvidenceBundleError> {
+        let slice = self.bundle.slice();
+        if !slice.admissibility_token.is_valid_format() {
+            return Err(VerificationError::InvalidTokenFormat(
+                "Token must be 32 hex characters".to_string()
+            ).into());
+        }
+        if !slice.verify_token(hmac_secret) {
+            return Err(VerificationError::TokenMismatch.into());
+        }
+        if !slice.fingerprint_matches() {
+            return Err(EvidenceBundleError::FingerprintMismatch);
+        }
+
+        let check = policy.check(&DiversityMetrics::from_bundle(&self.bundle));
+        if !check.is_sufficient {
+            let violations: Vec<String> = check.violations.iter()
+                .map(|v| v.to_string())
+                .collect();
+            return Err(EvidenceBundleError::InsufficientEvidence(
+                violations.join("; ")
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Create an evidence bundle with lenient policy (for testing).
     #[cfg(test)]
     pub fn from_admissible_lenient(bundle: AdmissibleEvidenceBundle) -> Self {
@@ -585,6 +1051,38 @@ mod tests {
         assert!(policy.is_satisfied(&metrics));
     }
 
+    #[test]
+    fn test_required_phase_missing() {
+        let turns = vec![
+            make_turn(1, Role::User, Phase::Exploration, 0.8, "s1"),
+            make_turn(2, Role::Assistant, Phase::Planning, 0.6, "s1"),
+            make_turn(3, Role::User, Phase::Planning, 0.5, "s1"),
+        ];
+        let metrics = DiversityMetrics::from_bundle(&make_admissible_bundle(turns));
+
+        let policy = SufficiencyPolicy {
+            required_phases: HashSet::from([Phase::Synthesis]),
+            ..SufficiencyPolicy::default()
+        };
+        let check = policy.check(&metrics);
+        assert!(!check.is_sufficient);
+        assert!(!policy.is_satisfied(&metrics));
+        assert!(matches!(
+            check.violations.as_slice(),
+            [SufficiencyViolation::MissingRequiredPhase { phase: Phase::Synthesis }]
+        ));
+
+        // The default (empty) requirement accepts the slice and keeps its hash
+        assert!(SufficiencyPolicy::default().is_satisfied(&metrics));
+        assert_ne!(policy.params_hash(), SufficiencyPolicy::default().params_hash());
+
+        let planning = SufficiencyPolicy {
+            required_phases: HashSet::from([Phase::Planning]),
+            ..SufficiencyPolicy::default()
+        };
+        assert!(planning.is_satisfied(&metrics));
+    }
+
     #[test]
     fn test_sufficiency_policy_insufficient_turns() {
         let turns = vec![
@@ -617,6 +1115,37 @@ mod tests {
         assert!(check.violations.iter().any(|v| matches!(v, SufficiencyViolation::NoExchange)));
     }
 
+    #[test]
+    fn test_custom_exchange_roles_accept_user_tool() {
+        let turns = vec![
+            make_turn(1, Role::User, Phase::Exploration, 0.8, "s1"),
+            make_turn(2, Role::Tool, Phase::Planning, 0.7, "s1"),
+            make_turn(3, Role::User, Phase::Synthesis, 0.9, "s1"),
+        ];
+        let bundle = make_admissible_bundle(turns);
+        let metrics = DiversityMetrics::from_bundle(&bundle);
+
+        let default = SufficiencyPolicy::default();
+        assert!(!default.has_exchange(&metrics));
+        assert!(default.check(&metrics).violations.iter().any(|v| matches!(v, SufficiencyViolation::NoExchange)));
+
+        let tool_augmented = SufficiencyPolicy {
+            exchange_roles: vec![(Role::User, Role::Assistant), (Role::User, Role::Tool)],
+            ..SufficiencyPolicy::default()
+        };
+        assert!(tool_augmented.has_exchange(&metrics));
+        assert!(tool_augmented.check(&metrics).is_sufficient);
+        assert!(tool_augmented.is_satisfied(&metrics));
+
+        // Custom definitions get their own identity; pair order is irrelevant
+        assert_ne!(tool_augmented.params_hash(), default.params_hash());
+        let reordered = SufficiencyPolicy {
+            exchange_roles: vec![(Role::Tool, Role::User), (Role::Assistant, Role::User)],
+            ..SufficiencyPolicy::default()
+        };
+        assert_eq!(reordered.params_hash(), tool_augmented.params_hash());
+    }
+
     #[test]
     fn test_sufficiency_policy_low_salience() {
         let turns = vec![
@@ -716,6 +1245,265 @@ mod tests {
         assert_eq!(v.to_string(), "Insufficient turns: 5 required, 2 found");
 
         let v = SufficiencyViolation::NoExchange;
-        assert!(v.to_string().contains("user and assistant"));
+        assert!(!v.to_string().contains("user and assistant"));
+
+        // The policy names its own exchange roles
+        let policy = SufficiencyPolicy::default();
+        assert_eq!(
+            policy.describe_violation(&v),
+            "No meaningful exchange: requires user and assistant turns"
+        );
+        let policy = SufficiencyPolicy {
+            exchange_roles: vec![(Role::User, Role::Assistant), (Role::User, Role::Tool)],
+            ..SufficiencyPolicy::default()
+        };
+        assert_eq!(
+            policy.describe_violation(&v),
+            "No meaningful exchange: requires user and assistant, or user and tool turns"
+        );
+        let other = SufficiencyViolation::InsufficientTurns { required: 5, actual: 2 };
+        assert_eq!(policy.describe_violation(&other), other.to_string());
+    }
+
+    #[test]
+    fn test_no_exchange_wire_format() {
+        // Unit variant: serialized checks from earlier releases still load
+        let v: SufficiencyViolation = serde_json::from_str("\"NoExchange\"").unwrap();
+        assert!(matches!(v, SufficiencyViolation::NoExchange));
+        assert_eq!(serde_json::to_string(&v).unwrap(), "\"NoExchange\"");
+    }
+
+    #[test]
+    fn test_sufficiency_policy_params_hash() {
+        let hash1 = SufficiencyPolicy::default().params_hash();
+        let hash2 = SufficiencyPolicy::default().params_hash();
+        assert_eq!(hash1, hash2);
+        assert_eq!(hash1.len(), 16);
+
+        assert_ne!(hash1, SufficiencyPolicy::strict().params_hash());
+        assert_ne!(hash1, SufficiencyPolicy::lenient().params_hash());
+    }
+
+    #[test]
+    fn test_salience_percentiles() {
+        let saliences = [0.9, 0.1, 0.5, 0.3, 0.7];
+        let stats = DiversityMetrics::compute_salience_stats(&saliences);
+        assert!((stats.p25 - 0.3).abs() < 1e-6);
+        assert!((stats.median - 0.5).abs() < 1e-6);
+        assert!((stats.p75 - 0.7).abs() < 1e-6);
+
+        // Even count interpolates between the middle values
+        let stats = DiversityMetrics::compute_salience_stats(&[0.2, 0.4, 0.6, 0.8]);
+        assert!((stats.median - 0.5).abs() < 1e-6);
+        assert!((stats.p25 - 0.35).abs() < 1e-6);
+        assert!((stats.p75 - 0.65).abs() < 1e-6);
+
+        let empty = DiversityMetrics::compute_salience_stats(&[]);
+        assert_eq!((empty.median, empty.p25, empty.p75), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_accumulator_matches_batch() {
+        // Deterministic pseudo-random turn sequence (LCG)
+        let mut seed: u64 = 0x5eed;
+        let mut next = || {
+            seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (seed >> 33) as u32
+        };
+        let roles = [Role::User, Role::Assistant, Role::System];
+        let phases = [Phase::Exploration, Phase::Debugging, Phase::Planning, Phase::Synthesis];
+        let turns: Vec<TurnSnapshot> = (0..40)
+            .map(|i| {
+                make_turn(
+                    i + 1,
+                    roles[next() as usize % roles.len()],
+                    phases[next() as usize % phases.len()],
+                    (next() % 1000) as f32 / 1000.0,
+                    &format!("session_{}", next() % 5),
+                )
+            })
+            .collect();
+
+        let mut acc = DiversityAccumulator::new();
+        for turn in &turns {
+            acc.add_turn(turn);
+        }
+        let incremental = acc.finalize();
+        let batch = DiversityMetrics::from_bundle(&make_admissible_bundle(turns.clone()));
+
+        assert_eq!(incremental.turn_count, 40);
+        assert_eq!(incremental.turn_count, batch.turn_count);
+        assert_eq!(incremental.unique_roles, batch.unique_roles);
+        assert_eq!(incremental.role_distribution, batch.role_distribution);
+        assert_eq!(incremental.unique_phases, batch.unique_phases);
+        assert_eq!(incremental.phase_distribution, batch.phase_distribution);
+        assert_eq!(incremental.unique_sessions, batch.unique_sessions);
+        assert_eq!(incremental.has_exchange, batch.has_exchange);
+
+        let (inc, bat) = (&incremental.salience_stats, &batch.salience_stats);
+        assert_eq!(
+            (inc.min, inc.max, inc.high_salience_count, inc.median, inc.p25, inc.p75),
+            (bat.min, bat.max, bat.high_salience_count, bat.median, bat.p25, bat.p75)
+        );
+
+        // Welford agrees with the two-pass mean and std dev up to rounding
+        assert!((inc.mean - bat.mean).abs() < 1e-5);
+        assert!((inc.std_dev - bat.std_dev).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_phase_and_role_entropy() {
+        let phases = [Phase::Exploration, Phase::Debugging, Phase::Planning, Phase::Synthesis];
+        let uniform: Vec<_> = (0..8)
+            .map(|i| make_turn(i + 1, Role::User, phases[i as usize % 4], 0.5, "s"))
+            .collect();
+        let metrics = DiversityMetrics::from_bundle(&make_admissible_bundle(uniform));
+        assert!((metrics.phase_entropy() - 2.0).abs() < 1e-6);
+        assert_eq!(metrics.role_entropy(), 0.0);
+
+        let single: Vec<_> = (0..8)
+            .map(|i| make_turn(i + 1, Role::User, Phase::Planning, 0.5, "s"))
+            .collect();
+        let metrics = DiversityMetrics::from_bundle(&make_admissible_bundle(single));
+        assert_eq!(metrics.phase_entropy(), 0.0);
+
+        // Even user/assistant split is one bit
+        let exchange: Vec<_> = (0..4)
+            .map(|i| {
+                let role = if i % 2 == 0 { Role::User } else { Role::Assistant };
+                make_turn(i + 1, role, Phase::Planning, 0.5, "s")
+            })
+            .collect();
+        let metrics = DiversityMetrics::from_bundle(&make_admissible_bundle(exchange));
+        assert!((metrics.role_entropy() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_salience_stats_deserialize_without_percentiles() {
+        let json = r#"{"min":0.1,"max":0.9,"mean":0.5,"std_dev":0.2,"high_salience_count":1}"#;
+        let stats: SalienceStats = serde_json::from_str(json).unwrap();
+        assert_eq!(stats.high_salience_count, 1);
+        assert_eq!((stats.median, stats.p25, stats.p75), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_suggestions_per_violation() {
+        let violations = vec![
+            SufficiencyViolation::InsufficientTurns { required: 3, actual: 1 },
+            SufficiencyViolation::InsufficientRoles { required: 2, actual: 1 },
+            SufficiencyViolation::InsufficientPhases { required: 3, actual: 1 },
+            SufficiencyViolation::InsufficientHighSalience { required: 2, actual: 0 },
+            SufficiencyViolation::NoExchange,
+            SufficiencyViolation::LowMeanSalience { required: 0.5, actual: 0.2 },
+            SufficiencyViolation::EdgeDensityTooHigh { required: 1.0, actual: 1.5 },
+            SufficiencyViolation::MissingRequiredPhase { phase: Phase::Planning },
+        ];
+        let check = SufficiencyCheck {
+            is_sufficient: false,
+            violations,
+            metrics: DiversityAccumulator::new().finalize(),
+        };
+
+        assert_eq!(
+            check.suggestions(),
+            vec![
+                "Add 2 more turn(s): raise max_nodes or widen max_radius",
+                "Include turns from 1 more role(s)",
+                "Widen max_radius to capture 2 more phase(s)",
+                "Add 2 more high-salience turn(s): raise salience_weight or pick a more salient anchor",
+                "Add turns completing one of the policy's exchange_roles pairs",
+                "Raise mean salience to at least 0.50: raise salience_weight or lower max_nodes",
+                "Reduce edge density to at most 1.00 edges per turn: restrict allowed_edge_types",
+                "Include a Planning turn: raise its phase weight or widen max_radius",
+            ]
+        );
+        assert_eq!(check.suggestions(), check.suggestions());
+    }
+
+    #[test]
+    fn test_edge_density_too_high() {
+        use crate::types::{Edge, EdgeType};
+
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let turns: Vec<TurnSnapshot> = (1..=4)
+            .map(|i| make_turn(i, if i % 2 == 0 { Role::Assistant } else { Role::User }, Phase::Synthesis, 0.8, "s1"))
+            .collect();
+        let id = |n: u128| TurnId::new(Uuid::from_u128(n));
+
+        // Complete DAG on 4 turns: 6 edges, 1.5 edges per turn
+        let mut edges = Vec::new();
+        for parent in 1..=4u128 {
+            for child in (parent + 1)..=4 {
+                edges.push(Edge::new(id(parent), id(child), EdgeType::Reference));
+            }
+        }
+
+        let slice = SliceExport::new_with_secret(
+            secret,
+            id(1),
+            turns,
+            edges,
+            "test_policy".to_string(),
+            "params_hash".to_string(),
+            GraphSnapshotHash::new("test_snapshot".to_string()),
+        );
+        let bundle = AdmissibleEvidenceBundle::from_verified(slice, secret).unwrap();
+        let metrics = DiversityMetrics::from_bundle(&bundle);
+        assert_eq!(metrics.edge_count, 6);
+        assert!((metrics.edge_turn_ratio() - 1.5).abs() < 1e-6);
+
+        let policy = SufficiencyPolicy {
+            max_edge_turn_ratio: Some(1.0),
+            ..SufficiencyPolicy::default()
+        };
+        let check = policy.check(&metrics);
+        assert!(!check.is_sufficient);
+        assert!(!policy.is_satisfied(&metrics));
+        assert!(matches!(
+            check.violations.as_slice(),
+            [SufficiencyViolation::EdgeDensityTooHigh { .. }]
+        ));
+
+        // Unset ratio keeps the default hash and accepts the slice
+        assert!(SufficiencyPolicy::default().is_satisfied(&metrics));
+        assert_ne!(policy.params_hash(), SufficiencyPolicy::default().params_hash());
+    }
+
+    #[test]
+    fn test_reverify_detects_tampering() {
+        let secret = b"test_kernel_secret_32_bytes_min!";
+        let diverse = make_admissible_bundle(vec![
+            make_turn(1, Role::User, Phase::Synthesis, 0.9, "s1"),
+            make_turn(2, Role::Assistant, Phase::Synthesis, 0.9, "s1"),
+            make_turn(3, Role::User, Phase::Planning, 0.8, "s1"),
+        ]);
+        let evidence = EvidenceBundle::from_admissible(diverse, &SufficiencyPolicy::default(), "default").unwrap();
+        assert!(evidence.reverify(secret, &SufficiencyPolicy::default()).is_ok());
+        assert!(matches!(
+            evidence.reverify(b"wrong_secret_32_bytes_minimum!!!", &SufficiencyPolicy::default()),
+            Err(EvidenceBundleError::Verification(VerificationError::TokenMismatch))
+        ));
+
+        // Injected turn: token still covers the old slice_id
+        let mut json = serde_json::to_value(&evidence).unwrap();
+        let turns = json["bundle"]["slice"]["turns"].as_array_mut().unwrap();
+        let mut injected = turns[0].clone();
+        injected["id"] = serde_json::json!(Uuid::from_u128(99));
+        turns.push(injected);
+        let tampered: EvidenceBundle = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            tampered.reverify(secret, &SufficiencyPolicy::default()),
+            Err(EvidenceBundleError::FingerprintMismatch)
+        ));
+
+        // Inflated metrics on a thin bundle are ignored
+        let thin = make_admissible_bundle(vec![make_turn(1, Role::User, Phase::Exploration, 0.1, "s1")]);
+        let mut json = serde_json::to_value(EvidenceBundle::from_admissible_lenient(thin)).unwrap();
+        json["metrics"] = serde_json::to_value(evidence.metrics()).unwrap();
+        let inflated: EvidenceBundle = serde_json::from_value(json).unwrap();
+        assert!(matches!(
+            inflated.reverify(secret, &SufficiencyPolicy::default()),
+            Err(EvidenceBundleError::InsufficientEvidence(_))
+        ));
     }
 }
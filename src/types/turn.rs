@@ -17,6 +17,7 @@ impl TurnId {
     }
 
     /// Create a new TurnId from a UUID string.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, uuid::Error> {
         Ok(Self(Uuid::parse_str(s)?))
     }
@@ -46,9 +47,10 @@ impl From<Uuid> for TurnId {
 }
 
 /// Role of the turn author.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Role {
     /// User message.
+    #[default]
     User,
     /// Assistant/AI response.
     Assistant,
@@ -60,6 +62,7 @@ pub enum Role {
 
 impl Role {
     /// Parse role from string.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "user" => Some(Self::User),
@@ -69,11 +72,15 @@ impl Role {
             _ => None,
         }
     }
-}
 
-impl Default for Role {
-    fn default() -> Self {
-        Self::User
+    /// Role name used by OpenAI-style chat APIs.
+    pub fn chat_role(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Assistant => "assistant",
+            Self::System => "system",
+            Self::Tool => "tool",
+        }
     }
 }
 
@@ -92,9 +99,10 @@ impl fmt::Display for Role {
 ///
 /// Phases are ordered by their typical importance for context:
 /// Synthesis > Planning > Consolidation > Debugging > Exploration
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Phase {
     /// Exploratory thinking, brainstorming.
+    #[default]
     Exploration,
     /// Debugging, troubleshooting.
     Debugging,
@@ -108,6 +116,7 @@ pub enum Phase {
 
 impl Phase {
     /// Parse phase from string.
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "exploration" => Some(Self::Exploration),
@@ -131,12 +140,6 @@ impl Phase {
     }
 }
 
-impl Default for Phase {
-    fn default() -> Self {
-        Self::Exploration
-    }
-}
-
 impl fmt::Display for Phase {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -179,6 +182,10 @@ pub struct TurnSnapshot {
     pub created_at: i64,
     /// SHA-256 hash of content_text for immutable graph snapshots.
     pub content_hash: Option<String>,
+    /// The stored phase was missing or unrecognized; `phase` holds the
+    /// `Exploration` placeholder. See `SlicePolicyV1::unknown_phase_weight`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub phase_unknown: bool,
 }
 
 impl TurnSnapshot {
@@ -210,6 +217,7 @@ impl TurnSnapshot {
             trajectory_complexity,
             created_at,
             content_hash: None,
+            phase_unknown: false,
         }
     }
 
@@ -242,6 +250,7 @@ impl TurnSnapshot {
             trajectory_complexity,
             created_at,
             content_hash,
+            phase_unknown: false,
         }
     }
 
@@ -251,6 +260,24 @@ impl TurnSnapshot {
         self
     }
 
+    /// Set the phase from its stored string form.
+    ///
+    /// A missing or unrecognized value falls back to `Phase::Exploration`
+    /// and sets `phase_unknown`.
+    pub fn with_parsed_phase(mut self, phase: Option<&str>) -> Self {
+        match phase.and_then(Phase::from_str) {
+            Some(parsed) => {
+                self.phase = parsed;
+                self.phase_unknown = false;
+            }
+            None => {
+                self.phase = Phase::default();
+                self.phase_unknown = true;
+            }
+        }
+        self
+    }
+
     /// Verify content hash matches actual content.
     ///
     /// # Arguments
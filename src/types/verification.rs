@@ -16,6 +16,12 @@
 //! |------|----------|-------------|----------|
 //! | `LocalSecret` | Single-node deployment | ~100μs | Full HMAC verification |
 //! | `Cached` | High-throughput services | ~10μs (cache hit) | Full HMAC + LRU cache |
+//! | `Remote` | Edge services without the secret | network RTT (cache miss) | Kernel service HMAC + LRU cache |
+//! | `PerPolicy` | Multi-tenant kernels | ~10μs (cache hit) | Per-policy HMAC + LRU cache |
+//!
+//! `Remote` requires the `remote-verify` feature. Its HTTP call is blocking;
+//! async services must run `Remote` verification inside
+//! `tokio::task::spawn_blocking` (or an equivalent blocking pool).
 //!
 //! ## Cache Key Design
 //!
@@ -27,19 +33,55 @@
 //! - `graph_snapshot_hash`
 //! - `schema_version`
 //! - `admissibility_token`
+//! - `issued_at_unix_ms` (when present)
 //!
 //! This ensures that any parameter change results in a cache miss and full verification.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use parking_lot::RwLock;
 use lru::LruCache;
 use std::num::NonZeroUsize;
 use std::hash::{Hash, Hasher};
+use serde::{Deserialize, Serialize};
 use xxhash_rust::xxh64::Xxh64;
 
+use super::incident::{Incident, IncidentType};
 use super::slice::{SliceFingerprint, GraphSnapshotHash, AdmissibilityToken};
 use super::turn::TurnId;
 
+/// Selects the HMAC secret for a policy.
+///
+/// Lets a multi-tenant kernel sign each policy with its own key, so a
+/// leaked secret only exposes the tokens of the policies it is mapped to.
+/// The token already binds `policy_id`; the resolver only picks the key.
+pub trait SecretResolver: Send + Sync {
+    /// Secret for tokens issued under `policy_id`, or `None` if the policy
+    /// has no key (signing and verification then fail closed).
+    fn secret_for(&self, policy_id: &str) -> Option<&[u8]>;
+}
+
+/// One secret for every policy.
+impl SecretResolver for Vec<u8> {
+    fn secret_for(&self, _policy_id: &str) -> Option<&[u8]> {
+        Some(self)
+    }
+}
+
+/// Secrets keyed by `policy_id`; unlisted policies have no key.
+impl SecretResolver for HashMap<String, Vec<u8>> {
+    fn secret_for(&self, policy_id: &str) -> Option<&[u8]> {
+        self.get(policy_id).map(Vec::as_slice)
+    }
+}
+
+impl std::fmt::Debug for dyn SecretResolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print key material
+        f.write_str("SecretResolver")
+    }
+}
+
 /// Configuration for the token verification cache.
 #[derive(Debug, Clone)]
 pub struct CacheConfig {
@@ -78,6 +120,38 @@ pub enum VerificationMode {
         /// Cache configuration.
         config: CacheConfig,
     },
+
+    /// Verify with the secret a resolver maps the token's `policy_id` to.
+    ///
+    /// Best for: Multi-tenant kernels with one secret per policy. Tokens for
+    /// policies the resolver has no key for fail with `UnknownPolicy` and
+    /// are never cached.
+    PerPolicy {
+        /// Maps `policy_id` to the HMAC secret.
+        resolver: Arc<dyn SecretResolver>,
+        /// Cache configuration.
+        config: CacheConfig,
+    },
+
+    /// Verify by calling a kernel service's `POST /api/verify_token`.
+    ///
+    /// Best for: Edge services that must never hold the HMAC secret.
+    /// Remote answers are cached like local results; transport failures
+    /// are reported as `RemoteUnavailable` and never cached.
+    ///
+    /// A cache miss blocks the calling thread for up to the client timeout.
+    /// From async code, call the verifier inside `spawn_blocking`. The
+    /// endpoint must be `https` (plain `http` only to loopback addresses);
+    /// other endpoints fail with `RemoteUnavailable`.
+    #[cfg(feature = "remote-verify")]
+    Remote {
+        /// Base URL of the kernel service; may include a path prefix.
+        endpoint: url::Url,
+        /// HTTP client used for verification calls.
+        client: ureq::Agent,
+        /// Cache configuration.
+        config: CacheConfig,
+    },
 }
 
 impl VerificationMode {
@@ -98,6 +172,30 @@ impl VerificationMode {
     pub fn cached_with_config(secret: Vec<u8>, config: CacheConfig) -> Self {
         Self::Cached { secret, config }
     }
+
+    /// Create a per-policy verification mode with default cache configuration.
+    pub fn per_policy(resolver: Arc<dyn SecretResolver>) -> Self {
+        Self::PerPolicy {
+            resolver,
+            config: CacheConfig::default(),
+        }
+    }
+
+    /// Create a remote verification mode with a default client and cache.
+    ///
+    /// Rejects endpoints that are not `https`, except loopback addresses,
+    /// so a network attacker cannot answer `valid: true`.
+    #[cfg(feature = "remote-verify")]
+    pub fn remote(endpoint: url::Url) -> Result<Self, RemoteEndpointError> {
+        verify_token_url(&endpoint)?;
+        Ok(Self::Remote {
+            endpoint,
+            client: ureq::AgentBuilder::new()
+                .timeout(std::time::Duration::from_secs(5))
+                .build(),
+            config: CacheConfig::default(),
+        })
+    }
 }
 
 /// Cache key for token verification.
@@ -108,6 +206,7 @@ struct VerificationCacheKey(u64);
 
 impl VerificationCacheKey {
     /// Compute the cache key from verification parameters.
+    #[allow(clippy::too_many_arguments)]
     fn compute(
         slice_id: &SliceFingerprint,
         anchor_turn_id: &TurnId,
@@ -116,6 +215,7 @@ impl VerificationCacheKey {
         graph_snapshot_hash: &GraphSnapshotHash,
         schema_version: &str,
         token: &AdmissibilityToken,
+        issued_at_unix_ms: Option<i64>,
     ) -> Self {
         let mut hasher = Xxh64::new(0);
 
@@ -126,11 +226,44 @@ impl VerificationCacheKey {
         hasher.write(graph_snapshot_hash.as_str().as_bytes());
         hasher.write(schema_version.as_bytes());
         hasher.write(token.as_str().as_bytes());
+        if let Some(issued_at) = issued_at_unix_ms {
+            hasher.write(&issued_at.to_le_bytes());
+        }
 
         Self(hasher.finish())
     }
 }
 
+/// Why a token failed verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureKind {
+    /// Token is not 32 hex characters; no HMAC was computed.
+    InvalidFormat,
+    /// Token is well-formed but does not match the expected HMAC.
+    ///
+    /// This is the security-relevant case (forgery, tampering or a secret
+    /// mismatch) and warrants an INV-GK-005 incident.
+    HmacMismatch,
+    /// The remote verifier could not be reached or answered unexpectedly,
+    /// so the token was not checked. Verification fails closed.
+    RemoteUnavailable,
+    /// No secret is configured for the token's `policy_id`, so the token
+    /// was not checked. Verification fails closed.
+    UnknownPolicy,
+}
+
+impl std::fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "invalid_format"),
+            Self::HmacMismatch => write!(f, "hmac_mismatch"),
+            Self::RemoteUnavailable => write!(f, "remote_unavailable"),
+            Self::UnknownPolicy => write!(f, "unknown_policy"),
+        }
+    }
+}
+
 /// Result of a cached verification.
 #[derive(Debug, Clone, Copy)]
 pub struct VerificationResult {
@@ -138,16 +271,48 @@ pub struct VerificationResult {
     pub is_valid: bool,
     /// Whether this result came from cache.
     pub cache_hit: bool,
+    /// Why verification failed (`None` when valid).
+    pub failure_kind: Option<FailureKind>,
+}
+
+impl VerificationResult {
+    fn from_cached(is_valid: bool, cache_hit: bool) -> Self {
+        Self {
+            is_valid,
+            cache_hit,
+            failure_kind: if is_valid { None } else { Some(FailureKind::HmacMismatch) },
+        }
+    }
+
+    /// Build a `TokenVerificationFailure` incident for a genuine mismatch.
+    ///
+    /// Returns `None` for valid tokens, for malformed tokens, which are
+    /// client errors rather than security signals, and for tokens a remote
+    /// verifier could not check.
+    pub fn incident(&self, slice_id: &SliceFingerprint, source: impl Into<String>) -> Option<Incident> {
+        match self.failure_kind {
+            Some(FailureKind::HmacMismatch) => Some(Incident::new(
+                IncidentType::TokenVerificationFailure {
+                    slice_fingerprint: slice_id.as_str().to_string(),
+                    reason: "Token does not match expected HMAC".to_string(),
+                },
+                source,
+            )),
+            _ => None,
+        }
+    }
 }
 
 /// Token verifier with optional caching.
 ///
-/// Thread-safe and suitable for use in async services.
+/// Thread-safe and suitable for use in async services. Verification is
+/// synchronous; with `VerificationMode::Remote` a cache miss performs a
+/// blocking HTTP call, so run it on a blocking thread.
 ///
 /// # Example
 ///
 /// ```rust,ignore
-/// use cc_graph_kernel::types::verification::{TokenVerifier, VerificationMode};
+/// use admissibility_kernel::types::verification::{TokenVerifier, VerificationMode};
 ///
 /// // Create a cached verifier
 /// let verifier = TokenVerifier::new(VerificationMode::cached(secret.to_vec()));
@@ -167,7 +332,14 @@ impl TokenVerifier {
     /// Create a new token verifier with the specified mode.
     pub fn new(mode: VerificationMode) -> Self {
         let cache = match &mode {
-            VerificationMode::Cached { config, .. } if config.enabled => {
+            VerificationMode::Cached { config, .. } | VerificationMode::PerPolicy { config, .. }
+                if config.enabled =>
+            {
+                let size = NonZeroUsize::new(config.max_entries).unwrap_or(NonZeroUsize::new(1000).unwrap());
+                Some(Arc::new(RwLock::new(LruCache::new(size))))
+            }
+            #[cfg(feature = "remote-verify")]
+            VerificationMode::Remote { config, .. } if config.enabled => {
                 let size = NonZeroUsize::new(config.max_entries).unwrap_or(NonZeroUsize::new(1000).unwrap());
                 Some(Arc::new(RwLock::new(LruCache::new(size))))
             }
@@ -177,12 +349,55 @@ impl TokenVerifier {
         Self { mode, cache }
     }
 
-    /// Get the HMAC secret from the verification mode.
-    fn secret(&self) -> &[u8] {
-        match &self.mode {
-            VerificationMode::LocalSecret { secret } => secret,
-            VerificationMode::Cached { secret, .. } => secret,
-        }
+    /// Check a well-formed token without consulting the cache.
+    ///
+    /// Returns `Err(FailureKind::RemoteUnavailable)` when no answer could
+    /// be obtained, or `Err(FailureKind::UnknownPolicy)` when no secret
+    /// applies; such outcomes must not be cached.
+    #[allow(clippy::too_many_arguments)]
+    fn check_uncached(
+        &self,
+        token: &AdmissibilityToken,
+        slice_id: &SliceFingerprint,
+        anchor_turn_id: &TurnId,
+        policy_id: &str,
+        policy_params_hash: &str,
+        graph_snapshot_hash: &GraphSnapshotHash,
+        schema_version: &str,
+        issued_at_unix_ms: Option<i64>,
+    ) -> Result<bool, FailureKind> {
+        let secret = match &self.mode {
+            VerificationMode::LocalSecret { secret } => secret.as_slice(),
+            VerificationMode::Cached { secret, .. } => secret.as_slice(),
+            VerificationMode::PerPolicy { resolver, .. } => resolver
+                .secret_for(policy_id)
+                .ok_or(FailureKind::UnknownPolicy)?,
+            #[cfg(feature = "remote-verify")]
+            VerificationMode::Remote { endpoint, client, .. } => {
+                let body = serde_json::json!({
+                    "admissibility_token": token.as_str(),
+                    "slice_id": slice_id.as_str(),
+                    "anchor_turn_id": anchor_turn_id.to_string(),
+                    "policy_id": policy_id,
+                    "policy_params_hash": policy_params_hash,
+                    "graph_snapshot_hash": graph_snapshot_hash.as_str(),
+                    "schema_version": schema_version,
+                    "issued_at_unix_ms": issued_at_unix_ms,
+                });
+                return remote_verify(endpoint, client, &body);
+            }
+        };
+
+        Ok(token.verify_hmac_at(
+            secret,
+            slice_id,
+            anchor_turn_id,
+            policy_id,
+            policy_params_hash,
+            graph_snapshot_hash,
+            schema_version,
+            issued_at_unix_ms,
+        ))
     }
 
     /// Verify an admissibility token.
@@ -193,7 +408,9 @@ impl TokenVerifier {
     /// * All fields that were used to issue the token
     ///
     /// # Returns
-    /// `VerificationResult` with validity and cache hit status
+    /// `VerificationResult` with validity, cache hit status and, on failure,
+    /// whether the token was malformed or a genuine HMAC mismatch
+    #[allow(clippy::too_many_arguments)]
     pub fn verify_token(
         &self,
         token: &AdmissibilityToken,
@@ -204,6 +421,40 @@ impl TokenVerifier {
         graph_snapshot_hash: &GraphSnapshotHash,
         schema_version: &str,
     ) -> VerificationResult {
+        self.verify_token_at(
+            token,
+            slice_id,
+            anchor_turn_id,
+            policy_id,
+            policy_params_hash,
+            graph_snapshot_hash,
+            schema_version,
+            None,
+        )
+    }
+
+    /// Verify a token that binds an issue time (see `AdmissibilityToken::issue_hmac_at`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_token_at(
+        &self,
+        token: &AdmissibilityToken,
+        slice_id: &SliceFingerprint,
+        anchor_turn_id: &TurnId,
+        policy_id: &str,
+        policy_params_hash: &str,
+        graph_snapshot_hash: &GraphSnapshotHash,
+        schema_version: &str,
+        issued_at_unix_ms: Option<i64>,
+    ) -> VerificationResult {
+        // Malformed tokens never reach the HMAC (or the cache)
+        if !token.is_valid_format() {
+            return VerificationResult {
+                is_valid: false,
+                cache_hit: false,
+                failure_kind: Some(FailureKind::InvalidFormat),
+            };
+        }
+
         // Compute cache key
         let cache_key = VerificationCacheKey::compute(
             slice_id,
@@ -213,46 +464,56 @@ impl TokenVerifier {
             graph_snapshot_hash,
             schema_version,
             token,
+            issued_at_unix_ms,
         );
 
         // Check cache first (if enabled)
         if let Some(cache) = &self.cache {
             // Try read lock first (non-blocking for other readers)
             if let Some(&is_valid) = cache.read().peek(&cache_key) {
-                return VerificationResult {
-                    is_valid,
-                    cache_hit: true,
-                };
+                return VerificationResult::from_cached(is_valid, true);
             }
         }
 
-        // Cache miss - perform full HMAC verification
-        let is_valid = token.verify_hmac(
-            self.secret(),
+        // Cache miss - perform full verification
+        let is_valid = match self.check_uncached(
+            token,
             slice_id,
             anchor_turn_id,
             policy_id,
             policy_params_hash,
             graph_snapshot_hash,
             schema_version,
-        );
+            issued_at_unix_ms,
+        ) {
+            Ok(is_valid) => is_valid,
+            Err(failure_kind) => {
+                return VerificationResult {
+                    is_valid: false,
+                    cache_hit: false,
+                    failure_kind: Some(failure_kind),
+                };
+            }
+        };
 
         // Update cache (if enabled)
         if let Some(cache) = &self.cache {
             cache.write().put(cache_key, is_valid);
         }
 
-        VerificationResult {
-            is_valid,
-            cache_hit: false,
-        }
+        VerificationResult::from_cached(is_valid, false)
     }
 
     /// Verify a `SliceExport` using its embedded token.
     ///
     /// This is the high-level verification method for typical use cases.
     pub fn verify_slice(&self, slice: &super::slice::SliceExport) -> VerificationResult {
-        self.verify_token(
+        // Derivation metadata that disagrees with the signed policy_id is tampering
+        if !slice.derivation_matches() {
+            return VerificationResult::from_cached(false, false);
+        }
+
+        self.verify_token_at(
             &slice.admissibility_token,
             &slice.slice_id,
             &slice.anchor_turn_id,
@@ -260,6 +521,7 @@ impl TokenVerifier {
             &slice.policy_params_hash,
             &slice.graph_snapshot_hash,
             &slice.schema_version,
+            slice.issued_at_unix_ms,
         )
     }
 
@@ -286,6 +548,72 @@ impl TokenVerifier {
     }
 }
 
+/// Reasons a remote verification endpoint is refused.
+#[cfg(feature = "remote-verify")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RemoteEndpointError {
+    /// The endpoint is neither `https` nor a loopback address.
+    #[error("remote verification endpoint must use https: {0}")]
+    InsecureScheme(String),
+    /// The endpoint cannot carry a path (e.g. `data:` URLs).
+    #[error("remote verification endpoint cannot be a base URL: {0}")]
+    CannotBeABase(String),
+}
+
+/// `endpoint` with `api/verify_token` appended to its path.
+///
+/// Keeps any path prefix (`https://host/kernel` becomes
+/// `https://host/kernel/api/verify_token`). Plain `http` is accepted only
+/// for loopback hosts.
+#[cfg(feature = "remote-verify")]
+fn verify_token_url(endpoint: &url::Url) -> Result<url::Url, RemoteEndpointError> {
+    let loopback = match endpoint.host() {
+        Some(url::Host::Domain(domain)) => domain.eq_ignore_ascii_case("localhost"),
+        Some(url::Host::Ipv4(ip)) => ip.is_loopback(),
+        Some(url::Host::Ipv6(ip)) => ip.is_loopback(),
+        None => false,
+    };
+    if endpoint.scheme() != "https" && !(endpoint.scheme() == "http" && loopback) {
+        return Err(RemoteEndpointError::InsecureScheme(endpoint.to_string()));
+    }
+
+    let mut url = endpoint.clone();
+    url.path_segments_mut()
+        .map_err(|_| RemoteEndpointError::CannotBeABase(endpoint.to_string()))?
+        .pop_if_empty()
+        .extend(["api", "verify_token"]);
+    Ok(url)
+}
+
+/// Ask a kernel service whether a token is valid.
+#[cfg(feature = "remote-verify")]
+fn remote_verify(
+    endpoint: &url::Url,
+    client: &ureq::Agent,
+    body: &serde_json::Value,
+) -> Result<bool, FailureKind> {
+    #[derive(Deserialize)]
+    struct RemoteResponse {
+        valid: bool,
+    }
+
+    let unavailable = |e: &dyn std::fmt::Display| {
+        tracing::warn!(endpoint = %endpoint, error = %e, "Remote token verification failed");
+        FailureKind::RemoteUnavailable
+    };
+
+    let url = verify_token_url(endpoint).map_err(|e| unavailable(&e))?;
+    let response = client
+        .post(url.as_str())
+        .set("Content-Type", "application/json")
+        .send_string(&body.to_string())
+        .map_err(|e| unavailable(&e))?;
+    let text = response.into_string().map_err(|e| unavailable(&e))?;
+    let parsed: RemoteResponse = serde_json::from_str(&text).map_err(|e| unavailable(&e))?;
+
+    Ok(parsed.valid)
+}
+
 /// Cache statistics.
 #[derive(Debug, Clone, Copy)]
 pub struct CacheStats {
@@ -389,6 +717,38 @@ mod tests {
         assert!(!result.is_valid);
     }
 
+    #[test]
+    fn test_failure_kind_distinguishes_format_from_mismatch() {
+        let secret = b"correct_secret_32_bytes_minimum!";
+        let verifier = TokenVerifier::new(VerificationMode::cached(secret.to_vec()));
+
+        let valid = verifier.verify_slice(&make_slice(secret));
+        assert!(valid.is_valid);
+        assert_eq!(valid.failure_kind, None);
+
+        // Malformed: not hex
+        let mut malformed = make_slice(secret);
+        malformed.admissibility_token = AdmissibilityToken::from_string("not-a-token".to_string());
+        let result = verifier.verify_slice(&malformed);
+        assert!(!result.is_valid);
+        assert_eq!(result.failure_kind, Some(FailureKind::InvalidFormat));
+        assert!(result.incident(&malformed.slice_id, "test").is_none());
+
+        // Well-formed token signed with a different secret
+        let forged = make_slice(b"wrong_secret_totally_different!!");
+        let result = verifier.verify_slice(&forged);
+        assert!(!result.is_valid);
+        assert_eq!(result.failure_kind, Some(FailureKind::HmacMismatch));
+
+        let incident = result.incident(&forged.slice_id, "test").unwrap();
+        assert_eq!(incident.incident_type.invariant(), "INV-GK-005");
+
+        // Cached mismatches keep their kind
+        let cached = verifier.verify_slice(&forged);
+        assert!(cached.cache_hit);
+        assert_eq!(cached.failure_kind, Some(FailureKind::HmacMismatch));
+    }
+
     #[test]
     fn test_cache_clear() {
         let secret = b"test_kernel_secret_32_bytes_min!";
@@ -463,6 +823,7 @@ mod tests {
             &snapshot,
             "1.0.0",
             &token,
+            None,
         );
 
         let key2 = VerificationCacheKey::compute(
@@ -473,6 +834,7 @@ mod tests {
             &snapshot,
             "1.0.0",
             &token,
+            None,
         );
 
         assert_ne!(key1, key2);
@@ -499,4 +861,117 @@ mod tests {
         assert!(!result2.is_valid);
         assert!(result2.cache_hit); // Invalid results are also cached
     }
+
+    /// Serve `POST {prefix}/api/verify_token`, accepting only `valid_token`.
+    #[cfg(feature = "remote-verify")]
+    fn spawn_mock_verifier(prefix: &'static str, valid_token: String) -> (url::Url, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+                let valid = request_line.starts_with(&format!("POST {prefix}/api/verify_token "))
+                    && request["admissibility_token"] == valid_token.as_str();
+                let reply = serde_json::json!({ "valid": valid }).to_string();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    reply.len(),
+                    reply
+                )
+                .unwrap();
+            }
+        });
+
+        (url::Url::parse(&format!("http://{addr}{prefix}")).unwrap(), calls)
+    }
+
+    #[cfg(feature = "remote-verify")]
+    #[test]
+    fn test_remote_verification_is_cached() {
+        use std::sync::atomic::Ordering;
+
+        // The secret stays with the "service"; the verifier never sees it
+        let slice = make_slice(b"kernel_secret_held_by_the_service");
+        let (endpoint, calls) = spawn_mock_verifier("", slice.admissibility_token.as_str().to_string());
+        let verifier = TokenVerifier::new(VerificationMode::remote(endpoint).unwrap());
+
+        let first = verifier.verify_slice(&slice);
+        assert!(first.is_valid);
+        assert!(!first.cache_hit);
+        let second = verifier.verify_slice(&slice);
+        assert!(second.is_valid);
+        assert!(second.cache_hit);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // A well-formed token the service rejects is also cached
+        let mut forged = slice.clone();
+        forged.admissibility_token = AdmissibilityToken::from_string("0".repeat(32));
+        let rejected = verifier.verify_slice(&forged);
+        assert!(!rejected.is_valid);
+        assert_eq!(rejected.failure_kind, Some(FailureKind::HmacMismatch));
+        assert!(verifier.verify_slice(&forged).cache_hit);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[cfg(feature = "remote-verify")]
+    #[test]
+    fn test_remote_unavailable_fails_closed() {
+        // Bind and drop to get a port nothing listens on
+        let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let endpoint = url::Url::parse(&format!("http://127.0.0.1:{port}")).unwrap();
+        let verifier = TokenVerifier::new(VerificationMode::remote(endpoint).unwrap());
+
+        let result = verifier.verify_slice(&make_slice(b"test_kernel_secret_32_bytes_min!"));
+        assert!(!result.is_valid);
+        assert_eq!(result.failure_kind, Some(FailureKind::RemoteUnavailable));
+        assert!(result.incident(&SliceFingerprint::new("s".to_string()), "test").is_none());
+        assert_eq!(verifier.cache_stats().unwrap().len, 0);
+    }
+
+    #[cfg(feature = "remote-verify")]
+    #[test]
+    fn test_remote_endpoint_keeps_prefix_and_requires_https() {
+        let slice = make_slice(b"kernel_secret_held_by_the_service");
+        let (endpoint, _) = spawn_mock_verifier("/kernel", slice.admissibility_token.as_str().to_string());
+        let verifier = TokenVerifier::new(VerificationMode::remote(endpoint).unwrap());
+        assert!(verifier.verify_slice(&slice).is_valid);
+
+        let parse = |s: &str| url::Url::parse(s).unwrap();
+        assert_eq!(
+            verify_token_url(&parse("https://kernel.example/v1/")).unwrap().as_str(),
+            "https://kernel.example/v1/api/verify_token"
+        );
+        assert!(matches!(
+            VerificationMode::remote(parse("http://kernel.example")),
+            Err(RemoteEndpointError::InsecureScheme(_))
+        ));
+        assert!(VerificationMode::remote(parse("http://localhost:8001")).is_ok());
+    }
 }
@@ -9,9 +9,9 @@
 //! 6. Phase topology
 //! 7. Manifest bundling
 
-use cc_graph_kernel::{
+use admissibility_kernel::{
     TurnId, TurnSnapshot, Edge, EdgeType, Phase, Role,
-    SlicePolicyV1, PhaseWeights as KernelPhaseWeights,
+    SlicePolicyV1,
     GraphSnapshot, SnapshotInput,
     BatchSlicer, AnchorSet,
     OverlapAnalyzer,
@@ -19,8 +19,7 @@ use cc_graph_kernel::{
     AtlasBundler, PhaseTopology,
     ATLAS_SCHEMA_VERSION,
 };
-use cc_graph_kernel::store::memory::InMemoryGraphStore;
-use std::collections::BTreeMap;
+use admissibility_kernel::store::memory::InMemoryGraphStore;
 use uuid::Uuid;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -66,8 +65,8 @@ fn create_test_graph(num_turns: usize) -> (InMemoryGraphStore, Vec<TurnSnapshot>
     for i in 1..turns.len() {
         let parent_idx = (i - 1) / 2; // Binary tree-ish structure
         store.add_edge(Edge::new(
-            turns[parent_idx].id.clone(),
-            turns[i].id.clone(),
+            turns[parent_idx].id,
+            turns[i].id,
             EdgeType::Reply,
         ));
     }
@@ -84,7 +83,7 @@ fn test_snapshot_determinism() {
     let (store, _turns) = create_test_graph(50);
     
     // Get all turn IDs and edges
-    let turn_ids: Vec<TurnId> = store.all_turns().iter().map(|t| t.id.clone()).collect();
+    let turn_ids: Vec<TurnId> = store.all_turns().iter().map(|t| t.id).collect();
     let edges = store.all_edges().to_vec();
     let timestamps: Vec<i64> = store.all_turns().iter().map(|t| t.created_at).collect();
     
@@ -109,13 +108,13 @@ fn test_snapshot_changes_on_new_data() {
     let (store2, _) = create_test_graph(51);
     
     let input1 = SnapshotInput {
-        turn_ids: store1.all_turns().iter().map(|t| t.id.clone()).collect(),
+        turn_ids: store1.all_turns().iter().map(|t| t.id).collect(),
         edges: store1.all_edges().to_vec(),
         timestamps: store1.all_turns().iter().map(|t| t.created_at).collect(),
     };
     
     let input2 = SnapshotInput {
-        turn_ids: store2.all_turns().iter().map(|t| t.id.clone()).collect(),
+        turn_ids: store2.all_turns().iter().map(|t| t.id).collect(),
         edges: store2.all_edges().to_vec(),
         timestamps: store2.all_turns().iter().map(|t| t.created_at).collect(),
     };
@@ -134,7 +133,7 @@ fn test_snapshot_changes_on_new_data() {
 #[test]
 fn test_anchor_set_determinism() {
     let (_, turns) = create_test_graph(100);
-    let turn_ids: Vec<TurnId> = turns.iter().take(20).map(|t| t.id.clone()).collect();
+    let turn_ids: Vec<TurnId> = turns.iter().take(20).map(|t| t.id).collect();
     
     let set1 = AnchorSet::new(turn_ids.clone(), "test_policy_v1");
     let set2 = AnchorSet::new(turn_ids, "test_policy_v1");
@@ -145,7 +144,7 @@ fn test_anchor_set_determinism() {
 #[test]
 fn test_anchor_set_order_independence() {
     let (_, turns) = create_test_graph(100);
-    let mut turn_ids: Vec<TurnId> = turns.iter().take(20).map(|t| t.id.clone()).collect();
+    let mut turn_ids: Vec<TurnId> = turns.iter().take(20).map(|t| t.id).collect();
     
     let set1 = AnchorSet::new(turn_ids.clone(), "test_policy_v1");
     
@@ -165,17 +164,15 @@ fn test_anchor_set_order_independence() {
 async fn test_batch_slicer_determinism() {
     let (store, turns) = create_test_graph(100);
     let store = std::sync::Arc::new(store);
-    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id).collect();
 
     let policy = SlicePolicyV1 {
         max_nodes: 15,
         max_radius: 3,
-        phase_weights: KernelPhaseWeights::default(),
         salience_weight: 1.0,
         distance_decay: 0.8,
-        include_siblings: true,
         max_siblings_per_node: 3,
-        version: "slice_policy_v1".to_string(),
+        ..Default::default()
     };
 
     let slicer = BatchSlicer::new(store, policy, b"test_hmac_secret_for_integration".to_vec());
@@ -196,7 +193,7 @@ async fn test_batch_slicer_determinism() {
 async fn test_batch_slicer_produces_registry() {
     let (store, turns) = create_test_graph(50);
     let store = std::sync::Arc::new(store);
-    let anchors: Vec<TurnId> = turns.iter().take(5).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(5).map(|t| t.id).collect();
 
     let policy = SlicePolicyV1::default();
     let slicer = BatchSlicer::new(store, policy, b"test_hmac_secret_for_integration".to_vec());
@@ -222,7 +219,7 @@ async fn test_batch_slicer_produces_registry() {
 async fn test_overlap_graph_determinism() {
     let (store, turns) = create_test_graph(50);
     let store = std::sync::Arc::new(store);
-    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id).collect();
 
     let policy = SlicePolicyV1::default();
     let slicer = BatchSlicer::new(store, policy, b"test_hmac_secret_for_integration".to_vec());
@@ -242,7 +239,7 @@ async fn test_overlap_detects_shared_turns() {
     let store = std::sync::Arc::new(store);
 
     // Use adjacent turns as anchors - they should share neighbors
-    let anchors = vec![turns[0].id.clone(), turns[1].id.clone()];
+    let anchors = vec![turns[0].id, turns[1].id];
 
     let policy = SlicePolicyV1 {
         max_nodes: 10,
@@ -268,7 +265,7 @@ async fn test_overlap_detects_shared_turns() {
 async fn test_influence_scores_determinism() {
     let (store, turns) = create_test_graph(50);
     let store = std::sync::Arc::new(store);
-    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id).collect();
 
     let policy = SlicePolicyV1::default();
     let slicer = BatchSlicer::new(store, policy, b"test_hmac_secret_for_integration".to_vec());
@@ -284,7 +281,7 @@ async fn test_influence_scores_determinism() {
 async fn test_influence_identifies_high_coverage_turns() {
     let (store, turns) = create_test_graph(30);
     let store = std::sync::Arc::new(store);
-    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id).collect();
 
     let policy = SlicePolicyV1 {
         max_nodes: 20,
@@ -314,7 +311,7 @@ async fn test_influence_identifies_high_coverage_turns() {
 async fn test_phase_topology_determinism() {
     let (store, turns) = create_test_graph(50);
     let store = std::sync::Arc::new(store);
-    let anchors: Vec<TurnId> = turns.iter().take(15).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(15).map(|t| t.id).collect();
 
     let policy = SlicePolicyV1 {
         max_nodes: 15,
@@ -341,11 +338,11 @@ async fn test_phase_topology_determinism() {
 #[tokio::test]
 async fn test_atlas_bundle_determinism() {
     let (store, turns) = create_test_graph(50);
-    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(10).map(|t| t.id).collect();
 
     // Compute snapshot
     let snapshot_input = SnapshotInput {
-        turn_ids: store.all_turns().iter().map(|t| t.id.clone()).collect(),
+        turn_ids: store.all_turns().iter().map(|t| t.id).collect(),
         edges: store.all_edges().to_vec(),
         timestamps: store.all_turns().iter().map(|t| t.created_at).collect(),
     };
@@ -398,10 +395,10 @@ async fn test_atlas_bundle_determinism() {
 #[tokio::test]
 async fn test_atlas_bundle_includes_stats() {
     let (store, turns) = create_test_graph(30);
-    let anchors: Vec<TurnId> = turns.iter().take(5).map(|t| t.id.clone()).collect();
+    let anchors: Vec<TurnId> = turns.iter().take(5).map(|t| t.id).collect();
 
     let snapshot_input = SnapshotInput {
-        turn_ids: store.all_turns().iter().map(|t| t.id.clone()).collect(),
+        turn_ids: store.all_turns().iter().map(|t| t.id).collect(),
         edges: store.all_edges().to_vec(),
         timestamps: store.all_turns().iter().map(|t| t.created_at).collect(),
     };
@@ -445,10 +442,10 @@ async fn test_full_atlas_replay_determinism() {
     // Run the full pipeline twice and verify byte-identical output
 
     async fn run_atlas_pipeline(store: std::sync::Arc<InMemoryGraphStore>, turns: &[TurnSnapshot]) -> String {
-        let anchors: Vec<TurnId> = turns.iter().take(8).map(|t| t.id.clone()).collect();
+        let anchors: Vec<TurnId> = turns.iter().take(8).map(|t| t.id).collect();
 
         let snapshot_input = SnapshotInput {
-            turn_ids: store.all_turns().iter().map(|t| t.id.clone()).collect(),
+            turn_ids: store.all_turns().iter().map(|t| t.id).collect(),
             edges: store.all_edges().to_vec(),
             timestamps: store.all_turns().iter().map(|t| t.created_at).collect(),
         };
@@ -457,12 +454,10 @@ async fn test_full_atlas_replay_determinism() {
         let policy = SlicePolicyV1 {
             max_nodes: 12,
             max_radius: 4,
-            phase_weights: KernelPhaseWeights::default(),
             salience_weight: 1.0,
             distance_decay: 0.8,
-            include_siblings: true,
             max_siblings_per_node: 2,
-            version: "slice_policy_v1".to_string(),
+            ..Default::default()
         };
 
         let slicer = BatchSlicer::new(store, policy, b"test_hmac_secret_for_integration".to_vec());
@@ -2,14 +2,16 @@
 //!
 //! These tests verify determinism and correctness of the context slicer.
 
+#![allow(clippy::field_reassign_with_default)]
+
 use std::sync::Arc;
-use cc_graph_kernel::{
+use admissibility_kernel::{
     TurnId, TurnSnapshot, Edge, EdgeType, Role, Phase,
     SlicePolicyV1, PhaseWeights,
     ContextSlicer,
     canonical_hash_hex,
 };
-use cc_graph_kernel::store::InMemoryGraphStore;
+use admissibility_kernel::store::InMemoryGraphStore;
 use uuid::Uuid;
 
 /// Test HMAC secret for unit tests